@@ -1,65 +1,205 @@
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{
+    parse_macro_input, DeriveInput, Expr, ExprLit, GenericArgument, Lit, PathArguments, Type,
+};
+
+/// Layout values of `Vec<T>` and `Option<T>` are offset from the value of `T`.
+const VEC_OFFSET: u8 = 13;
+const OPTION_OFFSET: u8 = 26;
+/// Layout values of nested struct groups.
+const STRUCT: u8 = 55;
+const VEC_STRUCT: u8 = 56;
+/// Layout value of `Decimal<SCALE>`, followed by the scale.
+const DECIMAL: u8 = 59;
+/// Layout value of fieldless enums, followed by the number of variants.
+const ENUM: u8 = 61;
 
 /// A convenient way to get the serialized representation
 /// of a struct for Solana Attestation Service. Adds a
-/// `get_serialized_representation` function to the struct.
+/// `get_serialized_representation` function to the struct,
+/// and `get_enum_variant_names` listing the variant names
+/// of its Enum fields for CreateSchema.
+///
+/// Fields whose type is not a known data type are treated as nested structs
+/// or fieldless enums, which must also derive `SchemaStructSerialize`.
 #[proc_macro_derive(SchemaStructSerialize)]
 pub fn schema_struct_serialize(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let struct_name = input.ident; // Extract struct name
 
-    let fields = match input.data {
-        syn::Data::Struct(ref s) => &s.fields,
-        _ => panic!("SchemaStructSerialize only supports structs"),
-    };
+    let output = match input.data {
+        syn::Data::Struct(ref s) => {
+            let fields = &s.fields;
+            let field_count = u8::try_from(fields.len()).expect("Too many fields in struct");
+            let layouts: Vec<_> = fields.iter().map(|f| layout_of(&f.ty)).collect();
+            let field_bytes = layouts.iter().map(Layout::to_tokens);
+            let variant_names = layouts.iter().filter_map(|layout| match layout {
+                Layout::Nested { ty, .. } => {
+                    Some(quote! { names.extend(<#ty>::get_enum_variant_names()); })
+                }
+                _ => None,
+            });
+
+            quote! {
+                impl #struct_name {
+                    pub fn get_serialized_representation() -> Vec<u8> {
+                        let mut layout: Vec<u8> = Vec::new();
+                        #(#field_bytes)*
+                        layout
+                    }
 
-    let field_bytes = fields.iter().map(|f| {
-        let ty = &f.ty;
-        if let syn::Type::Path(type_path) = ty {
-            let type_ident = type_path.path.segments.last().unwrap().ident.to_string();
-            match type_ident.as_str() {
-                "u8" => quote! { 0 },
-                "u16" => quote! { 1 },
-                "u32" => quote! { 2 },
-                "u64" => quote! { 3 },
-                "u128" => quote! { 4 },
-                "i8" => quote! { 5 },
-                "i16" => quote! { 6 },
-                "i32" => quote! { 7 },
-                "i64" => quote! { 8 },
-                "i128" => quote! { 9 },
-                "bool" => quote! { 10 },
-                "char" => quote! { 11 },
-                "String" => quote! { 12 },
-                "Vec<u8>" => quote! { 13 },
-                "Vec<u16>" => quote! { 14 },
-                "Vec<u32>" => quote! { 15 },
-                "Vec<u64>" => quote! { 16 },
-                "Vec<u128>" => quote! { 17 },
-                "Vec<i8>" => quote! { 18 },
-                "Vec<i16>" => quote! { 19 },
-                "Vec<i32>" => quote! { 20 },
-                "Vec<i64>" => quote! { 21 },
-                "Vec<i128>" => quote! { 22 },
-                "Vec<bool>" => quote! { 23 },
-                "Vec<char>" => quote! { 24 },
-                "Vec<String>" => quote! { 25 },
-                _ => panic!("Unsupported type in struct"),
+                    pub fn get_enum_variant_names() -> Vec<String> {
+                        let mut names: Vec<String> = Vec::new();
+                        #(#variant_names)*
+                        names
+                    }
+
+                    /// Layout of the struct when nested in another.
+                    #[doc(hidden)]
+                    pub fn get_type_descriptor() -> Vec<u8> {
+                        let mut layout = vec![#STRUCT, #field_count];
+                        layout.extend(Self::get_serialized_representation());
+                        layout
+                    }
+                }
             }
-        } else {
-            panic!("Unsupported type format");
         }
-    });
+        syn::Data::Enum(ref e) => {
+            if e.variants.iter().any(|v| !v.fields.is_empty()) {
+                panic!("SchemaStructSerialize only supports fieldless enums");
+            }
+            let variant_count = u8::try_from(e.variants.len())
+                .ok()
+                .filter(|count| *count > 0)
+                .expect("Unsupported number of enum variants");
+            let names = e.variants.iter().map(|v| v.ident.to_string());
 
-    let output = quote! {
-        impl #struct_name {
-            pub fn get_serialized_representation() -> Vec<u8> {
-                vec![ #(#field_bytes),* ]
+            quote! {
+                impl #struct_name {
+                    pub fn get_enum_variant_names() -> Vec<String> {
+                        vec![#(#names.to_string()),*]
+                    }
+
+                    /// Layout of the enum when used as a field.
+                    #[doc(hidden)]
+                    pub fn get_type_descriptor() -> Vec<u8> {
+                        vec![#ENUM, #variant_count]
+                    }
+                }
             }
         }
+        _ => panic!("SchemaStructSerialize only supports structs and enums"),
     };
 
     output.into()
 }
+
+/// Layout of a field type.
+enum Layout<'a> {
+    /// A single SchemaDataTypes value.
+    DataType(u8),
+    /// A Decimal with its scale.
+    Decimal(u8),
+    /// A nested struct or enum, or a Vec of structs when `vec` is set.
+    Nested { ty: &'a Type, vec: bool },
+}
+
+impl Layout<'_> {
+    /// Statements pushing the layout onto `layout`.
+    fn to_tokens(&self) -> TokenStream2 {
+        match self {
+            Layout::DataType(data_type) => quote! { layout.push(#data_type); },
+            Layout::Decimal(scale) => quote! { layout.extend([#DECIMAL, #scale]); },
+            Layout::Nested { ty, vec: false } => {
+                quote! { layout.extend(<#ty>::get_type_descriptor()); }
+            }
+            Layout::Nested { ty, vec: true } => quote! {
+                let mut descriptor = <#ty>::get_type_descriptor();
+                assert_eq!(descriptor[0], #STRUCT, "Unsupported Vec element type in struct");
+                descriptor[0] = #VEC_STRUCT;
+                layout.extend(descriptor);
+            },
+        }
+    }
+}
+
+/// Map a field type to its layout.
+fn layout_of(ty: &Type) -> Layout<'_> {
+    if let Type::Array(array) = ty {
+        return match (layout_of(&array.elem), int_literal(&array.len)) {
+            (Layout::DataType(0), 32) => Layout::DataType(53),
+            (Layout::DataType(0), 64) => Layout::DataType(54),
+            _ => panic!("Unsupported array type in struct"),
+        };
+    }
+    let Type::Path(type_path) = ty else {
+        panic!("Unsupported type format");
+    };
+    let segment = type_path.path.segments.last().unwrap();
+    let data_type = match segment.ident.to_string().as_str() {
+        "u8" => 0,
+        "u16" => 1,
+        "u32" => 2,
+        "u64" => 3,
+        "u128" => 4,
+        "i8" => 5,
+        "i16" => 6,
+        "i32" => 7,
+        "i64" => 8,
+        "i128" => 9,
+        "bool" => 10,
+        "char" => 11,
+        "String" => 12,
+        "Pubkey" => 52,
+        "f32" => 57,
+        "f64" => 58,
+        "Timestamp" => 60,
+        // Decimal<SCALE>
+        "Decimal" => match &segment.arguments {
+            PathArguments::AngleBracketed(args) => match args.args.first() {
+                Some(GenericArgument::Const(scale)) => return Layout::Decimal(int_literal(scale)),
+                _ => panic!("Unsupported Decimal scale in struct"),
+            },
+            _ => panic!("Unsupported Decimal scale in struct"),
+        },
+        // Vec<u8> -> Vec<String>, Vec<struct>
+        "Vec" => match layout_of(generic_argument(&segment.arguments)) {
+            Layout::DataType(element @ 0..=12) => VEC_OFFSET + element,
+            Layout::Nested { ty, vec: false } => return Layout::Nested { ty, vec: true },
+            _ => panic!("Unsupported Vec element type in struct"),
+        },
+        // Option<u8> -> Option<Vec<String>>
+        "Option" => match layout_of(generic_argument(&segment.arguments)) {
+            Layout::DataType(inner @ 0..=25) => OPTION_OFFSET + inner,
+            _ => panic!("Unsupported Option type in struct"),
+        },
+        _ => return Layout::Nested { ty, vec: false },
+    };
+    Layout::DataType(data_type)
+}
+
+/// The single type argument of `Vec<T>` or `Option<T>`.
+fn generic_argument(arguments: &PathArguments) -> &Type {
+    match arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first() {
+            Some(GenericArgument::Type(ty)) => ty,
+            _ => panic!("Unsupported type format"),
+        },
+        _ => panic!("Unsupported type format"),
+    }
+}
+
+/// Value of an array length or const generic, which must be an integer literal.
+fn int_literal<N: std::str::FromStr>(expr: &Expr) -> N
+where
+    N::Err: std::fmt::Display,
+{
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().unwrap(),
+        _ => panic!("Unsupported array length or scale in struct"),
+    }
+}
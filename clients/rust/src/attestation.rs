@@ -0,0 +1,51 @@
+use crate::{accounts::Attestation, types::VerifyAttestationResult};
+
+impl Attestation {
+    /// Whether the Attestation has been revoked by an authorized signer.
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at != 0
+    }
+
+    /// Whether the Attestation has a non-zero expiry that is before `unix_timestamp`,
+    /// i.e. it can be closed with `CloseExpiredAttestation`.
+    pub fn is_expired(&self, unix_timestamp: i64) -> bool {
+        self.expiry != 0 && unix_timestamp > self.expiry
+    }
+
+    /// Whether the Attestation is neither revoked nor expired at `unix_timestamp`.
+    /// Verifiers walking `references` should check this on every dependency,
+    /// since referenced Attestations are only validated at creation.
+    pub fn is_active(&self, unix_timestamp: i64) -> bool {
+        !self.is_revoked() && !self.is_expired(unix_timestamp)
+    }
+}
+
+/// Values of `VerifyAttestationResult::status`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum AttestationStatus {
+    Valid = 0,
+    Revoked = 1,
+    Expired = 2,
+    /// The signer is no longer one of the Credential's authorized signers
+    SignerNotAuthorized = 3,
+    SchemaPaused = 4,
+}
+
+impl VerifyAttestationResult {
+    /// Decoded status, `None` for values unknown to this client.
+    pub fn status(&self) -> Option<AttestationStatus> {
+        match self.status {
+            0 => Some(AttestationStatus::Valid),
+            1 => Some(AttestationStatus::Revoked),
+            2 => Some(AttestationStatus::Expired),
+            3 => Some(AttestationStatus::SignerNotAuthorized),
+            4 => Some(AttestationStatus::SchemaPaused),
+            _ => None,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.status() == Some(AttestationStatus::Valid)
+    }
+}
@@ -0,0 +1,259 @@
+use solana_program::{instruction::Instruction, message::Message, pubkey::Pubkey};
+
+use crate::{
+    instructions::{CloseAttestationsBatchBuilder, CreateAttestationsBatchBuilder},
+    types::CreateAttestationEntry,
+    SOLANA_ATTESTATION_SERVICE_ID,
+};
+
+/// Maximum size of a serialized transaction (`PACKET_DATA_SIZE`).
+pub const MAX_TRANSACTION_SIZE: usize = 1232;
+
+/// Size of `message` once signed and serialized as a transaction.
+fn transaction_size(message: &Message) -> usize {
+    let num_signatures = message.header.num_required_signatures as usize;
+    // Signatures are prefixed by a compact-u16 length, which is 1 byte below 128.
+    1 + num_signatures * 64 + message.serialize().len()
+}
+
+/// Attestation PDA for the given Credential, Schema and nonce.
+fn find_attestation_pda(credential: &Pubkey, schema: &Pubkey, nonce: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"attestation",
+            credential.as_ref(),
+            schema.as_ref(),
+            nonce.as_ref(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0
+}
+
+/// Splits `items` into as few instructions as fit in transactions paid for by
+/// `payer`, with at most `max_items` per instruction.
+fn pack<T>(
+    items: &[T],
+    payer: &Pubkey,
+    max_items: Option<usize>,
+    build: impl Fn(&[T]) -> Instruction,
+) -> Vec<Instruction> {
+    let max_items = max_items.unwrap_or(usize::MAX).max(1);
+
+    let mut instructions = Vec::new();
+    let mut start = 0;
+    while start < items.len() {
+        // A single item is always emitted, even if it is too large on its own.
+        let mut end = start + 1;
+        let mut instruction = build(&items[start..end]);
+        while end < items.len() && end - start < max_items {
+            let candidate = build(&items[start..=end]);
+            let message = Message::new(std::slice::from_ref(&candidate), Some(payer));
+            if transaction_size(&message) > MAX_TRANSACTION_SIZE {
+                break;
+            }
+            instruction = candidate;
+            end += 1;
+        }
+        instructions.push(instruction);
+        start = end;
+    }
+
+    instructions
+}
+
+/// Packs Attestation entries into `CreateAttestationsBatch` instructions.
+///
+/// Each returned instruction holds as many entries as fit in a single
+/// transaction signed by `payer` and `authority`, optionally capped by
+/// `max_entries` to stay within compute limits.
+#[derive(Clone, Debug, Default)]
+pub struct CreateAttestationsBatchPacker {
+    payer: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    credential: Option<Pubkey>,
+    schema: Option<Pubkey>,
+    max_entries: Option<usize>,
+    entries: Vec<CreateAttestationEntry>,
+}
+
+impl CreateAttestationsBatchPacker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// Authorized signer of the Schema's Credential
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    #[inline(always)]
+    pub fn credential(&mut self, credential: Pubkey) -> &mut Self {
+        self.credential = Some(credential);
+        self
+    }
+    #[inline(always)]
+    pub fn schema(&mut self, schema: Pubkey) -> &mut Self {
+        self.schema = Some(schema);
+        self
+    }
+    /// `[optional argument]`
+    /// Upper bound on entries per instruction.
+    #[inline(always)]
+    pub fn max_entries(&mut self, max_entries: usize) -> &mut Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+    #[inline(always)]
+    pub fn add_entry(&mut self, entry: CreateAttestationEntry) -> &mut Self {
+        self.entries.push(entry);
+        self
+    }
+    #[inline(always)]
+    pub fn add_entries(&mut self, entries: &[CreateAttestationEntry]) -> &mut Self {
+        self.entries.extend_from_slice(entries);
+        self
+    }
+
+    fn build(&self, entries: &[CreateAttestationEntry]) -> Instruction {
+        let credential = self.credential.expect("credential is not set");
+        let schema = self.schema.expect("schema is not set");
+        let attestations: Vec<_> = entries
+            .iter()
+            .map(|entry| {
+                solana_program::instruction::AccountMeta::new(
+                    find_attestation_pda(&credential, &schema, &entry.nonce),
+                    false,
+                )
+            })
+            .collect();
+
+        CreateAttestationsBatchBuilder::new()
+            .payer(self.payer.expect("payer is not set"))
+            .authority(self.authority.expect("authority is not set"))
+            .credential(credential)
+            .schema(schema)
+            .entries(entries.to_vec())
+            .add_remaining_accounts(&attestations)
+            .instruction()
+    }
+
+    /// Returns one instruction per transaction, in entry order.
+    pub fn instructions(&self) -> Vec<Instruction> {
+        let payer = self.payer.expect("payer is not set");
+        pack(&self.entries, &payer, self.max_entries, |entries| {
+            self.build(entries)
+        })
+    }
+}
+
+/// Splits Attestations into `CloseAttestationsBatch` instructions.
+///
+/// Each returned instruction closes as many Attestations as fit in a single
+/// transaction signed by `authority` and paid for by `fee_payer`, optionally
+/// capped by `max_attestations`. The Schemas of the Attestations in an
+/// instruction are passed alongside them.
+#[derive(Clone, Debug, Default)]
+pub struct CloseAttestationsBatchChunker {
+    fee_payer: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    credential: Option<Pubkey>,
+    max_attestations: Option<usize>,
+    /// Attestations to close, each paired with its Schema.
+    attestations: Vec<(Pubkey, Pubkey)>,
+}
+
+impl CloseAttestationsBatchChunker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Pays the transaction fees. Used only to size transactions
+    #[inline(always)]
+    pub fn fee_payer(&mut self, fee_payer: Pubkey) -> &mut Self {
+        self.fee_payer = Some(fee_payer);
+        self
+    }
+    /// Receives the rent. Must be the original payer of every Attestation unless the Credential authority signs
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// Authorized signer of the Schema's Credential
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    #[inline(always)]
+    pub fn credential(&mut self, credential: Pubkey) -> &mut Self {
+        self.credential = Some(credential);
+        self
+    }
+    /// `[optional argument]`
+    /// Upper bound on Attestations per instruction.
+    #[inline(always)]
+    pub fn max_attestations(&mut self, max_attestations: usize) -> &mut Self {
+        self.max_attestations = Some(max_attestations);
+        self
+    }
+    /// Attestation of `schema` to close.
+    #[inline(always)]
+    pub fn add_attestation(&mut self, schema: Pubkey, attestation: Pubkey) -> &mut Self {
+        self.attestations.push((schema, attestation));
+        self
+    }
+    /// Attestations of `schema` to close.
+    #[inline(always)]
+    pub fn add_attestations(&mut self, schema: Pubkey, attestations: &[Pubkey]) -> &mut Self {
+        self.attestations.extend(
+            attestations
+                .iter()
+                .map(|attestation| (schema, *attestation)),
+        );
+        self
+    }
+
+    fn build(&self, attestations: &[(Pubkey, Pubkey)]) -> Instruction {
+        let event_authority =
+            Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID).0;
+        let mut schemas: Vec<Pubkey> = Vec::new();
+        for (schema, _) in attestations {
+            if !schemas.contains(schema) {
+                schemas.push(*schema);
+            }
+        }
+        let remaining_accounts: Vec<_> = attestations
+            .iter()
+            .map(|(_, attestation)| attestation)
+            .chain(&schemas)
+            .map(|key| solana_program::instruction::AccountMeta::new(*key, false))
+            .collect();
+
+        CloseAttestationsBatchBuilder::new()
+            .payer(self.payer.expect("payer is not set"))
+            .authority(self.authority.expect("authority is not set"))
+            .credential(self.credential.expect("credential is not set"))
+            .event_authority(event_authority)
+            .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+            .add_remaining_accounts(&remaining_accounts)
+            .instruction()
+    }
+
+    /// Returns one instruction per transaction, in Attestation order.
+    pub fn instructions(&self) -> Vec<Instruction> {
+        let fee_payer = self.fee_payer.or(self.payer).expect("fee_payer is not set");
+        pack(
+            &self.attestations,
+            &fee_payer,
+            self.max_attestations,
+            |attestations| self.build(attestations),
+        )
+    }
+}
@@ -0,0 +1,119 @@
+use solana_program::{instruction::AccountMeta, keccak::hashv, pubkey::Pubkey};
+
+use crate::{
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    types::{CompressedAttestation, CompressedAttestationEvent},
+};
+
+pub const ATTESTATION_TREE_SEED: &[u8] = b"attestationTree";
+
+/// AttestationTree PDA bound to `schema`.
+pub fn find_attestation_tree_pda(schema: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[ATTESTATION_TREE_SEED, &schema.to_bytes()],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0
+}
+
+/// Proof nodes as the trailing accounts of RevokeCompressedAttestation.
+pub fn proof_account_metas(proof: &[[u8; 32]]) -> Vec<AccountMeta> {
+    proof
+        .iter()
+        .map(|node| AccountMeta::new_readonly(Pubkey::new_from_array(*node), false))
+        .collect()
+}
+
+impl CompressedAttestation {
+    /// Leaf hash stored in the AttestationTree: keccak256 of the serialized leaf.
+    pub fn hash(&self) -> [u8; 32] {
+        hashv(&[&borsh::to_vec(self).unwrap()]).to_bytes()
+    }
+
+    /// Whether the Attestation has been revoked by an authorized signer.
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at != 0
+    }
+}
+
+/// Off-chain copy of an AttestationTree, rebuilt from its
+/// CompressedAttestationEvents, used to look up leaves and compute proofs.
+#[derive(Clone, Debug)]
+pub struct AttestationTreeReplica {
+    tree: Pubkey,
+    max_depth: u8,
+    attestations: Vec<Option<CompressedAttestation>>,
+}
+
+impl AttestationTreeReplica {
+    pub fn new(tree: Pubkey, max_depth: u8) -> Self {
+        Self {
+            tree,
+            max_depth,
+            attestations: Vec::new(),
+        }
+    }
+
+    /// Record the leaf of an event. Events of other trees are ignored, events
+    /// may be applied in any order.
+    pub fn apply_event(&mut self, event: &CompressedAttestationEvent) {
+        if event.tree != self.tree {
+            return;
+        }
+        let index = event.leaf_index as usize;
+        if self.attestations.len() <= index {
+            self.attestations.resize(index + 1, None);
+        }
+        self.attestations[index] = Some(event.attestation.clone());
+    }
+
+    /// Latest known leaf at `index`.
+    pub fn attestation(&self, index: u32) -> Option<&CompressedAttestation> {
+        self.attestations.get(index as usize)?.as_ref()
+    }
+
+    /// Root of the tree with every applied event.
+    pub fn root(&self) -> [u8; 32] {
+        let mut level = self.leaves();
+        let mut zero = [0u8; 32];
+        for _ in 0..self.max_depth {
+            level = Self::parent_level(&level, &zero);
+            zero = hash_pair(&zero, &zero);
+        }
+        level.first().copied().unwrap_or(zero)
+    }
+
+    /// Sibling nodes of the leaf at `index`, from the leaf level up.
+    pub fn proof(&self, index: u32) -> Vec<[u8; 32]> {
+        let mut proof = Vec::with_capacity(self.max_depth as usize);
+        let mut level = self.leaves();
+        let mut zero = [0u8; 32];
+        let mut index = index as usize;
+        for _ in 0..self.max_depth {
+            proof.push(level.get(index ^ 1).copied().unwrap_or(zero));
+            level = Self::parent_level(&level, &zero);
+            zero = hash_pair(&zero, &zero);
+            index >>= 1;
+        }
+        proof
+    }
+
+    fn leaves(&self) -> Vec<[u8; 32]> {
+        self.attestations
+            .iter()
+            .map(|attestation| attestation.as_ref().map_or([0u8; 32], |a| a.hash()))
+            .collect()
+    }
+
+    /// Hash pairs of non-empty nodes, padding with the empty subtree `zero`.
+    fn parent_level(level: &[[u8; 32]], zero: &[u8; 32]) -> Vec<[u8; 32]> {
+        level
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(zero)))
+            .collect()
+    }
+}
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    hashv(&[left, right]).to_bytes()
+}
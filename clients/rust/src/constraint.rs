@@ -0,0 +1,378 @@
+use std::{
+    cmp::Ordering,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use borsh::BorshSerialize;
+
+use crate::accounts::Schema;
+
+/// Constraint on a field of a Schema, checked on chain when Attestations are
+/// created or updated. Fields are referenced by their top level index in the
+/// Schema's layout, see `Schema::field_index`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FieldConstraint {
+    /// Minimum of a numeric field, serialized like the field.
+    Min { field: u8, value: Vec<u8> },
+    /// Maximum of a numeric field, serialized like the field.
+    Max { field: u8, value: Vec<u8> },
+    /// Maximum length of a String (in bytes) or Vec (in elements) field.
+    MaxLen { field: u8, len: u32 },
+    /// String or Vec field must not be empty.
+    NonEmpty { field: u8 },
+    /// Field must equal one of the values, serialized like the field.
+    OneOf { field: u8, values: Vec<Vec<u8>> },
+    /// Timestamp field must not be before the Clock's unix timestamp plus
+    /// `offset` seconds when attested.
+    NotBefore { field: u8, offset: i64 },
+    /// Timestamp field must not be after the Clock's unix timestamp plus
+    /// `offset` seconds when attested.
+    NotAfter { field: u8, offset: i64 },
+}
+
+/// Reason attestation data was rejected by `Schema::validate_attestation_data`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AttestationDataError {
+    /// The data does not match the Schema's layout.
+    InvalidLayout,
+    /// The Schema's constraints could not be decoded.
+    InvalidConstraints,
+    /// The data violates one of the Schema's constraints.
+    ConstraintViolated(FieldConstraint),
+}
+
+impl FieldConstraint {
+    pub fn min<T: BorshSerialize>(field: u8, value: &T) -> Self {
+        FieldConstraint::Min {
+            field,
+            value: borsh::to_vec(value).unwrap(),
+        }
+    }
+
+    pub fn max<T: BorshSerialize>(field: u8, value: &T) -> Self {
+        FieldConstraint::Max {
+            field,
+            value: borsh::to_vec(value).unwrap(),
+        }
+    }
+
+    pub fn max_len(field: u8, len: u32) -> Self {
+        FieldConstraint::MaxLen { field, len }
+    }
+
+    pub fn non_empty(field: u8) -> Self {
+        FieldConstraint::NonEmpty { field }
+    }
+
+    pub fn one_of<T: BorshSerialize>(field: u8, values: &[T]) -> Self {
+        FieldConstraint::OneOf {
+            field,
+            values: values
+                .iter()
+                .map(|value| borsh::to_vec(value).unwrap())
+                .collect(),
+        }
+    }
+
+    pub fn not_before(field: u8, offset: i64) -> Self {
+        FieldConstraint::NotBefore { field, offset }
+    }
+
+    pub fn not_after(field: u8, offset: i64) -> Self {
+        FieldConstraint::NotAfter { field, offset }
+    }
+
+    pub fn field(&self) -> u8 {
+        match self {
+            FieldConstraint::Min { field, .. }
+            | FieldConstraint::Max { field, .. }
+            | FieldConstraint::MaxLen { field, .. }
+            | FieldConstraint::NonEmpty { field }
+            | FieldConstraint::OneOf { field, .. }
+            | FieldConstraint::NotBefore { field, .. }
+            | FieldConstraint::NotAfter { field, .. } => *field,
+        }
+    }
+
+    /// Encoding expected by the `constraints` argument of CreateSchema and
+    /// ChangeSchemaVersion.
+    pub fn to_bytes(constraints: &[FieldConstraint]) -> Vec<u8> {
+        let mut data = Vec::new();
+        for constraint in constraints {
+            match constraint {
+                FieldConstraint::Min { field, value } => {
+                    data.extend_from_slice(&[*field, 0]);
+                    data.extend_from_slice(value);
+                }
+                FieldConstraint::Max { field, value } => {
+                    data.extend_from_slice(&[*field, 1]);
+                    data.extend_from_slice(value);
+                }
+                FieldConstraint::MaxLen { field, len } => {
+                    data.extend_from_slice(&[*field, 2]);
+                    data.extend_from_slice(&len.to_le_bytes());
+                }
+                FieldConstraint::NonEmpty { field } => data.extend_from_slice(&[*field, 3]),
+                FieldConstraint::OneOf { field, values } => {
+                    data.extend_from_slice(&[*field, 4, values.len() as u8]);
+                    for value in values {
+                        data.extend_from_slice(value);
+                    }
+                }
+                FieldConstraint::NotBefore { field, offset } => {
+                    data.extend_from_slice(&[*field, 5]);
+                    data.extend_from_slice(&offset.to_le_bytes());
+                }
+                FieldConstraint::NotAfter { field, offset } => {
+                    data.extend_from_slice(&[*field, 6]);
+                    data.extend_from_slice(&offset.to_le_bytes());
+                }
+            }
+        }
+        data
+    }
+
+    /// Decode constraints encoded with `to_bytes` for a Schema with `layout`.
+    pub fn from_bytes(mut data: &[u8], layout: &[u8]) -> Option<Vec<FieldConstraint>> {
+        let descriptors = split_layout(layout)?;
+        let mut constraints = Vec::new();
+        while let [field, kind, rest @ ..] = data {
+            let descriptor = *descriptors.get(*field as usize)?;
+            let data_type = descriptor[0];
+            let field = *field;
+            data = rest;
+            let has_length = (12..=25).contains(&data_type) || data_type == 56;
+            let constraint = match kind {
+                0 | 1 if data_type <= 9 || (57..=60).contains(&data_type) => {
+                    let size = field_size(descriptor, data)?;
+                    let value = data.get(..size)?.to_vec();
+                    data = &data[size..];
+                    if *kind == 0 {
+                        FieldConstraint::Min { field, value }
+                    } else {
+                        FieldConstraint::Max { field, value }
+                    }
+                }
+                2 if has_length => {
+                    let len = u32::from_le_bytes(data.get(..4)?.try_into().unwrap());
+                    data = &data[4..];
+                    FieldConstraint::MaxLen { field, len }
+                }
+                3 if has_length => FieldConstraint::NonEmpty { field },
+                4 if data_type <= 12 || matches!(data_type, 52..=54 | 59..=61) => {
+                    let (count, rest) = data.split_first()?;
+                    data = rest;
+                    let mut values = Vec::new();
+                    for _ in 0..*count {
+                        let size = field_size(descriptor, data)?;
+                        values.push(data.get(..size)?.to_vec());
+                        data = &data[size..];
+                    }
+                    FieldConstraint::OneOf { field, values }
+                }
+                5 | 6 if data_type == 60 => {
+                    let offset = i64::from_le_bytes(data.get(..8)?.try_into().unwrap());
+                    data = &data[8..];
+                    if *kind == 5 {
+                        FieldConstraint::NotBefore { field, offset }
+                    } else {
+                        FieldConstraint::NotAfter { field, offset }
+                    }
+                }
+                _ => return None,
+            };
+            constraints.push(constraint);
+        }
+        data.is_empty().then_some(constraints)
+    }
+
+    /// Whether the encoded `value` of a field of `data_type` satisfies the
+    /// constraint at `unix_timestamp`.
+    fn is_satisfied(&self, data_type: u8, value: &[u8], unix_timestamp: i64) -> bool {
+        let len = || u32::from_le_bytes(value[..4].try_into().unwrap());
+        let timestamp = || i64::from_le_bytes(value.try_into().unwrap());
+        match self {
+            FieldConstraint::Min { value: min, .. } => matches!(
+                compare_numbers(data_type, value, min),
+                Some(Ordering::Greater | Ordering::Equal)
+            ),
+            FieldConstraint::Max { value: max, .. } => matches!(
+                compare_numbers(data_type, value, max),
+                Some(Ordering::Less | Ordering::Equal)
+            ),
+            FieldConstraint::MaxLen { len: max_len, .. } => len() <= *max_len,
+            FieldConstraint::NonEmpty { .. } => len() != 0,
+            FieldConstraint::OneOf { values, .. } => values.iter().any(|v| v == value),
+            FieldConstraint::NotBefore { offset, .. } => {
+                timestamp() >= unix_timestamp.saturating_add(*offset)
+            }
+            FieldConstraint::NotAfter { offset, .. } => {
+                timestamp() <= unix_timestamp.saturating_add(*offset)
+            }
+        }
+    }
+}
+
+/// Length in the layout of the type descriptor at the start of `layout`: a
+/// single data type, or a struct group followed by its field count and
+/// fields. Also returns the number of field names the descriptor takes, as
+/// struct groups and each of their fields are named in order.
+pub(crate) fn descriptor_len(layout: &[u8]) -> Option<(usize, usize)> {
+    match layout.first()? {
+        // Decimal and Enum, followed by their scale and variant count
+        59 | 61 => Some((2, 1)),
+        55 | 56 => {
+            let (mut len, mut names) = (2, 1);
+            for _ in 0..*layout.get(1)? {
+                let (field_len, field_names) = descriptor_len(layout.get(len..)?)?;
+                len += field_len;
+                names += field_names;
+            }
+            Some((len, names))
+        }
+        _ => Some((1, 1)),
+    }
+}
+
+/// Split `layout` into the type descriptors of its top level fields.
+pub(crate) fn split_layout(mut layout: &[u8]) -> Option<Vec<&[u8]>> {
+    let mut descriptors = Vec::new();
+    while !layout.is_empty() {
+        let (len, _) = descriptor_len(layout)?;
+        descriptors.push(&layout[..len]);
+        layout = &layout[len..];
+    }
+    Some(descriptors)
+}
+
+/// Size of a group of `fields` encoded at the start of `data`.
+fn struct_size(fields: &[&[u8]], data: &[u8]) -> Option<usize> {
+    let mut size = 0;
+    for field in fields {
+        size += field_size(field, data.get(size..)?)?;
+    }
+    Some(size)
+}
+
+/// Size of the value described by the type `descriptor`, encoded at the start
+/// of `data`.
+fn field_size(descriptor: &[u8], data: &[u8]) -> Option<usize> {
+    let data_type = descriptor[0];
+    let vec_len = || -> Option<usize> {
+        Some(u32::from_le_bytes(data.get(..4)?.try_into().unwrap()) as usize)
+    };
+    let size = match data_type {
+        0 | 5 | 10 => 1,
+        1 | 6 => 2,
+        2 | 7 | 11 => 4,
+        3 | 8 => 8,
+        4 | 9 => 16,
+        12 | 13 | 18 | 23 => 4 + vec_len()?,
+        14 | 19 => 4 + vec_len()? * 2,
+        15 | 20 | 24 => 4 + vec_len()? * 4,
+        16 | 21 => 4 + vec_len()? * 8,
+        17 | 22 => 4 + vec_len()? * 16,
+        25 => {
+            let mut size = 4;
+            for _ in 0..vec_len()? {
+                size += field_size(&[12], data.get(size..)?)?;
+            }
+            size
+        }
+        // Option<T>: one byte tag, followed by the value when Some
+        26..=51 => match data.first()? {
+            0 => 1,
+            1 => 1 + field_size(&[data_type - 26], &data[1..])?,
+            _ => return None,
+        },
+        // Pubkey, [u8; 32], [u8; 64]
+        52 | 53 => 32,
+        54 => 64,
+        // f32, f64, Decimal, Timestamp
+        57 => 4,
+        58 | 60 => 8,
+        59 => 16,
+        // Enum variant index
+        61 => (*data.first()? < descriptor[1]).then_some(1)?,
+        // Struct, Vec<Struct>
+        55 => struct_size(&split_layout(&descriptor[2..])?, data)?,
+        56 => {
+            let fields = split_layout(&descriptor[2..])?;
+            let mut size = 4;
+            for _ in 0..vec_len()? {
+                size += struct_size(&fields, data.get(size..)?)?;
+            }
+            size
+        }
+        _ => return None,
+    };
+    (size <= data.len()).then_some(size)
+}
+
+/// Compare two encoded numbers of `data_type`. Decimals compare by mantissa.
+/// `None` if either is a NaN float.
+fn compare_numbers(data_type: u8, a: &[u8], b: &[u8]) -> Option<Ordering> {
+    let signed = matches!(data_type, 5..=9 | 59 | 60);
+    let extend = |bytes: &[u8]| {
+        let negative = signed && bytes[bytes.len() - 1] & 0x80 != 0;
+        let mut buf = if negative { [0xff; 16] } else { [0; 16] };
+        buf[..bytes.len()].copy_from_slice(bytes);
+        buf
+    };
+    match data_type {
+        57 => f32::from_le_bytes(a.try_into().ok()?)
+            .partial_cmp(&f32::from_le_bytes(b.try_into().ok()?)),
+        58 => f64::from_le_bytes(a.try_into().ok()?)
+            .partial_cmp(&f64::from_le_bytes(b.try_into().ok()?)),
+        _ if signed => Some(i128::from_le_bytes(extend(a)).cmp(&i128::from_le_bytes(extend(b)))),
+        _ => Some(u128::from_le_bytes(extend(a)).cmp(&u128::from_le_bytes(extend(b)))),
+    }
+}
+
+impl Schema {
+    /// Field constraints of the Schema.
+    pub fn field_constraints(&self) -> Option<Vec<FieldConstraint>> {
+        FieldConstraint::from_bytes(&self.constraints, &self.layout)
+    }
+
+    /// Check serialized attestation `data` matches the Schema's layout and
+    /// satisfies its field constraints, as CreateAttestation does on chain.
+    /// Timestamp constraints are checked against the system clock.
+    pub fn validate_attestation_data(&self, data: &[u8]) -> Result<(), AttestationDataError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs() as i64);
+        self.validate_attestation_data_at(data, now)
+    }
+
+    /// `validate_attestation_data`, checking Timestamp constraints against
+    /// `unix_timestamp`.
+    pub fn validate_attestation_data_at(
+        &self,
+        data: &[u8],
+        unix_timestamp: i64,
+    ) -> Result<(), AttestationDataError> {
+        let descriptors = split_layout(&self.layout).ok_or(AttestationDataError::InvalidLayout)?;
+        let mut fields = Vec::with_capacity(descriptors.len());
+        let mut rest = data;
+        for descriptor in &descriptors {
+            let size = field_size(descriptor, rest).ok_or(AttestationDataError::InvalidLayout)?;
+            fields.push(&rest[..size]);
+            rest = &rest[size..];
+        }
+        if !rest.is_empty() {
+            return Err(AttestationDataError::InvalidLayout);
+        }
+
+        let constraints = self
+            .field_constraints()
+            .ok_or(AttestationDataError::InvalidConstraints)?;
+        for constraint in constraints {
+            let field = constraint.field() as usize;
+            if !constraint.is_satisfied(descriptors[field][0], fields[field], unix_timestamp) {
+                return Err(AttestationDataError::ConstraintViolated(constraint));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Fixed-point number with `SCALE` decimal places, the Rust type of a Decimal
+/// Schema field. `Decimal::<2>(1234)` is 12.34.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Decimal<const SCALE: u8>(pub i128);
+
+impl<const SCALE: u8> fmt::Display for Decimal<SCALE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let digits = self.0.unsigned_abs().to_string();
+        let scale = SCALE as usize;
+        if scale == 0 {
+            return write!(f, "{sign}{digits}");
+        }
+        let digits = format!("{digits:0>width$}", width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        write!(f, "{sign}{integer}.{fraction}")
+    }
+}
+
+/// Unix timestamp in seconds, the Rust type of a Timestamp Schema field.
+#[derive(
+    BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd,
+)]
+pub struct Timestamp(pub i64);
@@ -40,6 +40,25 @@ pub struct Attestation {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub token_account: Pubkey,
+    pub revoked_at: i64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub revoked_by: Pubkey,
+    pub revocation_reason: u8,
+    pub created_at: i64,
+    pub updated_at: i64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub payer: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+    )]
+    pub references: Vec<Pubkey>,
 }
 
 impl Attestation {
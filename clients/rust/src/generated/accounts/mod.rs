@@ -6,9 +6,13 @@
 //!
 
 pub(crate) mod r#attestation;
+pub(crate) mod r#attestation_tree;
 pub(crate) mod r#credential;
 pub(crate) mod r#schema;
+pub(crate) mod r#signature_receipt;
 
 pub use self::r#attestation::*;
+pub use self::r#attestation_tree::*;
 pub use self::r#credential::*;
 pub use self::r#schema::*;
+pub use self::r#signature_receipt::*;
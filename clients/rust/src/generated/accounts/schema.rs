@@ -24,6 +24,15 @@ pub struct Schema {
     pub field_names: Vec<u8>,
     pub is_paused: bool,
     pub version: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub payer: Pubkey,
+    pub requires_subject_signer: bool,
+    pub constraints: Vec<u8>,
+    pub enum_variants: Vec<u8>,
+    pub attestation_count: u64,
 }
 
 impl Schema {
@@ -46,7 +46,7 @@ impl ChangeSchemaVersion {
             self.authority,
             true,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.credential,
             false,
         ));
@@ -98,6 +98,8 @@ impl Default for ChangeSchemaVersionInstructionData {
 pub struct ChangeSchemaVersionInstructionArgs {
     pub layout: Vec<u8>,
     pub field_names: Vec<String>,
+    pub constraints: Option<Vec<u8>>,
+    pub enum_variants: Option<Vec<String>>,
 }
 
 /// Instruction builder for `ChangeSchemaVersion`.
@@ -106,7 +108,7 @@ pub struct ChangeSchemaVersionInstructionArgs {
 ///
 ///   0. `[writable, signer]` payer
 ///   1. `[signer]` authority
-///   2. `[]` credential
+///   2. `[writable]` credential
 ///   3. `[]` existing_schema
 ///   4. `[writable]` new_schema
 ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
@@ -120,6 +122,8 @@ pub struct ChangeSchemaVersionBuilder {
     system_program: Option<solana_program::pubkey::Pubkey>,
     layout: Option<Vec<u8>>,
     field_names: Option<Vec<String>>,
+    constraints: Option<Vec<u8>>,
+    enum_variants: Option<Vec<String>>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -172,6 +176,18 @@ impl ChangeSchemaVersionBuilder {
         self.field_names = Some(field_names);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn constraints(&mut self, constraints: Vec<u8>) -> &mut Self {
+        self.constraints = Some(constraints);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn enum_variants(&mut self, enum_variants: Vec<String>) -> &mut Self {
+        self.enum_variants = Some(enum_variants);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -205,6 +221,8 @@ impl ChangeSchemaVersionBuilder {
         let args = ChangeSchemaVersionInstructionArgs {
             layout: self.layout.clone().expect("layout is not set"),
             field_names: self.field_names.clone().expect("field_names is not set"),
+            constraints: self.constraints.clone(),
+            enum_variants: self.enum_variants.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -306,7 +324,7 @@ impl<'a, 'b> ChangeSchemaVersionCpi<'a, 'b> {
             *self.authority.key,
             true,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.credential.key,
             false,
         ));
@@ -364,7 +382,7 @@ impl<'a, 'b> ChangeSchemaVersionCpi<'a, 'b> {
 ///
 ///   0. `[writable, signer]` payer
 ///   1. `[signer]` authority
-///   2. `[]` credential
+///   2. `[writable]` credential
 ///   3. `[]` existing_schema
 ///   4. `[writable]` new_schema
 ///   5. `[]` system_program
@@ -385,6 +403,8 @@ impl<'a, 'b> ChangeSchemaVersionCpiBuilder<'a, 'b> {
             system_program: None,
             layout: None,
             field_names: None,
+            constraints: None,
+            enum_variants: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -445,6 +465,18 @@ impl<'a, 'b> ChangeSchemaVersionCpiBuilder<'a, 'b> {
         self.instruction.field_names = Some(field_names);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn constraints(&mut self, constraints: Vec<u8>) -> &mut Self {
+        self.instruction.constraints = Some(constraints);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn enum_variants(&mut self, enum_variants: Vec<String>) -> &mut Self {
+        self.instruction.enum_variants = Some(enum_variants);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -493,6 +525,8 @@ impl<'a, 'b> ChangeSchemaVersionCpiBuilder<'a, 'b> {
                 .field_names
                 .clone()
                 .expect("field_names is not set"),
+            constraints: self.instruction.constraints.clone(),
+            enum_variants: self.instruction.enum_variants.clone(),
         };
         let instruction = ChangeSchemaVersionCpi {
             __program: self.instruction.__program,
@@ -534,6 +568,8 @@ struct ChangeSchemaVersionCpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     layout: Option<Vec<u8>>,
     field_names: Option<Vec<String>>,
+    constraints: Option<Vec<u8>>,
+    enum_variants: Option<Vec<String>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -11,8 +11,9 @@ use borsh::BorshSerialize;
 /// Accounts.
 #[derive(Debug)]
 pub struct CloseAttestation {
+    /// Receives the rent. Must be the original payer unless the Credential admins approve
     pub payer: solana_program::pubkey::Pubkey,
-    /// Authorized signer of the Schema's Credential
+    /// Authorized signer of the Schema's Credential or the Attestation's subject
     pub authority: solana_program::pubkey::Pubkey,
 
     pub credential: solana_program::pubkey::Pubkey,
@@ -24,6 +25,8 @@ pub struct CloseAttestation {
     pub system_program: solana_program::pubkey::Pubkey,
 
     pub attestation_program: solana_program::pubkey::Pubkey,
+    /// Schema the Attestation is associated with
+    pub schema: solana_program::pubkey::Pubkey,
 }
 
 impl CloseAttestation {
@@ -36,9 +39,9 @@ impl CloseAttestation {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
-            self.payer, true,
+            self.payer, false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.authority,
@@ -64,6 +67,10 @@ impl CloseAttestation {
             self.attestation_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.schema,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = borsh::to_vec(&CloseAttestationInstructionData::new()).unwrap();
 
@@ -97,13 +104,14 @@ impl Default for CloseAttestationInstructionData {
 ///
 /// ### Accounts:
 ///
-///   0. `[writable, signer]` payer
+///   0. `[writable]` payer
 ///   1. `[signer]` authority
 ///   2. `[]` credential
 ///   3. `[writable]` attestation
 ///   4. `[]` event_authority
 ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   6. `[]` attestation_program
+///   7. `[writable]` schema
 #[derive(Clone, Debug, Default)]
 pub struct CloseAttestationBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
@@ -113,6 +121,7 @@ pub struct CloseAttestationBuilder {
     event_authority: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     attestation_program: Option<solana_program::pubkey::Pubkey>,
+    schema: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -120,12 +129,13 @@ impl CloseAttestationBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+    /// Receives the rent. Must be the original payer unless the Credential admins approve
     #[inline(always)]
     pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
         self.payer = Some(payer);
         self
     }
-    /// Authorized signer of the Schema's Credential
+    /// Authorized signer of the Schema's Credential or the Attestation's subject
     #[inline(always)]
     pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
         self.authority = Some(authority);
@@ -163,6 +173,12 @@ impl CloseAttestationBuilder {
         self.attestation_program = Some(attestation_program);
         self
     }
+    /// Schema the Attestation is associated with
+    #[inline(always)]
+    pub fn schema(&mut self, schema: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.schema = Some(schema);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -195,6 +211,7 @@ impl CloseAttestationBuilder {
             attestation_program: self
                 .attestation_program
                 .expect("attestation_program is not set"),
+            schema: self.schema.expect("schema is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -203,8 +220,9 @@ impl CloseAttestationBuilder {
 
 /// `close_attestation` CPI accounts.
 pub struct CloseAttestationCpiAccounts<'a, 'b> {
+    /// Receives the rent. Must be the original payer unless the Credential admins approve
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Authorized signer of the Schema's Credential
+    /// Authorized signer of the Schema's Credential or the Attestation's subject
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub credential: &'b solana_program::account_info::AccountInfo<'a>,
@@ -216,15 +234,17 @@ pub struct CloseAttestationCpiAccounts<'a, 'b> {
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub attestation_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Schema the Attestation is associated with
+    pub schema: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `close_attestation` CPI instruction.
 pub struct CloseAttestationCpi<'a, 'b> {
     /// The program to invoke.
     pub __program: &'b solana_program::account_info::AccountInfo<'a>,
-
+    /// Receives the rent. Must be the original payer unless the Credential admins approve
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Authorized signer of the Schema's Credential
+    /// Authorized signer of the Schema's Credential or the Attestation's subject
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub credential: &'b solana_program::account_info::AccountInfo<'a>,
@@ -236,6 +256,8 @@ pub struct CloseAttestationCpi<'a, 'b> {
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub attestation_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Schema the Attestation is associated with
+    pub schema: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> CloseAttestationCpi<'a, 'b> {
@@ -252,6 +274,7 @@ impl<'a, 'b> CloseAttestationCpi<'a, 'b> {
             event_authority: accounts.event_authority,
             system_program: accounts.system_program,
             attestation_program: accounts.attestation_program,
+            schema: accounts.schema,
         }
     }
     #[inline(always)]
@@ -288,10 +311,10 @@ impl<'a, 'b> CloseAttestationCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.payer.key,
-            true,
+            false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.authority.key,
@@ -317,6 +340,10 @@ impl<'a, 'b> CloseAttestationCpi<'a, 'b> {
             *self.attestation_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.schema.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -331,7 +358,7 @@ impl<'a, 'b> CloseAttestationCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.authority.clone());
@@ -340,6 +367,7 @@ impl<'a, 'b> CloseAttestationCpi<'a, 'b> {
         account_infos.push(self.event_authority.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.attestation_program.clone());
+        account_infos.push(self.schema.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -356,13 +384,14 @@ impl<'a, 'b> CloseAttestationCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-///   0. `[writable, signer]` payer
+///   0. `[writable]` payer
 ///   1. `[signer]` authority
 ///   2. `[]` credential
 ///   3. `[writable]` attestation
 ///   4. `[]` event_authority
 ///   5. `[]` system_program
 ///   6. `[]` attestation_program
+///   7. `[writable]` schema
 #[derive(Clone, Debug)]
 pub struct CloseAttestationCpiBuilder<'a, 'b> {
     instruction: Box<CloseAttestationCpiBuilderInstruction<'a, 'b>>,
@@ -379,16 +408,18 @@ impl<'a, 'b> CloseAttestationCpiBuilder<'a, 'b> {
             event_authority: None,
             system_program: None,
             attestation_program: None,
+            schema: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
     }
+    /// Receives the rent. Must be the original payer unless the Credential admins approve
     #[inline(always)]
     pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.payer = Some(payer);
         self
     }
-    /// Authorized signer of the Schema's Credential
+    /// Authorized signer of the Schema's Credential or the Attestation's subject
     #[inline(always)]
     pub fn authority(
         &mut self,
@@ -437,6 +468,15 @@ impl<'a, 'b> CloseAttestationCpiBuilder<'a, 'b> {
         self.instruction.attestation_program = Some(attestation_program);
         self
     }
+    /// Schema the Attestation is associated with
+    #[inline(always)]
+    pub fn schema(
+        &mut self,
+        schema: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.schema = Some(schema);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -506,6 +546,8 @@ impl<'a, 'b> CloseAttestationCpiBuilder<'a, 'b> {
                 .instruction
                 .attestation_program
                 .expect("attestation_program is not set"),
+
+            schema: self.instruction.schema.expect("schema is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -524,6 +566,7 @@ struct CloseAttestationCpiBuilderInstruction<'a, 'b> {
     event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     attestation_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    schema: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
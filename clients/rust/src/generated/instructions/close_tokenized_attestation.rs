@@ -11,8 +11,9 @@ use borsh::BorshSerialize;
 /// Accounts.
 #[derive(Debug)]
 pub struct CloseTokenizedAttestation {
+    /// Receives the rent. Must be the original payer unless the Credential admins approve
     pub payer: solana_program::pubkey::Pubkey,
-    /// Authorized signer of the Schema's Credential
+    /// Authorized signer of the Schema's Credential or the Attestation's subject
     pub authority: solana_program::pubkey::Pubkey,
 
     pub credential: solana_program::pubkey::Pubkey,
@@ -32,6 +33,8 @@ pub struct CloseTokenizedAttestation {
     pub attestation_token_account: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
+    /// Schema the Attestation is associated with
+    pub schema: solana_program::pubkey::Pubkey,
 }
 
 impl CloseTokenizedAttestation {
@@ -44,9 +47,9 @@ impl CloseTokenizedAttestation {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
-            self.payer, true,
+            self.payer, false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.authority,
@@ -88,6 +91,10 @@ impl CloseTokenizedAttestation {
             self.token_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.schema,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = borsh::to_vec(&CloseTokenizedAttestationInstructionData::new()).unwrap();
 
@@ -121,7 +128,7 @@ impl Default for CloseTokenizedAttestationInstructionData {
 ///
 /// ### Accounts:
 ///
-///   0. `[writable, signer]` payer
+///   0. `[writable]` payer
 ///   1. `[signer]` authority
 ///   2. `[]` credential
 ///   3. `[writable]` attestation
@@ -132,6 +139,7 @@ impl Default for CloseTokenizedAttestationInstructionData {
 ///   8. `[]` sas_pda
 ///   9. `[writable]` attestation_token_account
 ///   10. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   11. `[writable]` schema
 #[derive(Clone, Debug, Default)]
 pub struct CloseTokenizedAttestationBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
@@ -145,6 +153,7 @@ pub struct CloseTokenizedAttestationBuilder {
     sas_pda: Option<solana_program::pubkey::Pubkey>,
     attestation_token_account: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
+    schema: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -152,12 +161,13 @@ impl CloseTokenizedAttestationBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+    /// Receives the rent. Must be the original payer unless the Credential admins approve
     #[inline(always)]
     pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
         self.payer = Some(payer);
         self
     }
-    /// Authorized signer of the Schema's Credential
+    /// Authorized signer of the Schema's Credential or the Attestation's subject
     #[inline(always)]
     pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
         self.authority = Some(authority);
@@ -225,6 +235,12 @@ impl CloseTokenizedAttestationBuilder {
         self.token_program = Some(token_program);
         self
     }
+    /// Schema the Attestation is associated with
+    #[inline(always)]
+    pub fn schema(&mut self, schema: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.schema = Some(schema);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -265,6 +281,7 @@ impl CloseTokenizedAttestationBuilder {
             token_program: self.token_program.unwrap_or(solana_program::pubkey!(
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
+            schema: self.schema.expect("schema is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -273,8 +290,9 @@ impl CloseTokenizedAttestationBuilder {
 
 /// `close_tokenized_attestation` CPI accounts.
 pub struct CloseTokenizedAttestationCpiAccounts<'a, 'b> {
+    /// Receives the rent. Must be the original payer unless the Credential admins approve
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Authorized signer of the Schema's Credential
+    /// Authorized signer of the Schema's Credential or the Attestation's subject
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub credential: &'b solana_program::account_info::AccountInfo<'a>,
@@ -294,15 +312,17 @@ pub struct CloseTokenizedAttestationCpiAccounts<'a, 'b> {
     pub attestation_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Schema the Attestation is associated with
+    pub schema: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `close_tokenized_attestation` CPI instruction.
 pub struct CloseTokenizedAttestationCpi<'a, 'b> {
     /// The program to invoke.
     pub __program: &'b solana_program::account_info::AccountInfo<'a>,
-
+    /// Receives the rent. Must be the original payer unless the Credential admins approve
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Authorized signer of the Schema's Credential
+    /// Authorized signer of the Schema's Credential or the Attestation's subject
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub credential: &'b solana_program::account_info::AccountInfo<'a>,
@@ -322,6 +342,8 @@ pub struct CloseTokenizedAttestationCpi<'a, 'b> {
     pub attestation_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Schema the Attestation is associated with
+    pub schema: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> CloseTokenizedAttestationCpi<'a, 'b> {
@@ -342,6 +364,7 @@ impl<'a, 'b> CloseTokenizedAttestationCpi<'a, 'b> {
             sas_pda: accounts.sas_pda,
             attestation_token_account: accounts.attestation_token_account,
             token_program: accounts.token_program,
+            schema: accounts.schema,
         }
     }
     #[inline(always)]
@@ -378,10 +401,10 @@ impl<'a, 'b> CloseTokenizedAttestationCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.payer.key,
-            true,
+            false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.authority.key,
@@ -423,6 +446,10 @@ impl<'a, 'b> CloseTokenizedAttestationCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.schema.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -437,7 +464,7 @@ impl<'a, 'b> CloseTokenizedAttestationCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.authority.clone());
@@ -450,6 +477,7 @@ impl<'a, 'b> CloseTokenizedAttestationCpi<'a, 'b> {
         account_infos.push(self.sas_pda.clone());
         account_infos.push(self.attestation_token_account.clone());
         account_infos.push(self.token_program.clone());
+        account_infos.push(self.schema.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -466,7 +494,7 @@ impl<'a, 'b> CloseTokenizedAttestationCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-///   0. `[writable, signer]` payer
+///   0. `[writable]` payer
 ///   1. `[signer]` authority
 ///   2. `[]` credential
 ///   3. `[writable]` attestation
@@ -477,6 +505,7 @@ impl<'a, 'b> CloseTokenizedAttestationCpi<'a, 'b> {
 ///   8. `[]` sas_pda
 ///   9. `[writable]` attestation_token_account
 ///   10. `[]` token_program
+///   11. `[writable]` schema
 #[derive(Clone, Debug)]
 pub struct CloseTokenizedAttestationCpiBuilder<'a, 'b> {
     instruction: Box<CloseTokenizedAttestationCpiBuilderInstruction<'a, 'b>>,
@@ -497,16 +526,18 @@ impl<'a, 'b> CloseTokenizedAttestationCpiBuilder<'a, 'b> {
             sas_pda: None,
             attestation_token_account: None,
             token_program: None,
+            schema: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
     }
+    /// Receives the rent. Must be the original payer unless the Credential admins approve
     #[inline(always)]
     pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.payer = Some(payer);
         self
     }
-    /// Authorized signer of the Schema's Credential
+    /// Authorized signer of the Schema's Credential or the Attestation's subject
     #[inline(always)]
     pub fn authority(
         &mut self,
@@ -590,6 +621,15 @@ impl<'a, 'b> CloseTokenizedAttestationCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    /// Schema the Attestation is associated with
+    #[inline(always)]
+    pub fn schema(
+        &mut self,
+        schema: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.schema = Some(schema);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -676,6 +716,8 @@ impl<'a, 'b> CloseTokenizedAttestationCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+
+            schema: self.instruction.schema.expect("schema is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -698,6 +740,7 @@ struct CloseTokenizedAttestationCpiBuilderInstruction<'a, 'b> {
     sas_pda: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     attestation_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    schema: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
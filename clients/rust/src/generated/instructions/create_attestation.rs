@@ -51,7 +51,7 @@ impl CreateAttestation {
             self.credential,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.schema,
             false,
         ));
@@ -100,6 +100,7 @@ pub struct CreateAttestationInstructionArgs {
     pub nonce: Pubkey,
     pub data: Vec<u8>,
     pub expiry: i64,
+    pub references: Option<Vec<Pubkey>>,
 }
 
 /// Instruction builder for `CreateAttestation`.
@@ -109,7 +110,7 @@ pub struct CreateAttestationInstructionArgs {
 ///   0. `[writable, signer]` payer
 ///   1. `[signer]` authority
 ///   2. `[]` credential
-///   3. `[]` schema
+///   3. `[writable]` schema
 ///   4. `[writable]` attestation
 ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
@@ -123,6 +124,7 @@ pub struct CreateAttestationBuilder {
     nonce: Option<Pubkey>,
     data: Option<Vec<u8>>,
     expiry: Option<i64>,
+    references: Option<Vec<Pubkey>>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -179,6 +181,12 @@ impl CreateAttestationBuilder {
         self.expiry = Some(expiry);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn references(&mut self, references: Vec<Pubkey>) -> &mut Self {
+        self.references = Some(references);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -213,6 +221,7 @@ impl CreateAttestationBuilder {
             nonce: self.nonce.clone().expect("nonce is not set"),
             data: self.data.clone().expect("data is not set"),
             expiry: self.expiry.clone().expect("expiry is not set"),
+            references: self.references.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -318,7 +327,7 @@ impl<'a, 'b> CreateAttestationCpi<'a, 'b> {
             *self.credential.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.schema.key,
             false,
         ));
@@ -373,7 +382,7 @@ impl<'a, 'b> CreateAttestationCpi<'a, 'b> {
 ///   0. `[writable, signer]` payer
 ///   1. `[signer]` authority
 ///   2. `[]` credential
-///   3. `[]` schema
+///   3. `[writable]` schema
 ///   4. `[writable]` attestation
 ///   5. `[]` system_program
 #[derive(Clone, Debug)]
@@ -394,6 +403,7 @@ impl<'a, 'b> CreateAttestationCpiBuilder<'a, 'b> {
             nonce: None,
             data: None,
             expiry: None,
+            references: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -461,6 +471,12 @@ impl<'a, 'b> CreateAttestationCpiBuilder<'a, 'b> {
         self.instruction.expiry = Some(expiry);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn references(&mut self, references: Vec<Pubkey>) -> &mut Self {
+        self.instruction.references = Some(references);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -506,6 +522,7 @@ impl<'a, 'b> CreateAttestationCpiBuilder<'a, 'b> {
             nonce: self.instruction.nonce.clone().expect("nonce is not set"),
             data: self.instruction.data.clone().expect("data is not set"),
             expiry: self.instruction.expiry.clone().expect("expiry is not set"),
+            references: self.instruction.references.clone(),
         };
         let instruction = CreateAttestationCpi {
             __program: self.instruction.__program,
@@ -548,6 +565,7 @@ struct CreateAttestationCpiBuilderInstruction<'a, 'b> {
     nonce: Option<Pubkey>,
     data: Option<Vec<u8>>,
     expiry: Option<i64>,
+    references: Option<Vec<Pubkey>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -44,7 +44,7 @@ impl CreateSchema {
             self.authority,
             true,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.credential,
             false,
         ));
@@ -94,6 +94,9 @@ pub struct CreateSchemaInstructionArgs {
     pub description: String,
     pub layout: Vec<u8>,
     pub field_names: Vec<String>,
+    pub constraints: Option<Vec<u8>>,
+    pub enum_variants: Option<Vec<String>>,
+    pub requires_subject_signer: Option<bool>,
 }
 
 /// Instruction builder for `CreateSchema`.
@@ -102,7 +105,7 @@ pub struct CreateSchemaInstructionArgs {
 ///
 ///   0. `[writable, signer]` payer
 ///   1. `[signer]` authority
-///   2. `[]` credential
+///   2. `[writable]` credential
 ///   3. `[writable]` schema
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
@@ -116,6 +119,9 @@ pub struct CreateSchemaBuilder {
     description: Option<String>,
     layout: Option<Vec<u8>>,
     field_names: Option<Vec<String>>,
+    constraints: Option<Vec<u8>>,
+    enum_variants: Option<Vec<String>>,
+    requires_subject_signer: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -170,6 +176,24 @@ impl CreateSchemaBuilder {
         self.field_names = Some(field_names);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn constraints(&mut self, constraints: Vec<u8>) -> &mut Self {
+        self.constraints = Some(constraints);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn enum_variants(&mut self, enum_variants: Vec<String>) -> &mut Self {
+        self.enum_variants = Some(enum_variants);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn requires_subject_signer(&mut self, requires_subject_signer: bool) -> &mut Self {
+        self.requires_subject_signer = Some(requires_subject_signer);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -204,6 +228,9 @@ impl CreateSchemaBuilder {
             description: self.description.clone().expect("description is not set"),
             layout: self.layout.clone().expect("layout is not set"),
             field_names: self.field_names.clone().expect("field_names is not set"),
+            constraints: self.constraints.clone(),
+            enum_variants: self.enum_variants.clone(),
+            requires_subject_signer: self.requires_subject_signer.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -300,7 +327,7 @@ impl<'a, 'b> CreateSchemaCpi<'a, 'b> {
             *self.authority.key,
             true,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.credential.key,
             false,
         ));
@@ -353,7 +380,7 @@ impl<'a, 'b> CreateSchemaCpi<'a, 'b> {
 ///
 ///   0. `[writable, signer]` payer
 ///   1. `[signer]` authority
-///   2. `[]` credential
+///   2. `[writable]` credential
 ///   3. `[writable]` schema
 ///   4. `[]` system_program
 #[derive(Clone, Debug)]
@@ -374,6 +401,9 @@ impl<'a, 'b> CreateSchemaCpiBuilder<'a, 'b> {
             description: None,
             layout: None,
             field_names: None,
+            constraints: None,
+            enum_variants: None,
+            requires_subject_signer: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -436,6 +466,24 @@ impl<'a, 'b> CreateSchemaCpiBuilder<'a, 'b> {
         self.instruction.field_names = Some(field_names);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn constraints(&mut self, constraints: Vec<u8>) -> &mut Self {
+        self.instruction.constraints = Some(constraints);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn enum_variants(&mut self, enum_variants: Vec<String>) -> &mut Self {
+        self.instruction.enum_variants = Some(enum_variants);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn requires_subject_signer(&mut self, requires_subject_signer: bool) -> &mut Self {
+        self.instruction.requires_subject_signer = Some(requires_subject_signer);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -490,6 +538,9 @@ impl<'a, 'b> CreateSchemaCpiBuilder<'a, 'b> {
                 .field_names
                 .clone()
                 .expect("field_names is not set"),
+            constraints: self.instruction.constraints.clone(),
+            enum_variants: self.instruction.enum_variants.clone(),
+            requires_subject_signer: self.instruction.requires_subject_signer.clone(),
         };
         let instruction = CreateSchemaCpi {
             __program: self.instruction.__program,
@@ -527,6 +578,9 @@ struct CreateSchemaCpiBuilderInstruction<'a, 'b> {
     description: Option<String>,
     layout: Option<Vec<u8>>,
     field_names: Option<Vec<String>>,
+    constraints: Option<Vec<u8>>,
+    enum_variants: Option<Vec<String>>,
+    requires_subject_signer: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -65,7 +65,7 @@ impl CreateTokenizedAttestation {
             self.credential,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.schema,
             false,
         ));
@@ -146,6 +146,7 @@ pub struct CreateTokenizedAttestationInstructionArgs {
     pub uri: String,
     pub symbol: String,
     pub mint_account_space: u16,
+    pub references: Option<Vec<Pubkey>>,
 }
 
 /// Instruction builder for `CreateTokenizedAttestation`.
@@ -155,7 +156,7 @@ pub struct CreateTokenizedAttestationInstructionArgs {
 ///   0. `[writable, signer]` payer
 ///   1. `[signer]` authority
 ///   2. `[]` credential
-///   3. `[]` schema
+///   3. `[writable]` schema
 ///   4. `[writable]` attestation
 ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   6. `[writable]` schema_mint
@@ -187,6 +188,7 @@ pub struct CreateTokenizedAttestationBuilder {
     uri: Option<String>,
     symbol: Option<String>,
     mint_account_space: Option<u16>,
+    references: Option<Vec<Pubkey>>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -313,6 +315,12 @@ impl CreateTokenizedAttestationBuilder {
         self.mint_account_space = Some(mint_account_space);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn references(&mut self, references: Vec<Pubkey>) -> &mut Self {
+        self.references = Some(references);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -367,6 +375,7 @@ impl CreateTokenizedAttestationBuilder {
                 .mint_account_space
                 .clone()
                 .expect("mint_account_space is not set"),
+            references: self.references.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -507,7 +516,7 @@ impl<'a, 'b> CreateTokenizedAttestationCpi<'a, 'b> {
             *self.credential.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.schema.key,
             false,
         ));
@@ -597,7 +606,7 @@ impl<'a, 'b> CreateTokenizedAttestationCpi<'a, 'b> {
 ///   0. `[writable, signer]` payer
 ///   1. `[signer]` authority
 ///   2. `[]` credential
-///   3. `[]` schema
+///   3. `[writable]` schema
 ///   4. `[writable]` attestation
 ///   5. `[]` system_program
 ///   6. `[writable]` schema_mint
@@ -636,6 +645,7 @@ impl<'a, 'b> CreateTokenizedAttestationCpiBuilder<'a, 'b> {
             uri: None,
             symbol: None,
             mint_account_space: None,
+            references: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -784,6 +794,12 @@ impl<'a, 'b> CreateTokenizedAttestationCpiBuilder<'a, 'b> {
         self.instruction.mint_account_space = Some(mint_account_space);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn references(&mut self, references: Vec<Pubkey>) -> &mut Self {
+        self.instruction.references = Some(references);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -837,6 +853,7 @@ impl<'a, 'b> CreateTokenizedAttestationCpiBuilder<'a, 'b> {
                 .mint_account_space
                 .clone()
                 .expect("mint_account_space is not set"),
+            references: self.instruction.references.clone(),
         };
         let instruction = CreateTokenizedAttestationCpi {
             __program: self.instruction.__program,
@@ -919,6 +936,7 @@ struct CreateTokenizedAttestationCpiBuilderInstruction<'a, 'b> {
     uri: Option<String>,
     symbol: Option<String>,
     mint_account_space: Option<u16>,
+    references: Option<Vec<Pubkey>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
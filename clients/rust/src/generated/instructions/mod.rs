@@ -5,28 +5,68 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#accept_credential_authority;
 pub(crate) mod r#change_authorized_signers;
+pub(crate) mod r#change_credential_admins;
 pub(crate) mod r#change_schema_description;
 pub(crate) mod r#change_schema_status;
 pub(crate) mod r#change_schema_version;
+pub(crate) mod r#change_signer_permissions;
 pub(crate) mod r#close_attestation;
+pub(crate) mod r#close_attestations_batch;
+pub(crate) mod r#close_credential;
+pub(crate) mod r#close_expired_attestation;
+pub(crate) mod r#close_schema;
+pub(crate) mod r#close_signature_receipt;
 pub(crate) mod r#close_tokenized_attestation;
 pub(crate) mod r#create_attestation;
+pub(crate) mod r#create_attestation_tree;
+pub(crate) mod r#create_attestation_with_signature;
+pub(crate) mod r#create_attestations_batch;
+pub(crate) mod r#create_compressed_attestation;
 pub(crate) mod r#create_credential;
 pub(crate) mod r#create_schema;
 pub(crate) mod r#create_tokenized_attestation;
 pub(crate) mod r#emit_event;
+pub(crate) mod r#migrate_account;
+pub(crate) mod r#propose_credential_authority;
+pub(crate) mod r#renew_attestation;
+pub(crate) mod r#revoke_attestation;
+pub(crate) mod r#revoke_compressed_attestation;
 pub(crate) mod r#tokenize_schema;
+pub(crate) mod r#update_attestation;
+pub(crate) mod r#verify_attestation;
+pub(crate) mod r#verify_attestation_predicate;
 
+pub use self::r#accept_credential_authority::*;
 pub use self::r#change_authorized_signers::*;
+pub use self::r#change_credential_admins::*;
 pub use self::r#change_schema_description::*;
 pub use self::r#change_schema_status::*;
 pub use self::r#change_schema_version::*;
+pub use self::r#change_signer_permissions::*;
 pub use self::r#close_attestation::*;
+pub use self::r#close_attestations_batch::*;
+pub use self::r#close_credential::*;
+pub use self::r#close_expired_attestation::*;
+pub use self::r#close_schema::*;
+pub use self::r#close_signature_receipt::*;
 pub use self::r#close_tokenized_attestation::*;
 pub use self::r#create_attestation::*;
+pub use self::r#create_attestation_tree::*;
+pub use self::r#create_attestation_with_signature::*;
+pub use self::r#create_attestations_batch::*;
+pub use self::r#create_compressed_attestation::*;
 pub use self::r#create_credential::*;
 pub use self::r#create_schema::*;
 pub use self::r#create_tokenized_attestation::*;
 pub use self::r#emit_event::*;
+pub use self::r#migrate_account::*;
+pub use self::r#propose_credential_authority::*;
+pub use self::r#renew_attestation::*;
+pub use self::r#revoke_attestation::*;
+pub use self::r#revoke_compressed_attestation::*;
 pub use self::r#tokenize_schema::*;
+pub use self::r#update_attestation::*;
+pub use self::r#verify_attestation::*;
+pub use self::r#verify_attestation_predicate::*;
@@ -5,6 +5,32 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#change_log;
 pub(crate) mod r#close_attestation_event;
+pub(crate) mod r#close_attestations_batch_event;
+pub(crate) mod r#close_credential_event;
+pub(crate) mod r#close_schema_event;
+pub(crate) mod r#closed_attestation;
+pub(crate) mod r#compressed_attestation;
+pub(crate) mod r#compressed_attestation_event;
+pub(crate) mod r#create_attestation_entry;
+pub(crate) mod r#renew_attestation_event;
+pub(crate) mod r#signer_permission;
+pub(crate) mod r#subject_close_attestation_event;
+pub(crate) mod r#update_attestation_event;
+pub(crate) mod r#verify_attestation_result;
 
+pub use self::r#change_log::*;
 pub use self::r#close_attestation_event::*;
+pub use self::r#close_attestations_batch_event::*;
+pub use self::r#close_credential_event::*;
+pub use self::r#close_schema_event::*;
+pub use self::r#closed_attestation::*;
+pub use self::r#compressed_attestation::*;
+pub use self::r#compressed_attestation_event::*;
+pub use self::r#create_attestation_entry::*;
+pub use self::r#renew_attestation_event::*;
+pub use self::r#signer_permission::*;
+pub use self::r#subject_close_attestation_event::*;
+pub use self::r#update_attestation_event::*;
+pub use self::r#verify_attestation_result::*;
@@ -1,4 +1,12 @@
+mod attestation;
+mod batch;
+mod compression;
+mod constraint;
+mod data_types;
 mod generated;
+mod permissions;
+mod predicate;
+mod signature;
 
 use generated::*;
 
@@ -7,7 +15,12 @@ pub mod accounts {
 }
 
 pub mod instructions {
+    pub use super::batch::*;
+    pub use super::compression::*;
+    pub use super::constraint::*;
     pub use super::generated::instructions::*;
+    pub use super::predicate::*;
+    pub use super::signature::*;
 }
 
 pub mod errors {
@@ -23,5 +36,7 @@ pub mod programs {
 }
 
 pub mod types {
+    pub use super::attestation::AttestationStatus;
+    pub use super::data_types::*;
     pub use super::generated::types::*;
 }
@@ -0,0 +1,12 @@
+use crate::types::SignerPermission;
+
+/// Capability flags matching the program's `SignerPermission` bitmask.
+impl SignerPermission {
+    /// Create Attestations.
+    pub const ATTEST: u8 = 1 << 0;
+    /// Close and revoke Attestations.
+    pub const CLOSE: u8 = 1 << 1;
+    /// Update and renew Attestations.
+    pub const UPDATE: u8 = 1 << 2;
+    pub const ALL: u8 = Self::ATTEST | Self::CLOSE | Self::UPDATE;
+}
@@ -0,0 +1,161 @@
+use borsh::BorshSerialize;
+
+use crate::{
+    accounts::Schema,
+    constraint::{descriptor_len, split_layout},
+};
+
+/// Comparison applied by `Predicate::Compare`. Ordering comparisons are only
+/// valid on numeric, char and String fields. Decimals are compared by their
+/// mantissa, so values must use the field's scale.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum CompareOp {
+    Eq = 0,
+    Ne = 1,
+    Lt = 2,
+    Le = 3,
+    Gt = 4,
+    Ge = 5,
+}
+
+/// Predicate over an Attestation's data, evaluated on chain by
+/// VerifyAttestationPredicate. Fields are referenced by their index in the
+/// Schema's layout, see `Schema::field_index`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Predicate {
+    And(Vec<Predicate>),
+    Or(Vec<Predicate>),
+    Not(Box<Predicate>),
+    /// Compare a field with a value serialized like the field.
+    Compare {
+        field: u8,
+        op: CompareOp,
+        value: Vec<u8>,
+    },
+    /// Whether a field equals one of the values, serialized like the field.
+    In {
+        field: u8,
+        values: Vec<Vec<u8>>,
+    },
+}
+
+impl Predicate {
+    pub fn compare<T: BorshSerialize>(field: u8, op: CompareOp, value: &T) -> Self {
+        Predicate::Compare {
+            field,
+            op,
+            value: borsh::to_vec(value).unwrap(),
+        }
+    }
+
+    pub fn one_of<T: BorshSerialize>(field: u8, values: &[T]) -> Self {
+        Predicate::In {
+            field,
+            values: values
+                .iter()
+                .map(|value| borsh::to_vec(value).unwrap())
+                .collect(),
+        }
+    }
+
+    pub fn negate(self) -> Self {
+        Predicate::Not(Box::new(self))
+    }
+
+    /// Encoding expected by the `predicate` argument of
+    /// VerifyAttestationPredicate.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        self.write(&mut data);
+        data
+    }
+
+    fn write(&self, data: &mut Vec<u8>) {
+        match self {
+            Predicate::And(predicates) => {
+                data.extend_from_slice(&[0, predicates.len() as u8]);
+                for predicate in predicates {
+                    predicate.write(data);
+                }
+            }
+            Predicate::Or(predicates) => {
+                data.extend_from_slice(&[1, predicates.len() as u8]);
+                for predicate in predicates {
+                    predicate.write(data);
+                }
+            }
+            Predicate::Not(predicate) => {
+                data.push(2);
+                predicate.write(data);
+            }
+            Predicate::Compare { field, op, value } => {
+                data.extend_from_slice(&[3, *field, *op as u8]);
+                data.extend_from_slice(value);
+            }
+            Predicate::In { field, values } => {
+                data.extend_from_slice(&[4, *field, values.len() as u8]);
+                for value in values {
+                    data.extend_from_slice(value);
+                }
+            }
+        }
+    }
+}
+
+impl Schema {
+    /// Names of the Schema's fields, in layout order. Struct fields are
+    /// followed by the names of their nested fields.
+    pub fn field_names(&self) -> Vec<String> {
+        split_strings(&self.field_names)
+    }
+
+    /// Variant names of each of the Schema's Enum fields, in layout order.
+    /// An Enum field's value is the index of its variant.
+    pub fn enum_variants(&self) -> Vec<Vec<String>> {
+        let mut names = split_strings(&self.enum_variants).into_iter();
+        let mut variants = Vec::new();
+        let mut offset = 0;
+        while let Some(data_type) = self.layout.get(offset) {
+            // Struct groups, Decimals and Enums are followed by one byte,
+            // nested fields follow the group inline.
+            if let (61, Some(count)) = (data_type, self.layout.get(offset + 1)) {
+                variants.push(names.by_ref().take(*count as usize).collect());
+            }
+            offset += if matches!(data_type, 55 | 56 | 59 | 61) {
+                2
+            } else {
+                1
+            };
+        }
+        variants
+    }
+
+    /// Index of the top level field named `name`, to reference it in a
+    /// Predicate or FieldConstraint.
+    pub fn field_index(&self, name: &str) -> Option<u8> {
+        let names = self.field_names();
+        let mut name_index = 0;
+        for (index, descriptor) in split_layout(&self.layout)?.iter().enumerate() {
+            if names.get(name_index)? == name {
+                return Some(index as u8);
+            }
+            name_index += descriptor_len(descriptor)?.1;
+        }
+        None
+    }
+}
+
+/// Split a serialized array of Strings, each prefixed by its u32 length.
+fn split_strings(mut data: &[u8]) -> Vec<String> {
+    let mut strings = Vec::new();
+    while data.len() >= 4 {
+        let len = u32::from_le_bytes(data[..4].try_into().unwrap()) as usize;
+        let Some(string) = data.get(4..4 + len) else {
+            break;
+        };
+        strings.push(String::from_utf8_lossy(string).into_owned());
+        data = &data[4 + len..];
+    }
+    strings
+}
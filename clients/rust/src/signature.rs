@@ -0,0 +1,35 @@
+use solana_program::{hash::hash, pubkey::Pubkey};
+
+use crate::SOLANA_ATTESTATION_SERVICE_ID;
+
+pub use solana_attestation_service_core::CREATE_ATTESTATION_MESSAGE_PREFIX;
+
+/// Message an authorized signer signs off-chain to let anyone submit
+/// `CreateAttestationWithSignature`. It must be verified by an Ed25519 program
+/// instruction placed right before it in the transaction.
+pub fn create_attestation_message(
+    credential: &Pubkey,
+    schema: &Pubkey,
+    nonce: &Pubkey,
+    data: &[u8],
+    expiry: i64,
+    deadline: i64,
+) -> Vec<u8> {
+    solana_attestation_service_core::create_attestation_message(
+        &credential.to_bytes(),
+        &schema.to_bytes(),
+        &nonce.to_bytes(),
+        &hash(data).to_bytes(),
+        expiry,
+        deadline,
+    )
+}
+
+/// Receipt PDA marking `message` as used.
+pub fn find_signature_receipt_pda(message: &[u8]) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"signatureReceipt", hash(message).as_ref()],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0
+}
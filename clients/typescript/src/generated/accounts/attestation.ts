@@ -17,6 +17,8 @@ import {
   fetchEncodedAccounts,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI64Decoder,
@@ -49,6 +51,13 @@ export type Attestation = {
   signer: Address;
   expiry: bigint;
   tokenAccount: Address;
+  revokedAt: bigint;
+  revokedBy: Address;
+  revocationReason: number;
+  createdAt: bigint;
+  updatedAt: bigint;
+  payer: Address;
+  references: Array<Address>;
 };
 
 export type AttestationArgs = {
@@ -60,6 +69,13 @@ export type AttestationArgs = {
   signer: Address;
   expiry: number | bigint;
   tokenAccount: Address;
+  revokedAt: number | bigint;
+  revokedBy: Address;
+  revocationReason: number;
+  createdAt: number | bigint;
+  updatedAt: number | bigint;
+  payer: Address;
+  references: Array<Address>;
 };
 
 export function getAttestationEncoder(): Encoder<AttestationArgs> {
@@ -72,6 +88,13 @@ export function getAttestationEncoder(): Encoder<AttestationArgs> {
     ['signer', getAddressEncoder()],
     ['expiry', getI64Encoder()],
     ['tokenAccount', getAddressEncoder()],
+    ['revokedAt', getI64Encoder()],
+    ['revokedBy', getAddressEncoder()],
+    ['revocationReason', getU8Encoder()],
+    ['createdAt', getI64Encoder()],
+    ['updatedAt', getI64Encoder()],
+    ['payer', getAddressEncoder()],
+    ['references', getArrayEncoder(getAddressEncoder())],
   ]);
 }
 
@@ -85,6 +108,13 @@ export function getAttestationDecoder(): Decoder<Attestation> {
     ['signer', getAddressDecoder()],
     ['expiry', getI64Decoder()],
     ['tokenAccount', getAddressDecoder()],
+    ['revokedAt', getI64Decoder()],
+    ['revokedBy', getAddressDecoder()],
+    ['revocationReason', getU8Decoder()],
+    ['createdAt', getI64Decoder()],
+    ['updatedAt', getI64Decoder()],
+    ['payer', getAddressDecoder()],
+    ['references', getArrayDecoder(getAddressDecoder())],
   ]);
 }
 
@@ -39,15 +39,38 @@ import {
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from '@solana/kit';
+import {
+  getSignerPermissionDecoder,
+  getSignerPermissionEncoder,
+  type SignerPermission,
+  type SignerPermissionArgs,
+} from '../types';
 
 export type Credential = {
   discriminator: number;
   authority: Address;
   name: ReadonlyUint8Array;
   authorizedSigners: Array<Address>;
+  payer: Address;
+  adminThreshold: number;
+  admins: Array<Address>;
+  pendingAuthority: Address;
+  schemaCount: number;
+  signerPermissions: Array<SignerPermission>;
 };
 
-export type CredentialArgs = Credential;
+export type CredentialArgs = {
+  discriminator: number;
+  authority: Address;
+  name: ReadonlyUint8Array;
+  authorizedSigners: Array<Address>;
+  payer: Address;
+  adminThreshold: number;
+  admins: Array<Address>;
+  pendingAuthority: Address;
+  schemaCount: number;
+  signerPermissions: Array<SignerPermissionArgs>;
+};
 
 export function getCredentialEncoder(): Encoder<CredentialArgs> {
   return getStructEncoder([
@@ -55,6 +78,12 @@ export function getCredentialEncoder(): Encoder<CredentialArgs> {
     ['authority', getAddressEncoder()],
     ['name', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
     ['authorizedSigners', getArrayEncoder(getAddressEncoder())],
+    ['payer', getAddressEncoder()],
+    ['adminThreshold', getU8Encoder()],
+    ['admins', getArrayEncoder(getAddressEncoder())],
+    ['pendingAuthority', getAddressEncoder()],
+    ['schemaCount', getU32Encoder()],
+    ['signerPermissions', getArrayEncoder(getSignerPermissionEncoder())],
   ]);
 }
 
@@ -64,6 +93,12 @@ export function getCredentialDecoder(): Decoder<Credential> {
     ['authority', getAddressDecoder()],
     ['name', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
     ['authorizedSigners', getArrayDecoder(getAddressDecoder())],
+    ['payer', getAddressDecoder()],
+    ['adminThreshold', getU8Decoder()],
+    ['admins', getArrayDecoder(getAddressDecoder())],
+    ['pendingAuthority', getAddressDecoder()],
+    ['schemaCount', getU32Decoder()],
+    ['signerPermissions', getArrayDecoder(getSignerPermissionDecoder())],
   ]);
 }
 
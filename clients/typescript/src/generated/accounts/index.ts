@@ -7,5 +7,7 @@
  */
 
 export * from './attestation';
+export * from './attestationTree';
 export * from './credential';
 export * from './schema';
+export * from './signatureReceipt';
@@ -25,6 +25,8 @@ import {
   getStructEncoder,
   getU32Decoder,
   getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   type Account,
@@ -49,9 +51,28 @@ export type Schema = {
   fieldNames: ReadonlyUint8Array;
   isPaused: boolean;
   version: number;
+  payer: Address;
+  requiresSubjectSigner: boolean;
+  constraints: ReadonlyUint8Array;
+  enumVariants: ReadonlyUint8Array;
+  attestationCount: bigint;
 };
 
-export type SchemaArgs = Schema;
+export type SchemaArgs = {
+  discriminator: number;
+  credential: Address;
+  name: ReadonlyUint8Array;
+  description: ReadonlyUint8Array;
+  layout: ReadonlyUint8Array;
+  fieldNames: ReadonlyUint8Array;
+  isPaused: boolean;
+  version: number;
+  payer: Address;
+  requiresSubjectSigner: boolean;
+  constraints: ReadonlyUint8Array;
+  enumVariants: ReadonlyUint8Array;
+  attestationCount: number | bigint;
+};
 
 export function getSchemaEncoder(): Encoder<SchemaArgs> {
   return getStructEncoder([
@@ -63,6 +84,11 @@ export function getSchemaEncoder(): Encoder<SchemaArgs> {
     ['fieldNames', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
     ['isPaused', getBooleanEncoder()],
     ['version', getU8Encoder()],
+    ['payer', getAddressEncoder()],
+    ['requiresSubjectSigner', getBooleanEncoder()],
+    ['constraints', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+    ['enumVariants', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+    ['attestationCount', getU64Encoder()],
   ]);
 }
 
@@ -76,6 +102,11 @@ export function getSchemaDecoder(): Decoder<Schema> {
     ['fieldNames', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
     ['isPaused', getBooleanDecoder()],
     ['version', getU8Decoder()],
+    ['payer', getAddressDecoder()],
+    ['requiresSubjectSigner', getBooleanDecoder()],
+    ['constraints', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+    ['enumVariants', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+    ['attestationCount', getU64Decoder()],
   ]);
 }
 
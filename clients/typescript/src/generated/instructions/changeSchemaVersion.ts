@@ -14,6 +14,8 @@ import {
   getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
@@ -32,6 +34,8 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
@@ -72,7 +76,7 @@ export type ChangeSchemaVersionInstruction<
             IAccountSignerMeta<TAccountAuthority>
         : TAccountAuthority,
       TAccountCredential extends string
-        ? ReadonlyAccount<TAccountCredential>
+        ? WritableAccount<TAccountCredential>
         : TAccountCredential,
       TAccountExistingSchema extends string
         ? ReadonlyAccount<TAccountExistingSchema>
@@ -91,11 +95,15 @@ export type ChangeSchemaVersionInstructionData = {
   discriminator: number;
   layout: ReadonlyUint8Array;
   fieldNames: Array<string>;
+  constraints: Option<ReadonlyUint8Array>;
+  enumVariants: Option<Array<string>>;
 };
 
 export type ChangeSchemaVersionInstructionDataArgs = {
   layout: ReadonlyUint8Array;
   fieldNames: Array<string>;
+  constraints: OptionOrNullable<ReadonlyUint8Array>;
+  enumVariants: OptionOrNullable<Array<string>>;
 };
 
 export function getChangeSchemaVersionInstructionDataEncoder(): Encoder<ChangeSchemaVersionInstructionDataArgs> {
@@ -109,6 +117,20 @@ export function getChangeSchemaVersionInstructionDataEncoder(): Encoder<ChangeSc
           addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
         ),
       ],
+      [
+        'constraints',
+        getOptionEncoder(
+          addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())
+        ),
+      ],
+      [
+        'enumVariants',
+        getOptionEncoder(
+          getArrayEncoder(
+            addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
+          )
+        ),
+      ],
     ]),
     (value) => ({
       ...value,
@@ -125,6 +147,18 @@ export function getChangeSchemaVersionInstructionDataDecoder(): Decoder<ChangeSc
       'fieldNames',
       getArrayDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
     ],
+    [
+      'constraints',
+      getOptionDecoder(
+        addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())
+      ),
+    ],
+    [
+      'enumVariants',
+      getOptionDecoder(
+        getArrayDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder()))
+      ),
+    ],
   ]);
 }
 
@@ -155,6 +189,8 @@ export type ChangeSchemaVersionInput<
   systemProgram?: Address<TAccountSystemProgram>;
   layout: ChangeSchemaVersionInstructionDataArgs['layout'];
   fieldNames: ChangeSchemaVersionInstructionDataArgs['fieldNames'];
+  constraints: ChangeSchemaVersionInstructionDataArgs['constraints'];
+  enumVariants: ChangeSchemaVersionInstructionDataArgs['enumVariants'];
 };
 
 export function getChangeSchemaVersionInstruction<
@@ -193,7 +229,7 @@ export function getChangeSchemaVersionInstruction<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     authority: { value: input.authority ?? null, isWritable: false },
-    credential: { value: input.credential ?? null, isWritable: false },
+    credential: { value: input.credential ?? null, isWritable: true },
     existingSchema: { value: input.existingSchema ?? null, isWritable: false },
     newSchema: { value: input.newSchema ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
@@ -26,7 +26,6 @@ import {
   type ReadonlySignerAccount,
   type TransactionSigner,
   type WritableAccount,
-  type WritableSignerAccount,
 } from '@solana/kit';
 import { SOLANA_ATTESTATION_SERVICE_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -48,14 +47,14 @@ export type CloseAttestationInstruction<
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
   TAccountAttestationProgram extends string | IAccountMeta<string> = string,
+  TAccountSchema extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
   IInstructionWithAccounts<
     [
       TAccountPayer extends string
-        ? WritableSignerAccount<TAccountPayer> &
-            IAccountSignerMeta<TAccountPayer>
+        ? WritableAccount<TAccountPayer>
         : TAccountPayer,
       TAccountAuthority extends string
         ? ReadonlySignerAccount<TAccountAuthority> &
@@ -76,6 +75,9 @@ export type CloseAttestationInstruction<
       TAccountAttestationProgram extends string
         ? ReadonlyAccount<TAccountAttestationProgram>
         : TAccountAttestationProgram,
+      TAccountSchema extends string
+        ? WritableAccount<TAccountSchema>
+        : TAccountSchema,
       ...TRemainingAccounts,
     ]
   >;
@@ -113,15 +115,19 @@ export type CloseAttestationInput<
   TAccountEventAuthority extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountAttestationProgram extends string = string,
+  TAccountSchema extends string = string,
 > = {
-  payer: TransactionSigner<TAccountPayer>;
-  /** Authorized signer of the Schema's Credential */
+  /** Receives the rent. Must be the original payer unless the Credential admins approve */
+  payer: Address<TAccountPayer>;
+  /** Authorized signer of the Schema's Credential or the Attestation's subject */
   authority: TransactionSigner<TAccountAuthority>;
   credential: Address<TAccountCredential>;
   attestation: Address<TAccountAttestation>;
   eventAuthority: Address<TAccountEventAuthority>;
   systemProgram?: Address<TAccountSystemProgram>;
   attestationProgram: Address<TAccountAttestationProgram>;
+  /** Schema the Attestation is associated with */
+  schema: Address<TAccountSchema>;
 };
 
 export function getCloseAttestationInstruction<
@@ -132,6 +138,7 @@ export function getCloseAttestationInstruction<
   TAccountEventAuthority extends string,
   TAccountSystemProgram extends string,
   TAccountAttestationProgram extends string,
+  TAccountSchema extends string,
   TProgramAddress extends
     Address = typeof SOLANA_ATTESTATION_SERVICE_PROGRAM_ADDRESS,
 >(
@@ -142,7 +149,8 @@ export function getCloseAttestationInstruction<
     TAccountAttestation,
     TAccountEventAuthority,
     TAccountSystemProgram,
-    TAccountAttestationProgram
+    TAccountAttestationProgram,
+    TAccountSchema
   >,
   config?: { programAddress?: TProgramAddress }
 ): CloseAttestationInstruction<
@@ -153,7 +161,8 @@ export function getCloseAttestationInstruction<
   TAccountAttestation,
   TAccountEventAuthority,
   TAccountSystemProgram,
-  TAccountAttestationProgram
+  TAccountAttestationProgram,
+  TAccountSchema
 > {
   // Program address.
   const programAddress =
@@ -171,6 +180,7 @@ export function getCloseAttestationInstruction<
       value: input.attestationProgram ?? null,
       isWritable: false,
     },
+    schema: { value: input.schema ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -193,6 +203,7 @@ export function getCloseAttestationInstruction<
       getAccountMeta(accounts.eventAuthority),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.attestationProgram),
+      getAccountMeta(accounts.schema),
     ],
     programAddress,
     data: getCloseAttestationInstructionDataEncoder().encode({}),
@@ -204,7 +215,8 @@ export function getCloseAttestationInstruction<
     TAccountAttestation,
     TAccountEventAuthority,
     TAccountSystemProgram,
-    TAccountAttestationProgram
+    TAccountAttestationProgram,
+    TAccountSchema
   >;
 
   return instruction;
@@ -216,14 +228,17 @@ export type ParsedCloseAttestationInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
+    /** Receives the rent. Must be the original payer unless the Credential admins approve */
     payer: TAccountMetas[0];
-    /** Authorized signer of the Schema's Credential */
+    /** Authorized signer of the Schema's Credential or the Attestation's subject */
     authority: TAccountMetas[1];
     credential: TAccountMetas[2];
     attestation: TAccountMetas[3];
     eventAuthority: TAccountMetas[4];
     systemProgram: TAccountMetas[5];
     attestationProgram: TAccountMetas[6];
+    /** Schema the Attestation is associated with */
+    schema: TAccountMetas[7];
   };
   data: CloseAttestationInstructionData;
 };
@@ -236,7 +251,7 @@ export function parseCloseAttestationInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedCloseAttestationInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -256,6 +271,7 @@ export function parseCloseAttestationInstruction<
       eventAuthority: getNextAccount(),
       systemProgram: getNextAccount(),
       attestationProgram: getNextAccount(),
+      schema: getNextAccount(),
     },
     data: getCloseAttestationInstructionDataDecoder().decode(instruction.data),
   };
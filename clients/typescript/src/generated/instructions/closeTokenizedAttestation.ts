@@ -26,7 +26,6 @@ import {
   type ReadonlySignerAccount,
   type TransactionSigner,
   type WritableAccount,
-  type WritableSignerAccount,
 } from '@solana/kit';
 import { SOLANA_ATTESTATION_SERVICE_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -56,14 +55,14 @@ export type CloseTokenizedAttestationInstruction<
   TAccountTokenProgram extends
     | string
     | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountSchema extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
   IInstructionWithAccounts<
     [
       TAccountPayer extends string
-        ? WritableSignerAccount<TAccountPayer> &
-            IAccountSignerMeta<TAccountPayer>
+        ? WritableAccount<TAccountPayer>
         : TAccountPayer,
       TAccountAuthority extends string
         ? ReadonlySignerAccount<TAccountAuthority> &
@@ -96,6 +95,9 @@ export type CloseTokenizedAttestationInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountSchema extends string
+        ? WritableAccount<TAccountSchema>
+        : TAccountSchema,
       ...TRemainingAccounts,
     ]
   >;
@@ -142,9 +144,11 @@ export type CloseTokenizedAttestationInput<
   TAccountSasPda extends string = string,
   TAccountAttestationTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountSchema extends string = string,
 > = {
-  payer: TransactionSigner<TAccountPayer>;
-  /** Authorized signer of the Schema's Credential */
+  /** Receives the rent. Must be the original payer unless the Credential admins approve */
+  payer: Address<TAccountPayer>;
+  /** Authorized signer of the Schema's Credential or the Attestation's subject */
   authority: TransactionSigner<TAccountAuthority>;
   credential: Address<TAccountCredential>;
   attestation: Address<TAccountAttestation>;
@@ -158,6 +162,8 @@ export type CloseTokenizedAttestationInput<
   /** Associated token account of the related Attestation Token */
   attestationTokenAccount: Address<TAccountAttestationTokenAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  /** Schema the Attestation is associated with */
+  schema: Address<TAccountSchema>;
 };
 
 export function getCloseTokenizedAttestationInstruction<
@@ -172,6 +178,7 @@ export function getCloseTokenizedAttestationInstruction<
   TAccountSasPda extends string,
   TAccountAttestationTokenAccount extends string,
   TAccountTokenProgram extends string,
+  TAccountSchema extends string,
   TProgramAddress extends
     Address = typeof SOLANA_ATTESTATION_SERVICE_PROGRAM_ADDRESS,
 >(
@@ -186,7 +193,8 @@ export function getCloseTokenizedAttestationInstruction<
     TAccountAttestationMint,
     TAccountSasPda,
     TAccountAttestationTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountSchema
   >,
   config?: { programAddress?: TProgramAddress }
 ): CloseTokenizedAttestationInstruction<
@@ -201,7 +209,8 @@ export function getCloseTokenizedAttestationInstruction<
   TAccountAttestationMint,
   TAccountSasPda,
   TAccountAttestationTokenAccount,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountSchema
 > {
   // Program address.
   const programAddress =
@@ -226,6 +235,7 @@ export function getCloseTokenizedAttestationInstruction<
       isWritable: true,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    schema: { value: input.schema ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -256,6 +266,7 @@ export function getCloseTokenizedAttestationInstruction<
       getAccountMeta(accounts.sasPda),
       getAccountMeta(accounts.attestationTokenAccount),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.schema),
     ],
     programAddress,
     data: getCloseTokenizedAttestationInstructionDataEncoder().encode({}),
@@ -271,7 +282,8 @@ export function getCloseTokenizedAttestationInstruction<
     TAccountAttestationMint,
     TAccountSasPda,
     TAccountAttestationTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountSchema
   >;
 
   return instruction;
@@ -283,8 +295,9 @@ export type ParsedCloseTokenizedAttestationInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
+    /** Receives the rent. Must be the original payer unless the Credential admins approve */
     payer: TAccountMetas[0];
-    /** Authorized signer of the Schema's Credential */
+    /** Authorized signer of the Schema's Credential or the Attestation's subject */
     authority: TAccountMetas[1];
     credential: TAccountMetas[2];
     attestation: TAccountMetas[3];
@@ -298,6 +311,8 @@ export type ParsedCloseTokenizedAttestationInstruction<
     /** Associated token account of the related Attestation Token */
     attestationTokenAccount: TAccountMetas[9];
     tokenProgram: TAccountMetas[10];
+    /** Schema the Attestation is associated with */
+    schema: TAccountMetas[11];
   };
   data: CloseTokenizedAttestationInstructionData;
 };
@@ -310,7 +325,7 @@ export function parseCloseTokenizedAttestationInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedCloseTokenizedAttestationInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -334,6 +349,7 @@ export function parseCloseTokenizedAttestationInstruction<
       sasPda: getNextAccount(),
       attestationTokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
+      schema: getNextAccount(),
     },
     data: getCloseTokenizedAttestationInstructionDataDecoder().decode(
       instruction.data
@@ -12,10 +12,14 @@ import {
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
@@ -32,6 +36,8 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
@@ -75,7 +81,7 @@ export type CreateAttestationInstruction<
         ? ReadonlyAccount<TAccountCredential>
         : TAccountCredential,
       TAccountSchema extends string
-        ? ReadonlyAccount<TAccountSchema>
+        ? WritableAccount<TAccountSchema>
         : TAccountSchema,
       TAccountAttestation extends string
         ? WritableAccount<TAccountAttestation>
@@ -92,12 +98,14 @@ export type CreateAttestationInstructionData = {
   nonce: Address;
   data: ReadonlyUint8Array;
   expiry: bigint;
+  references: Option<Array<Address>>;
 };
 
 export type CreateAttestationInstructionDataArgs = {
   nonce: Address;
   data: ReadonlyUint8Array;
   expiry: number | bigint;
+  references: OptionOrNullable<Array<Address>>;
 };
 
 export function getCreateAttestationInstructionDataEncoder(): Encoder<CreateAttestationInstructionDataArgs> {
@@ -107,6 +115,7 @@ export function getCreateAttestationInstructionDataEncoder(): Encoder<CreateAtte
       ['nonce', getAddressEncoder()],
       ['data', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
       ['expiry', getI64Encoder()],
+      ['references', getOptionEncoder(getArrayEncoder(getAddressEncoder()))],
     ]),
     (value) => ({ ...value, discriminator: CREATE_ATTESTATION_DISCRIMINATOR })
   );
@@ -118,6 +127,7 @@ export function getCreateAttestationInstructionDataDecoder(): Decoder<CreateAtte
     ['nonce', getAddressDecoder()],
     ['data', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
     ['expiry', getI64Decoder()],
+    ['references', getOptionDecoder(getArrayDecoder(getAddressDecoder()))],
   ]);
 }
 
@@ -151,6 +161,7 @@ export type CreateAttestationInput<
   nonce: CreateAttestationInstructionDataArgs['nonce'];
   data: CreateAttestationInstructionDataArgs['data'];
   expiry: CreateAttestationInstructionDataArgs['expiry'];
+  references: CreateAttestationInstructionDataArgs['references'];
 };
 
 export function getCreateAttestationInstruction<
@@ -190,7 +201,7 @@ export function getCreateAttestationInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     authority: { value: input.authority ?? null, isWritable: false },
     credential: { value: input.credential ?? null, isWritable: false },
-    schema: { value: input.schema ?? null, isWritable: false },
+    schema: { value: input.schema ?? null, isWritable: true },
     attestation: { value: input.attestation ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -12,8 +12,12 @@ import {
   combineCodec,
   getArrayDecoder,
   getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
@@ -32,6 +36,8 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
@@ -71,7 +77,7 @@ export type CreateSchemaInstruction<
             IAccountSignerMeta<TAccountAuthority>
         : TAccountAuthority,
       TAccountCredential extends string
-        ? ReadonlyAccount<TAccountCredential>
+        ? WritableAccount<TAccountCredential>
         : TAccountCredential,
       TAccountSchema extends string
         ? WritableAccount<TAccountSchema>
@@ -89,6 +95,9 @@ export type CreateSchemaInstructionData = {
   description: string;
   layout: ReadonlyUint8Array;
   fieldNames: Array<string>;
+  constraints: Option<ReadonlyUint8Array>;
+  enumVariants: Option<Array<string>>;
+  requiresSubjectSigner: Option<boolean>;
 };
 
 export type CreateSchemaInstructionDataArgs = {
@@ -96,6 +105,9 @@ export type CreateSchemaInstructionDataArgs = {
   description: string;
   layout: ReadonlyUint8Array;
   fieldNames: Array<string>;
+  constraints: OptionOrNullable<ReadonlyUint8Array>;
+  enumVariants: OptionOrNullable<Array<string>>;
+  requiresSubjectSigner: OptionOrNullable<boolean>;
 };
 
 export function getCreateSchemaInstructionDataEncoder(): Encoder<CreateSchemaInstructionDataArgs> {
@@ -111,6 +123,21 @@ export function getCreateSchemaInstructionDataEncoder(): Encoder<CreateSchemaIns
           addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
         ),
       ],
+      [
+        'constraints',
+        getOptionEncoder(
+          addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())
+        ),
+      ],
+      [
+        'enumVariants',
+        getOptionEncoder(
+          getArrayEncoder(
+            addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
+          )
+        ),
+      ],
+      ['requiresSubjectSigner', getOptionEncoder(getBooleanEncoder())],
     ]),
     (value) => ({ ...value, discriminator: CREATE_SCHEMA_DISCRIMINATOR })
   );
@@ -126,6 +153,19 @@ export function getCreateSchemaInstructionDataDecoder(): Decoder<CreateSchemaIns
       'fieldNames',
       getArrayDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
     ],
+    [
+      'constraints',
+      getOptionDecoder(
+        addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())
+      ),
+    ],
+    [
+      'enumVariants',
+      getOptionDecoder(
+        getArrayDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder()))
+      ),
+    ],
+    ['requiresSubjectSigner', getOptionDecoder(getBooleanDecoder())],
   ]);
 }
 
@@ -156,6 +196,9 @@ export type CreateSchemaInput<
   description: CreateSchemaInstructionDataArgs['description'];
   layout: CreateSchemaInstructionDataArgs['layout'];
   fieldNames: CreateSchemaInstructionDataArgs['fieldNames'];
+  constraints: CreateSchemaInstructionDataArgs['constraints'];
+  enumVariants: CreateSchemaInstructionDataArgs['enumVariants'];
+  requiresSubjectSigner: CreateSchemaInstructionDataArgs['requiresSubjectSigner'];
 };
 
 export function getCreateSchemaInstruction<
@@ -191,7 +234,7 @@ export function getCreateSchemaInstruction<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     authority: { value: input.authority ?? null, isWritable: false },
-    credential: { value: input.credential ?? null, isWritable: false },
+    credential: { value: input.credential ?? null, isWritable: true },
     schema: { value: input.schema ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -12,10 +12,14 @@ import {
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
@@ -36,6 +40,8 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
@@ -88,7 +94,7 @@ export type CreateTokenizedAttestationInstruction<
         ? ReadonlyAccount<TAccountCredential>
         : TAccountCredential,
       TAccountSchema extends string
-        ? ReadonlyAccount<TAccountSchema>
+        ? WritableAccount<TAccountSchema>
         : TAccountSchema,
       TAccountAttestation extends string
         ? WritableAccount<TAccountAttestation>
@@ -130,6 +136,7 @@ export type CreateTokenizedAttestationInstructionData = {
   uri: string;
   symbol: string;
   mintAccountSpace: number;
+  references: Option<Array<Address>>;
 };
 
 export type CreateTokenizedAttestationInstructionDataArgs = {
@@ -140,6 +147,7 @@ export type CreateTokenizedAttestationInstructionDataArgs = {
   uri: string;
   symbol: string;
   mintAccountSpace: number;
+  references: OptionOrNullable<Array<Address>>;
 };
 
 export function getCreateTokenizedAttestationInstructionDataEncoder(): Encoder<CreateTokenizedAttestationInstructionDataArgs> {
@@ -153,6 +161,7 @@ export function getCreateTokenizedAttestationInstructionDataEncoder(): Encoder<C
       ['uri', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['symbol', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['mintAccountSpace', getU16Encoder()],
+      ['references', getOptionEncoder(getArrayEncoder(getAddressEncoder()))],
     ]),
     (value) => ({
       ...value,
@@ -171,6 +180,7 @@ export function getCreateTokenizedAttestationInstructionDataDecoder(): Decoder<C
     ['uri', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['symbol', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['mintAccountSpace', getU16Decoder()],
+    ['references', getOptionDecoder(getArrayDecoder(getAddressDecoder()))],
   ]);
 }
 
@@ -227,6 +237,7 @@ export type CreateTokenizedAttestationInput<
   uri: CreateTokenizedAttestationInstructionDataArgs['uri'];
   symbol: CreateTokenizedAttestationInstructionDataArgs['symbol'];
   mintAccountSpace: CreateTokenizedAttestationInstructionDataArgs['mintAccountSpace'];
+  references: CreateTokenizedAttestationInstructionDataArgs['references'];
 };
 
 export function getCreateTokenizedAttestationInstruction<
@@ -287,7 +298,7 @@ export function getCreateTokenizedAttestationInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     authority: { value: input.authority ?? null, isWritable: false },
     credential: { value: input.credential ?? null, isWritable: false },
-    schema: { value: input.schema ?? null, isWritable: false },
+    schema: { value: input.schema ?? null, isWritable: true },
     attestation: { value: input.attestation ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     schemaMint: { value: input.schemaMint ?? null, isWritable: true },
@@ -6,15 +6,35 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptCredentialAuthority';
 export * from './changeAuthorizedSigners';
+export * from './changeCredentialAdmins';
 export * from './changeSchemaDescription';
 export * from './changeSchemaStatus';
 export * from './changeSchemaVersion';
+export * from './changeSignerPermissions';
 export * from './closeAttestation';
+export * from './closeAttestationsBatch';
+export * from './closeCredential';
+export * from './closeExpiredAttestation';
+export * from './closeSchema';
+export * from './closeSignatureReceipt';
 export * from './closeTokenizedAttestation';
 export * from './createAttestation';
+export * from './createAttestationTree';
+export * from './createAttestationWithSignature';
+export * from './createAttestationsBatch';
+export * from './createCompressedAttestation';
 export * from './createCredential';
 export * from './createSchema';
 export * from './createTokenizedAttestation';
 export * from './emitEvent';
+export * from './migrateAccount';
+export * from './proposeCredentialAuthority';
+export * from './renewAttestation';
+export * from './revokeAttestation';
+export * from './revokeCompressedAttestation';
 export * from './tokenizeSchema';
+export * from './updateAttestation';
+export * from './verifyAttestation';
+export * from './verifyAttestationPredicate';
@@ -13,18 +13,38 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAcceptCredentialAuthorityInstruction,
   type ParsedChangeAuthorizedSignersInstruction,
+  type ParsedChangeCredentialAdminsInstruction,
   type ParsedChangeSchemaDescriptionInstruction,
   type ParsedChangeSchemaStatusInstruction,
   type ParsedChangeSchemaVersionInstruction,
+  type ParsedChangeSignerPermissionsInstruction,
   type ParsedCloseAttestationInstruction,
+  type ParsedCloseAttestationsBatchInstruction,
+  type ParsedCloseCredentialInstruction,
+  type ParsedCloseExpiredAttestationInstruction,
+  type ParsedCloseSchemaInstruction,
+  type ParsedCloseSignatureReceiptInstruction,
   type ParsedCloseTokenizedAttestationInstruction,
   type ParsedCreateAttestationInstruction,
+  type ParsedCreateAttestationTreeInstruction,
+  type ParsedCreateAttestationWithSignatureInstruction,
+  type ParsedCreateAttestationsBatchInstruction,
+  type ParsedCreateCompressedAttestationInstruction,
   type ParsedCreateCredentialInstruction,
   type ParsedCreateSchemaInstruction,
   type ParsedCreateTokenizedAttestationInstruction,
   type ParsedEmitEventInstruction,
+  type ParsedMigrateAccountInstruction,
+  type ParsedProposeCredentialAuthorityInstruction,
+  type ParsedRenewAttestationInstruction,
+  type ParsedRevokeAttestationInstruction,
+  type ParsedRevokeCompressedAttestationInstruction,
   type ParsedTokenizeSchemaInstruction,
+  type ParsedUpdateAttestationInstruction,
+  type ParsedVerifyAttestationInstruction,
+  type ParsedVerifyAttestationPredicateInstruction,
 } from '../instructions';
 
 export const SOLANA_ATTESTATION_SERVICE_PROGRAM_ADDRESS =
@@ -34,6 +54,8 @@ export enum SolanaAttestationServiceAccount {
   Attestation,
   Credential,
   Schema,
+  AttestationTree,
+  SignatureReceipt,
 }
 
 export enum SolanaAttestationServiceInstruction {
@@ -49,6 +71,26 @@ export enum SolanaAttestationServiceInstruction {
   TokenizeSchema,
   CreateTokenizedAttestation,
   CloseTokenizedAttestation,
+  RevokeAttestation,
+  UpdateAttestation,
+  CloseExpiredAttestation,
+  RenewAttestation,
+  CreateAttestationsBatch,
+  CloseAttestationsBatch,
+  CreateAttestationWithSignature,
+  CreateAttestationTree,
+  CreateCompressedAttestation,
+  RevokeCompressedAttestation,
+  VerifyAttestation,
+  VerifyAttestationPredicate,
+  ChangeCredentialAdmins,
+  ProposeCredentialAuthority,
+  AcceptCredentialAuthority,
+  CloseSchema,
+  CloseCredential,
+  ChangeSignerPermissions,
+  MigrateAccount,
+  CloseSignatureReceipt,
 }
 
 export function identifySolanaAttestationServiceInstruction(
@@ -91,6 +133,66 @@ export function identifySolanaAttestationServiceInstruction(
   if (containsBytes(data, getU8Encoder().encode(11), 0)) {
     return SolanaAttestationServiceInstruction.CloseTokenizedAttestation;
   }
+  if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+    return SolanaAttestationServiceInstruction.RevokeAttestation;
+  }
+  if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+    return SolanaAttestationServiceInstruction.UpdateAttestation;
+  }
+  if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+    return SolanaAttestationServiceInstruction.CloseExpiredAttestation;
+  }
+  if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+    return SolanaAttestationServiceInstruction.RenewAttestation;
+  }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return SolanaAttestationServiceInstruction.CreateAttestationsBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return SolanaAttestationServiceInstruction.CloseAttestationsBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return SolanaAttestationServiceInstruction.CreateAttestationWithSignature;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return SolanaAttestationServiceInstruction.CreateAttestationTree;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return SolanaAttestationServiceInstruction.CreateCompressedAttestation;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return SolanaAttestationServiceInstruction.RevokeCompressedAttestation;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return SolanaAttestationServiceInstruction.VerifyAttestation;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return SolanaAttestationServiceInstruction.VerifyAttestationPredicate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return SolanaAttestationServiceInstruction.ChangeCredentialAdmins;
+  }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return SolanaAttestationServiceInstruction.ProposeCredentialAuthority;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return SolanaAttestationServiceInstruction.AcceptCredentialAuthority;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return SolanaAttestationServiceInstruction.CloseSchema;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return SolanaAttestationServiceInstruction.CloseCredential;
+  }
+  if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+    return SolanaAttestationServiceInstruction.ChangeSignerPermissions;
+  }
+  if (containsBytes(data, getU8Encoder().encode(30), 0)) {
+    return SolanaAttestationServiceInstruction.MigrateAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(31), 0)) {
+    return SolanaAttestationServiceInstruction.CloseSignatureReceipt;
+  }
   throw new Error(
     'The provided instruction could not be identified as a solanaAttestationService instruction.'
   );
@@ -134,4 +236,64 @@ export type ParsedSolanaAttestationServiceInstruction<
     } & ParsedCreateTokenizedAttestationInstruction<TProgram>)
   | ({
       instructionType: SolanaAttestationServiceInstruction.CloseTokenizedAttestation;
-    } & ParsedCloseTokenizedAttestationInstruction<TProgram>);
+    } & ParsedCloseTokenizedAttestationInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.RevokeAttestation;
+    } & ParsedRevokeAttestationInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.UpdateAttestation;
+    } & ParsedUpdateAttestationInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.CloseExpiredAttestation;
+    } & ParsedCloseExpiredAttestationInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.RenewAttestation;
+    } & ParsedRenewAttestationInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.CreateAttestationsBatch;
+    } & ParsedCreateAttestationsBatchInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.CloseAttestationsBatch;
+    } & ParsedCloseAttestationsBatchInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.CreateAttestationWithSignature;
+    } & ParsedCreateAttestationWithSignatureInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.CreateAttestationTree;
+    } & ParsedCreateAttestationTreeInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.CreateCompressedAttestation;
+    } & ParsedCreateCompressedAttestationInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.RevokeCompressedAttestation;
+    } & ParsedRevokeCompressedAttestationInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.VerifyAttestation;
+    } & ParsedVerifyAttestationInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.VerifyAttestationPredicate;
+    } & ParsedVerifyAttestationPredicateInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.ChangeCredentialAdmins;
+    } & ParsedChangeCredentialAdminsInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.ProposeCredentialAuthority;
+    } & ParsedProposeCredentialAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.AcceptCredentialAuthority;
+    } & ParsedAcceptCredentialAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.CloseSchema;
+    } & ParsedCloseSchemaInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.CloseCredential;
+    } & ParsedCloseCredentialInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.ChangeSignerPermissions;
+    } & ParsedChangeSignerPermissionsInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.MigrateAccount;
+    } & ParsedMigrateAccountInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.CloseSignatureReceipt;
+    } & ParsedCloseSignatureReceiptInstruction<TProgram>);
@@ -6,4 +6,17 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './changeLog';
 export * from './closeAttestationEvent';
+export * from './closeAttestationsBatchEvent';
+export * from './closeCredentialEvent';
+export * from './closeSchemaEvent';
+export * from './closedAttestation';
+export * from './compressedAttestation';
+export * from './compressedAttestationEvent';
+export * from './createAttestationEntry';
+export * from './renewAttestationEvent';
+export * from './signerPermission';
+export * from './subjectCloseAttestationEvent';
+export * from './updateAttestationEvent';
+export * from './verifyAttestationResult';
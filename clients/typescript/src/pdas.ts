@@ -37,7 +37,8 @@ export const deriveSasAuthorityAddress = async () =>
 
 /**
  * Derive a Credential (aka Issuer) PDA.
- * @param authority The Address that controls the Credential account.
+ * @param authority The Address that created the Credential account. The PDA
+ * keeps this seed after the authority is transferred.
  * @param name A name for the credential. NOTE that only the first 32 bytes
  * will be used for the PDA due to seed size limits.
  * @returns
@@ -1,5 +1,7 @@
 import { BorshSchema } from "borsher";
 
+import type { ReadonlyUint8Array } from "@solana/kit";
+
 import { Schema } from "./generated";
 
 // A char type does not exist on BorshSchema, so we (de)serialize
@@ -14,7 +16,10 @@ type SchemaOutputTypes =
   | bigint
   | bigint[]
   | boolean
-  | boolean[];
+  | boolean[]
+  | Record<string, unknown>
+  | Record<string, unknown>[]
+  | null;
 /**
  * Maps the SAS compact byte layout to the equivalent data type.
  */
@@ -43,14 +48,114 @@ const compactLayoutMapping: Record<number, BorshSchema<SchemaOutputTypes>> = {
   21: BorshSchema.Vec(BorshSchema.i64),
   22: BorshSchema.Vec(BorshSchema.i128),
   23: BorshSchema.Vec(BorshSchema.bool),
-  24: BorshSchema.Vec(BorshSchema.String),
-  25: CHAR_SCHEMA,
+  24: BorshSchema.Vec(CHAR_SCHEMA),
+  25: BorshSchema.Vec(BorshSchema.String),
+};
+// Option<T> layout values follow, offset from the value of T. They are
+// encoded as a one byte tag followed by the value when Some.
+const OPTION_LAYOUT_OFFSET = 26;
+for (let layoutByte = 0; layoutByte < OPTION_LAYOUT_OFFSET; layoutByte++) {
+  compactLayoutMapping[OPTION_LAYOUT_OFFSET + layoutByte] = BorshSchema.Option(
+    compactLayoutMapping[layoutByte]
+  ) as BorshSchema<SchemaOutputTypes>;
+}
+// Pubkey, [u8; 32] and [u8; 64]
+compactLayoutMapping[52] = BorshSchema.Array(BorshSchema.u8, 32);
+compactLayoutMapping[53] = BorshSchema.Array(BorshSchema.u8, 32);
+compactLayoutMapping[54] = BorshSchema.Array(BorshSchema.u8, 64);
+// Struct and Vec<Struct> are followed in the layout by their number of
+// fields and the fields' layouts, and in the field names by the fields' names.
+const STRUCT_LAYOUT_VALUE = 55;
+const VEC_STRUCT_LAYOUT_VALUE = 56;
+compactLayoutMapping[57] = BorshSchema.f32;
+compactLayoutMapping[58] = BorshSchema.f64;
+// Decimal is an i128 mantissa, followed in the layout by its scale. Use
+// `formatDecimal` to render it.
+const DECIMAL_LAYOUT_VALUE = 59;
+compactLayoutMapping[DECIMAL_LAYOUT_VALUE] = BorshSchema.i128;
+// Timestamp is an i64 of unix seconds. Use `timestampToDate` to render it.
+compactLayoutMapping[60] = BorshSchema.i64;
+// Enum is a u8 variant index, followed in the layout by the number of
+// variants. Values are (de)serialized as `{ [variantName]: {} }`.
+const ENUM_LAYOUT_VALUE = 61;
+const MAX_LAYOUT_VALUE = 61;
+
+type LayoutReader = {
+  layout: ArrayLike<number>;
+  fields: string[];
+  variants: string[];
+  layoutOffset: number;
+  fieldIndex: number;
+  variantIndex: number;
+};
+
+/**
+ * Reads the next field from the Schema layout and field names, including
+ * the fields of a nested struct.
+ */
+const readField = (
+  reader: LayoutReader
+): [string, BorshSchema<SchemaOutputTypes>] => {
+  const layoutByte = reader.layout[reader.layoutOffset++];
+  const field = reader.fields[reader.fieldIndex++];
+  if (field === undefined) {
+    throw new Error("Schema field names and layout do not match");
+  }
+  if (layoutByte === undefined || layoutByte > MAX_LAYOUT_VALUE) {
+    throw new Error("Invalid Schema layout value");
+  }
+  if (layoutByte === DECIMAL_LAYOUT_VALUE) {
+    // Skip the scale, only needed to render the value.
+    if (reader.layout[reader.layoutOffset++] === undefined) {
+      throw new Error("Invalid Schema layout value");
+    }
+  }
+  if (layoutByte === ENUM_LAYOUT_VALUE) {
+    const variantCount = reader.layout[reader.layoutOffset++];
+    if (!variantCount) {
+      throw new Error("Invalid Schema layout value");
+    }
+    const variants: Record<string, BorshSchema<unknown>> = {};
+    for (let i = 0; i < variantCount; i++) {
+      const variant = reader.variants[reader.variantIndex++];
+      if (variant === undefined || variant in variants) {
+        throw new Error("Schema enum variants and layout do not match");
+      }
+      variants[variant] = BorshSchema.Unit;
+    }
+    return [
+      field,
+      BorshSchema.Enum(variants) as BorshSchema<SchemaOutputTypes>,
+    ];
+  }
+  if (
+    layoutByte !== STRUCT_LAYOUT_VALUE &&
+    layoutByte !== VEC_STRUCT_LAYOUT_VALUE
+  ) {
+    return [field, compactLayoutMapping[layoutByte]];
+  }
+
+  const fieldCount = reader.layout[reader.layoutOffset++];
+  if (!fieldCount) {
+    throw new Error("Invalid Schema layout value");
+  }
+  const structFields: Record<string, BorshSchema<SchemaOutputTypes>> = {};
+  for (let i = 0; i < fieldCount; i++) {
+    const [name, borshSchema] = readField(reader);
+    structFields[name] = borshSchema;
+  }
+  const struct = BorshSchema.Struct(structFields);
+  return [
+    field,
+    (layoutByte === STRUCT_LAYOUT_VALUE
+      ? struct
+      : BorshSchema.Vec(struct)) as BorshSchema<SchemaOutputTypes>,
+  ];
 };
-const MAX_LAYOUT_VALUE = 25;
 
 /**
  * Given the onchain representation of a Schema, we generate a Borsh schema
- * for (de)serialization.
+ * for (de)serialization. Enum fields need the Schema's `enumVariants`.
  * @param schema
  * @returns
  */
@@ -58,27 +163,30 @@ export const convertSasSchemaToBorshSchema = (
   schema: Schema
 ): BorshSchema<Record<string, unknown>> => {
   const textDecoder = new TextDecoder();
-  const fields = splitJoinedVecs(Uint8Array.from(schema.fieldNames)).map((f) =>
-    textDecoder.decode(Uint8Array.from(f))
-  );
+  const decodeStrings = (bytes: ReadonlyUint8Array) =>
+    splitJoinedVecs(Uint8Array.from(bytes)).map((f) =>
+      textDecoder.decode(Uint8Array.from(f))
+    );
+  const fields = decodeStrings(schema.fieldNames);
 
-  if (fields.length !== schema.layout.length) {
+  const reader: LayoutReader = {
+    layout: schema.layout,
+    fields,
+    variants: decodeStrings(schema.enumVariants),
+    layoutOffset: 0,
+    fieldIndex: 0,
+    variantIndex: 0,
+  };
+  const structFields: Record<string, BorshSchema<SchemaOutputTypes>> = {};
+  while (reader.layoutOffset < schema.layout.length) {
+    const [field, borshSchema] = readField(reader);
+    structFields[field] = borshSchema;
+  }
+  if (reader.fieldIndex !== fields.length) {
     throw new Error("Schema field names and layout do not match");
   }
 
-  return BorshSchema.Struct(
-    fields.reduce(
-      (acc, field, index) => {
-        const layoutByte = schema.layout[index];
-        if (layoutByte > MAX_LAYOUT_VALUE) {
-          throw new Error("Invalid Schema layout value");
-        }
-        acc[field] = compactLayoutMapping[layoutByte];
-        return acc;
-      },
-      {} as Record<string, BorshSchema<SchemaOutputTypes>>
-    )
-  );
+  return BorshSchema.Struct(structFields);
 };
 
 /**
@@ -107,6 +215,45 @@ export const deserializeAttestationData = <T>(
   return borshSchema.deserialize(data) as T;
 };
 
+/**
+ * Render the mantissa of a Decimal field with `scale` decimal places,
+ * e.g. 1234n with a scale of 2 is "12.34".
+ * @param mantissa
+ * @param scale
+ */
+export const formatDecimal = (mantissa: bigint, scale: number): string => {
+  const sign = mantissa < 0n ? "-" : "";
+  const digits = (mantissa < 0n ? -mantissa : mantissa)
+    .toString()
+    .padStart(scale + 1, "0");
+  if (scale === 0) {
+    return sign + digits;
+  }
+  return `${sign}${digits.slice(0, -scale)}.${digits.slice(-scale)}`;
+};
+
+/**
+ * Parse a decimal string such as "12.34" into the mantissa of a Decimal
+ * field with `scale` decimal places.
+ * @param value
+ * @param scale
+ */
+export const parseDecimal = (value: string, scale: number): bigint => {
+  const match = /^(-?)(\d+)(?:\.(\d+))?$/.exec(value.trim());
+  if (!match || (match[3] ?? "").length > scale) {
+    throw new Error(`Invalid decimal with scale ${scale}: ${value}`);
+  }
+  const mantissa = BigInt(match[2] + (match[3] ?? "").padEnd(scale, "0"));
+  return match[1] ? -mantissa : mantissa;
+};
+
+/**
+ * Convert the unix seconds of a Timestamp field to a Date.
+ * @param timestamp
+ */
+export const timestampToDate = (timestamp: bigint | number): Date =>
+  new Date(Number(timestamp) * 1000);
+
 type ByteLike = Uint8Array | number[];
 
 const splitJoinedVecs = (bytes: ByteLike): ByteLike[] => {
@@ -1,6 +1,10 @@
 import { assert } from "chai";
 import { getSchemaDecoder } from "../src/generated";
-import { convertSasSchemaToBorshSchema } from "../src/utils";
+import {
+  convertSasSchemaToBorshSchema,
+  formatDecimal,
+  parseDecimal,
+} from "../src/utils";
 
 describe("Utils", () => {
   const schemaAccountBytes = Uint8Array.from([
@@ -9,7 +13,10 @@ describe("Utils", () => {
     175, 9, 0, 0, 0, 116, 101, 115, 116, 95, 100, 97, 116, 97, 20, 0, 0, 0, 115,
     99, 104, 101, 109, 97, 32, 102, 111, 114, 32, 116, 101, 115, 116, 32, 100,
     97, 116, 97, 2, 0, 0, 0, 12, 0, 20, 0, 0, 0, 4, 0, 0, 0, 110, 97, 109, 101,
-    8, 0, 0, 0, 108, 111, 99, 97, 116, 105, 111, 110, 0, 1,
+    8, 0, 0, 0, 108, 111, 99, 97, 116, 105, 111, 110, 0, 1, 147, 244, 210, 208,
+    208, 76, 164, 106, 193, 96, 129, 24, 152, 59, 215, 13, 112, 136, 111, 235,
+    117, 29, 128, 253, 99, 200, 171, 204, 126, 178, 74, 175, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
   ]);
 
   describe("convertSasSchemaToBorshSchema", () => {
@@ -25,5 +32,128 @@ describe("Utils", () => {
       const deserialized = borshSchema.deserialize(serialized);
       assert.deepEqual(testData, deserialized);
     });
+
+    it("should support Option fields", () => {
+      const decoder = getSchemaDecoder();
+      const schema = decoder.decode(schemaAccountBytes);
+      // name: Option<String>, location: Option<u8>
+      const borshSchema = convertSasSchemaToBorshSchema({
+        ...schema,
+        layout: Uint8Array.from([38, 26]),
+      });
+      const testData = {
+        name: "hello",
+        location: null,
+      };
+      const serialized = borshSchema.serialize(testData);
+      assert.deepEqual(
+        Array.from(serialized),
+        [1, 5, 0, 0, 0, 104, 101, 108, 108, 111, 0]
+      );
+      assert.deepEqual(testData, borshSchema.deserialize(serialized));
+    });
+
+    it("should support Vec<char> and Vec<String> fields", () => {
+      const decoder = getSchemaDecoder();
+      const schema = decoder.decode(schemaAccountBytes);
+      // name: Vec<char>, location: Vec<String>
+      const borshSchema = convertSasSchemaToBorshSchema({
+        ...schema,
+        layout: Uint8Array.from([24, 25]),
+      });
+      const testData = {
+        name: [[104, 0, 0, 0]],
+        location: ["a"],
+      };
+      const serialized = borshSchema.serialize(testData);
+      assert.deepEqual(
+        Array.from(serialized),
+        [1, 0, 0, 0, 104, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 97]
+      );
+      assert.deepEqual(testData, borshSchema.deserialize(serialized));
+    });
+
+    it("should support nested struct fields", () => {
+      const decoder = getSchemaDecoder();
+      const schema = decoder.decode(schemaAccountBytes);
+      const encoder = new TextEncoder();
+      const fieldNames = ["owner", "name", "licenses", "id"].flatMap((name) => [
+        name.length,
+        0,
+        0,
+        0,
+        ...encoder.encode(name),
+      ]);
+      // owner: Struct { name: String }, licenses: Vec<Struct { id: u8 }>
+      const borshSchema = convertSasSchemaToBorshSchema({
+        ...schema,
+        layout: Uint8Array.from([55, 1, 12, 56, 1, 0]),
+        fieldNames: Uint8Array.from(fieldNames),
+      });
+      const testData = {
+        owner: { name: "hi" },
+        licenses: [{ id: 1 }, { id: 2 }],
+      };
+      const serialized = borshSchema.serialize(testData);
+      assert.deepEqual(
+        Array.from(serialized),
+        [2, 0, 0, 0, 104, 105, 2, 0, 0, 0, 1, 2]
+      );
+      assert.deepEqual(testData, borshSchema.deserialize(serialized));
+    });
+
+    it("should support numeric fields", () => {
+      const decoder = getSchemaDecoder();
+      const schema = decoder.decode(schemaAccountBytes);
+      // name: Decimal with 2 places, location: Timestamp
+      const borshSchema = convertSasSchemaToBorshSchema({
+        ...schema,
+        layout: Uint8Array.from([59, 2, 60]),
+      });
+      const testData = {
+        name: parseDecimal("-12.5", 2),
+        location: 1700000000n,
+      };
+      const deserialized = borshSchema.deserialize(
+        borshSchema.serialize(testData)
+      ) as typeof testData;
+      assert.deepEqual(testData, deserialized);
+      assert.equal(formatDecimal(deserialized.name, 2), "-12.50");
+    });
+
+    it("should support Enum fields", () => {
+      const decoder = getSchemaDecoder();
+      const schema = decoder.decode(schemaAccountBytes);
+      const encoder = new TextEncoder();
+      const enumVariants = ["basic", "pro"].flatMap((name) => [
+        name.length,
+        0,
+        0,
+        0,
+        ...encoder.encode(name),
+      ]);
+      // name: String, location: Enum with 2 variants
+      const borshSchema = convertSasSchemaToBorshSchema({
+        ...schema,
+        layout: Uint8Array.from([12, 61, 2]),
+        enumVariants: Uint8Array.from(enumVariants),
+      });
+      const serialized = Uint8Array.from([2, 0, 0, 0, 104, 105, 1]);
+      assert.deepEqual(borshSchema.deserialize(serialized), {
+        name: "hi",
+        location: { pro: {} },
+      });
+      // Out of range variant
+      assert.throws(() =>
+        borshSchema.deserialize(Uint8Array.from([2, 0, 0, 0, 104, 105, 2]))
+      );
+    });
+  });
+
+  describe("formatDecimal", () => {
+    it("should pad small values", () => {
+      assert.equal(formatDecimal(5n, 3), "0.005");
+      assert.equal(formatDecimal(-1234n, 0), "-1234");
+    });
   });
 });
@@ -1,3 +1,9 @@
+#![cfg_attr(not(test), no_std)]
+
+mod signature;
+
+pub use signature::*;
+
 #[repr(u8)]
 pub enum PrimitiveDataTypes {
     U8 = 1,
@@ -19,6 +25,7 @@ pub enum VariableDataTypes {
 
 #[cfg(test)]
 mod test {
+    use super::*;
     use solana_attestation_service_macros::SchemaStructSerialize;
 
     #[derive(SchemaStructSerialize)]
@@ -32,4 +39,130 @@ mod test {
     fn test_serialization() {
         assert_eq!(CustomData::get_serialized_representation(), vec![3, 5, 12]);
     }
+
+    #[derive(SchemaStructSerialize)]
+    struct OptionalData {
+        _field1: Vec<u16>,
+        _field2: Option<u8>,
+        _field3: Option<String>,
+        _field4: Option<Vec<String>>,
+    }
+
+    #[test]
+    fn test_serialization_option() {
+        assert_eq!(
+            OptionalData::get_serialized_representation(),
+            vec![14, 26, 38, 51]
+        );
+    }
+
+    // Stand-in for solana_program's Pubkey, the derive only sees type names.
+    struct Pubkey;
+
+    #[derive(SchemaStructSerialize)]
+    struct FixedSizeData {
+        _field1: Pubkey,
+        _field2: [u8; 32],
+        _field3: [u8; 64],
+    }
+
+    #[test]
+    fn test_serialization_fixed_size() {
+        assert_eq!(
+            FixedSizeData::get_serialized_representation(),
+            vec![52, 53, 54]
+        );
+    }
+
+    #[derive(SchemaStructSerialize)]
+    struct License {
+        _field1: String,
+        _field2: i64,
+    }
+
+    #[derive(SchemaStructSerialize)]
+    struct NestedData {
+        _field1: u8,
+        _field2: License,
+        _field3: Vec<License>,
+    }
+
+    #[test]
+    fn test_serialization_nested() {
+        assert_eq!(
+            NestedData::get_serialized_representation(),
+            vec![0, 55, 2, 12, 8, 56, 2, 12, 8]
+        );
+    }
+
+    // Stand-ins for the client's data types, the derive only sees type names.
+    struct Timestamp;
+    struct Decimal<const SCALE: u8>;
+
+    #[derive(SchemaStructSerialize)]
+    struct NumericData {
+        _field1: f32,
+        _field2: f64,
+        _field3: Decimal<2>,
+        _field4: Timestamp,
+    }
+
+    #[test]
+    fn test_serialization_numeric() {
+        assert_eq!(
+            NumericData::get_serialized_representation(),
+            vec![57, 58, 59, 2, 60]
+        );
+    }
+
+    #[derive(SchemaStructSerialize)]
+    enum Tier {
+        _Basic,
+        _Pro,
+        _Enterprise,
+    }
+
+    #[derive(SchemaStructSerialize)]
+    struct Membership {
+        _field1: Tier,
+        _field2: Vec<Plan>,
+    }
+
+    #[derive(SchemaStructSerialize)]
+    struct Plan {
+        _field1: Tier,
+    }
+
+    #[test]
+    fn test_create_attestation_message() {
+        let message = create_attestation_message(&[1; 32], &[2; 32], &[3; 32], &[4; 32], 5, -6);
+        let prefix_len = CREATE_ATTESTATION_MESSAGE_PREFIX.len();
+        assert_eq!(message.len(), prefix_len + 32 * 4 + 16);
+        assert_eq!(&message[..prefix_len], CREATE_ATTESTATION_MESSAGE_PREFIX);
+        assert_eq!(&message[prefix_len + 96..prefix_len + 128], &[4; 32]);
+        assert_eq!(
+            &message[prefix_len + 128..prefix_len + 136],
+            &5i64.to_le_bytes()
+        );
+        assert_eq!(&message[prefix_len + 136..], &(-6i64).to_le_bytes());
+    }
+
+    #[test]
+    fn test_serialization_enum() {
+        assert_eq!(
+            Membership::get_serialized_representation(),
+            vec![61, 3, 56, 1, 61, 3]
+        );
+        assert_eq!(
+            Membership::get_enum_variant_names(),
+            vec![
+                "_Basic",
+                "_Pro",
+                "_Enterprise",
+                "_Basic",
+                "_Pro",
+                "_Enterprise"
+            ]
+        );
+    }
 }
@@ -0,0 +1,32 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+/// Domain separator prepended to messages signed for `CreateAttestationWithSignature`.
+pub const CREATE_ATTESTATION_MESSAGE_PREFIX: &[u8] =
+    b"solana-attestation-service:create-attestation";
+
+/// Canonical message an authorized signer signs off-chain to let anyone submit
+/// `CreateAttestationWithSignature`. `data_hash` is the SHA-256 of the
+/// Attestation data.
+///
+/// prefix || credential || schema || nonce || data_hash || expiry || deadline
+pub fn create_attestation_message(
+    credential: &[u8; 32],
+    schema: &[u8; 32],
+    nonce: &[u8; 32],
+    data_hash: &[u8; 32],
+    expiry: i64,
+    deadline: i64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(CREATE_ATTESTATION_MESSAGE_PREFIX.len() + 32 * 4 + 16);
+    message.extend_from_slice(CREATE_ATTESTATION_MESSAGE_PREFIX);
+    message.extend_from_slice(credential);
+    message.extend_from_slice(schema);
+    message.extend_from_slice(nonce);
+    message.extend_from_slice(data_hash);
+    message.extend_from_slice(&expiry.to_le_bytes());
+    message.extend_from_slice(&deadline.to_le_bytes());
+
+    message
+}
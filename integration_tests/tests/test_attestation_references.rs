@@ -0,0 +1,276 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::Attestation,
+    instructions::{CreateAttestationBuilder, CreateCredentialBuilder, CreateSchemaBuilder},
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    authority: Keypair,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .instruction();
+
+    // Create Schema
+    let schema_name = "test_data";
+    let description = "schema for test data";
+    let schema_data = TestData::get_serialized_representation();
+    let field_names = vec!["name".into(), "location".into()];
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description(description.to_string())
+        .name(schema_name.to_string())
+        .layout(schema_data)
+        .field_names(field_names)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        authority,
+    }
+}
+
+/// CreateAttestation builder for a new nonce, with its Attestation PDA.
+fn create_attestation_builder(fixtures: &TestFixtures, expiry: i64) -> CreateAttestationBuilder {
+    let attestation_data = TestData {
+        name: "attest".to_string(),
+        location: 11,
+    };
+    let mut serialized_attestation_data = Vec::new();
+    attestation_data
+        .serialize(&mut serialized_attestation_data)
+        .unwrap();
+    let nonce = Pubkey::new_unique();
+    let attestation_pda = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &fixtures.credential.to_bytes(),
+            &fixtures.schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0;
+
+    let mut builder = CreateAttestationBuilder::new();
+    builder
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(serialized_attestation_data)
+        .expiry(expiry)
+        .nonce(nonce);
+    builder
+}
+
+async fn send(
+    fixtures: &TestFixtures,
+    builder: &CreateAttestationBuilder,
+) -> Result<Pubkey, TransactionError> {
+    let ctx = &fixtures.ctx;
+    let ix = builder.instruction();
+    let attestation_pda = ix.accounts[4].pubkey;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())?;
+    Ok(attestation_pda)
+}
+
+#[tokio::test]
+async fn create_attestation_with_references_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    // KYC Attestation the derived Attestation depends on.
+    let kyc = send(&fixtures, &create_attestation_builder(&fixtures, 0))
+        .await
+        .unwrap();
+
+    let derived = send(
+        &fixtures,
+        create_attestation_builder(&fixtures, 0)
+            .references(vec![kyc])
+            .add_remaining_account(AccountMeta::new_readonly(kyc, false)),
+    )
+    .await
+    .unwrap();
+
+    let attestation_account = ctx
+        .banks_client
+        .get_account(derived)
+        .await
+        .unwrap()
+        .unwrap();
+    let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert_eq!(attestation.references, vec![kyc]);
+
+    // Attestations created without references have none.
+    let kyc_account = ctx.banks_client.get_account(kyc).await.unwrap().unwrap();
+    let kyc_attestation = Attestation::try_from_slice(&kyc_account.data).unwrap();
+    assert!(kyc_attestation.references.is_empty());
+}
+
+#[tokio::test]
+async fn create_attestation_with_references_fail_expired_reference() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let kyc = send(
+        &fixtures,
+        &create_attestation_builder(&fixtures, clock.unix_timestamp + 60),
+    )
+    .await
+    .unwrap();
+
+    let mut warped_clock = clock.clone();
+    warped_clock.unix_timestamp += 120;
+    ctx.set_sysvar(&warped_clock);
+
+    let err = send(
+        &fixtures,
+        create_attestation_builder(&fixtures, 0)
+            .references(vec![kyc])
+            .add_remaining_account(AccountMeta::new_readonly(kyc, false)),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(19))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_references_fail_not_attestation() {
+    let fixtures = setup().await;
+
+    // The Schema is owned by the program but is not an Attestation.
+    let err = send(
+        &fixtures,
+        create_attestation_builder(&fixtures, 0)
+            .references(vec![fixtures.schema])
+            .add_remaining_account(AccountMeta::new_readonly(fixtures.schema, false)),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(19))
+    );
+
+    // Accounts not owned by the program are rejected.
+    let other = Pubkey::new_unique();
+    let err = send(
+        &fixtures,
+        create_attestation_builder(&fixtures, 0)
+            .references(vec![other])
+            .add_remaining_account(AccountMeta::new_readonly(other, false)),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(19))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_references_fail_missing_account() {
+    let fixtures = setup().await;
+
+    let kyc = send(&fixtures, &create_attestation_builder(&fixtures, 0))
+        .await
+        .unwrap();
+
+    let err = send(
+        &fixtures,
+        create_attestation_builder(&fixtures, 0).references(vec![kyc]),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
@@ -0,0 +1,280 @@
+use borsh::BorshDeserialize;
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::Credential,
+    instructions::{
+        ChangeAuthorizedSignersBuilder, ChangeCredentialAdminsBuilder, CreateCredentialBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    authority: Keypair,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            name.as_bytes(),
+        ],
+        &Pubkey::from(SOLANA_ATTESTATION_SERVICE_ID),
+    );
+
+    let ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(name.to_string())
+        .signers(vec![authority.pubkey()])
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        authority,
+    }
+}
+
+async fn send(
+    fixtures: &TestFixtures,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let mut all_signers = vec![&ctx.payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &all_signers,
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+async fn fetch_credential(fixtures: &TestFixtures) -> Credential {
+    let account = fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.credential)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    Credential::try_from_slice(&account.data).unwrap()
+}
+
+fn change_admins_ix(
+    fixtures: &TestFixtures,
+    authority: Pubkey,
+    co_signers: &[Pubkey],
+    admin_threshold: u8,
+    admins: Vec<Pubkey>,
+) -> Instruction {
+    ChangeCredentialAdminsBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(authority)
+        .credential(fixtures.credential)
+        .system_program(system_program::ID)
+        .admin_threshold(admin_threshold)
+        .admins(admins)
+        .add_remaining_accounts(
+            &co_signers
+                .iter()
+                .map(|key| AccountMeta::new_readonly(*key, true))
+                .collect::<Vec<_>>(),
+        )
+        .instruction()
+}
+
+fn change_signers_ix(
+    fixtures: &TestFixtures,
+    authority: Pubkey,
+    co_signers: &[Pubkey],
+    signers: Vec<Pubkey>,
+) -> Instruction {
+    ChangeAuthorizedSignersBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(authority)
+        .credential(fixtures.credential)
+        .system_program(system_program::ID)
+        .signers(signers)
+        .add_remaining_accounts(
+            &co_signers
+                .iter()
+                .map(|key| AccountMeta::new_readonly(*key, true))
+                .collect::<Vec<_>>(),
+        )
+        .instruction()
+}
+
+#[tokio::test]
+async fn change_credential_admins_success() {
+    let fixtures = setup().await;
+    let admins = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let admin_keys: Vec<Pubkey> = admins.iter().map(|a| a.pubkey()).collect();
+
+    // The authority alone can set the initial 2 of 3 admins.
+    let ix = change_admins_ix(
+        &fixtures,
+        fixtures.authority.pubkey(),
+        &[],
+        2,
+        admin_keys.clone(),
+    );
+    send(&fixtures, ix, &[&fixtures.authority]).await.unwrap();
+
+    let credential = fetch_credential(&fixtures).await;
+    assert_eq!(credential.authority, fixtures.authority.pubkey());
+    assert_eq!(credential.admin_threshold, 2);
+    assert_eq!(credential.admins, admin_keys);
+
+    // The authority no longer administers the Credential by itself.
+    let new_signers = vec![Keypair::new().pubkey()];
+    let ix = change_signers_ix(
+        &fixtures,
+        fixtures.authority.pubkey(),
+        &[],
+        new_signers.clone(),
+    );
+    let err = send(&fixtures, ix, &[&fixtures.authority])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(26))
+    );
+
+    // A single admin is below the threshold.
+    let ix = change_signers_ix(&fixtures, admin_keys[0], &[], new_signers.clone());
+    let err = send(&fixtures, ix, &[&admins[0]]).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(26))
+    );
+
+    // Two admins, one as the authority and one as a co-signer.
+    let ix = change_signers_ix(
+        &fixtures,
+        admin_keys[0],
+        &[admin_keys[2]],
+        new_signers.clone(),
+    );
+    send(&fixtures, ix, &[&admins[0], &admins[2]])
+        .await
+        .unwrap();
+    let credential = fetch_credential(&fixtures).await;
+    assert_eq!(credential.authorized_signers, new_signers);
+
+    // Shrinking the admin set requires the current threshold.
+    let ix = change_admins_ix(
+        &fixtures,
+        admin_keys[1],
+        &[admin_keys[2]],
+        1,
+        vec![admin_keys[1]],
+    );
+    send(&fixtures, ix, &[&admins[1], &admins[2]])
+        .await
+        .unwrap();
+    let credential = fetch_credential(&fixtures).await;
+    assert_eq!(credential.admin_threshold, 1);
+    assert_eq!(credential.admins, vec![admin_keys[1]]);
+
+    // Clearing the admins hands administration back to the authority.
+    let ix = change_admins_ix(&fixtures, admin_keys[1], &[], 0, vec![]);
+    send(&fixtures, ix, &[&admins[1]]).await.unwrap();
+    let ix = change_signers_ix(&fixtures, fixtures.authority.pubkey(), &[], vec![]);
+    send(&fixtures, ix, &[&fixtures.authority]).await.unwrap();
+
+    let credential = fetch_credential(&fixtures).await;
+    assert_eq!(credential.admin_threshold, 0);
+    assert!(credential.admins.is_empty());
+    assert!(credential.authorized_signers.is_empty());
+}
+
+#[tokio::test]
+async fn change_credential_admins_fail_duplicate_co_signer() {
+    let fixtures = setup().await;
+    let admins = [Keypair::new(), Keypair::new()];
+    let admin_keys: Vec<Pubkey> = admins.iter().map(|a| a.pubkey()).collect();
+
+    let ix = change_admins_ix(
+        &fixtures,
+        fixtures.authority.pubkey(),
+        &[],
+        2,
+        admin_keys.clone(),
+    );
+    send(&fixtures, ix, &[&fixtures.authority]).await.unwrap();
+
+    // The same admin passed twice only counts once.
+    let ix = change_admins_ix(&fixtures, admin_keys[0], &[admin_keys[0]], 1, admin_keys);
+    let err = send(&fixtures, ix, &[&admins[0]]).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(26))
+    );
+}
+
+#[tokio::test]
+async fn change_credential_admins_fail_invalid_config() {
+    let fixtures = setup().await;
+    let admin_keys = vec![Keypair::new().pubkey(), Keypair::new().pubkey()];
+    let authority = fixtures.authority.pubkey();
+
+    let invalid_configs = [
+        // Threshold above the number of admins.
+        (3, admin_keys.clone()),
+        // Admins without a threshold.
+        (0, admin_keys.clone()),
+        // Threshold without admins.
+        (1, vec![]),
+        // Duplicate admins.
+        (2, vec![admin_keys[0], admin_keys[0]]),
+        // Too many admins.
+        (1, (0..11).map(|_| Keypair::new().pubkey()).collect()),
+    ];
+    for (admin_threshold, admins) in invalid_configs {
+        let ix = change_admins_ix(&fixtures, authority, &[], admin_threshold, admins);
+        let err = send(&fixtures, ix, &[&fixtures.authority])
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(0, InstructionError::Custom(27))
+        );
+    }
+}
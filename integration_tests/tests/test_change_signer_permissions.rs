@@ -0,0 +1,407 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::Credential,
+    instructions::{
+        ChangeAuthorizedSignersBuilder, ChangeSignerPermissionsBuilder, CloseAttestationBuilder,
+        CreateAttestationBuilder, CreateCredentialBuilder, CreateSchemaBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    types::SignerPermission,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    kyc_schema: Pubkey,
+    accreditation_schema: Pubkey,
+    authority: Keypair,
+    kyc_signer: Keypair,
+    closer: Keypair,
+}
+
+fn schema_pda(credential: &Pubkey, name: &str) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"schema", &credential.to_bytes(), name.as_bytes(), &[1]],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let kyc_signer = Keypair::new();
+    let closer = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let mut ixs = vec![CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![
+            authority.pubkey(),
+            kyc_signer.pubkey(),
+            closer.pubkey(),
+        ])
+        .instruction()];
+    for schema_name in ["kyc", "accreditation"] {
+        ixs.push(
+            CreateSchemaBuilder::new()
+                .payer(ctx.payer.pubkey())
+                .authority(authority.pubkey())
+                .credential(credential_pda)
+                .schema(schema_pda(&credential_pda, schema_name))
+                .system_program(system_program::ID)
+                .description("schema for test data".to_string())
+                .name(schema_name.to_string())
+                .layout(TestData::get_serialized_representation())
+                .field_names(vec!["name".into(), "location".into()])
+                .instruction(),
+        );
+    }
+
+    let transaction = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        kyc_schema: schema_pda(&credential_pda, "kyc"),
+        accreditation_schema: schema_pda(&credential_pda, "accreditation"),
+        authority,
+        kyc_signer,
+        closer,
+    }
+}
+
+async fn send(
+    fixtures: &TestFixtures,
+    ix: Instruction,
+    signer: &Keypair,
+) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, signer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+fn change_permissions_ix(
+    fixtures: &TestFixtures,
+    authority: Pubkey,
+    permissions: Vec<SignerPermission>,
+) -> Instruction {
+    ChangeSignerPermissionsBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(authority)
+        .credential(fixtures.credential)
+        .system_program(system_program::ID)
+        .permissions(permissions)
+        .instruction()
+}
+
+fn create_attestation_ix(
+    fixtures: &TestFixtures,
+    signer: Pubkey,
+    schema: Pubkey,
+) -> (Pubkey, Instruction) {
+    let nonce = Pubkey::new_unique();
+    let attestation_pda = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &fixtures.credential.to_bytes(),
+            &schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0;
+    let mut data = Vec::new();
+    TestData {
+        name: "attest".to_string(),
+        location: 11,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    let ix = CreateAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(signer)
+        .credential(fixtures.credential)
+        .schema(schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(data)
+        .expiry(0)
+        .nonce(nonce)
+        .instruction();
+    (attestation_pda, ix)
+}
+
+fn close_attestation_ix(
+    fixtures: &TestFixtures,
+    signer: Pubkey,
+    schema: Pubkey,
+    attestation: Pubkey,
+) -> Instruction {
+    let event_auth_pda =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID).0;
+    CloseAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(signer)
+        .credential(fixtures.credential)
+        .schema(schema)
+        .attestation(attestation)
+        .event_authority(event_auth_pda)
+        .system_program(system_program::ID)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .instruction()
+}
+
+#[tokio::test]
+async fn change_signer_permissions_success() {
+    let fixtures = setup().await;
+    let kyc_signer = &fixtures.kyc_signer;
+    let closer = &fixtures.closer;
+
+    let permissions = vec![
+        SignerPermission {
+            signer: kyc_signer.pubkey(),
+            capabilities: SignerPermission::ATTEST,
+            schemas: vec![fixtures.kyc_schema],
+        },
+        SignerPermission {
+            signer: closer.pubkey(),
+            capabilities: SignerPermission::CLOSE,
+            schemas: vec![],
+        },
+    ];
+    let ix = change_permissions_ix(&fixtures, fixtures.authority.pubkey(), permissions.clone());
+    send(&fixtures, ix, &fixtures.authority).await.unwrap();
+
+    let credential_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.credential)
+        .await
+        .unwrap()
+        .unwrap();
+    let credential = Credential::try_from_slice(&credential_account.data).unwrap();
+    assert_eq!(credential.signer_permissions, permissions);
+
+    // The KYC signer attests only under the KYC Schema.
+    let (attestation, ix) =
+        create_attestation_ix(&fixtures, kyc_signer.pubkey(), fixtures.kyc_schema);
+    send(&fixtures, ix, kyc_signer).await.unwrap();
+
+    let (_, ix) = create_attestation_ix(
+        &fixtures,
+        kyc_signer.pubkey(),
+        fixtures.accreditation_schema,
+    );
+    let err = send(&fixtures, ix, kyc_signer).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(5))
+    );
+
+    // Attesting does not grant closing.
+    let ix = close_attestation_ix(
+        &fixtures,
+        kyc_signer.pubkey(),
+        fixtures.kyc_schema,
+        attestation,
+    );
+    let err = send(&fixtures, ix, kyc_signer).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(5))
+    );
+
+    // The closer cannot attest, but may close under any Schema.
+    let (_, ix) = create_attestation_ix(&fixtures, closer.pubkey(), fixtures.kyc_schema);
+    let err = send(&fixtures, ix, closer).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(5))
+    );
+
+    let ix = close_attestation_ix(&fixtures, closer.pubkey(), fixtures.kyc_schema, attestation);
+    send(&fixtures, ix, closer).await.unwrap();
+    let attestation_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(attestation)
+        .await
+        .unwrap();
+    assert!(attestation_account.is_none());
+
+    // Authorized signers without an entry keep every capability.
+    let (_, ix) = create_attestation_ix(
+        &fixtures,
+        fixtures.authority.pubkey(),
+        fixtures.accreditation_schema,
+    );
+    send(&fixtures, ix, &fixtures.authority).await.unwrap();
+}
+
+#[tokio::test]
+async fn change_signer_permissions_removed_signer_revoked() {
+    let fixtures = setup().await;
+    let closer = &fixtures.closer;
+
+    let ix = change_permissions_ix(
+        &fixtures,
+        fixtures.authority.pubkey(),
+        vec![SignerPermission {
+            signer: closer.pubkey(),
+            capabilities: SignerPermission::ALL,
+            schemas: vec![],
+        }],
+    );
+    send(&fixtures, ix, &fixtures.authority).await.unwrap();
+
+    let ix = ChangeAuthorizedSignersBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .system_program(system_program::ID)
+        .signers(vec![fixtures.authority.pubkey()])
+        .instruction();
+    send(&fixtures, ix, &fixtures.authority).await.unwrap();
+
+    // The remaining permission entry no longer grants anything.
+    let (_, ix) = create_attestation_ix(&fixtures, closer.pubkey(), fixtures.kyc_schema);
+    let err = send(&fixtures, ix, closer).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(5))
+    );
+}
+
+#[tokio::test]
+async fn change_signer_permissions_fail_invalid() {
+    let fixtures = setup().await;
+    let signer = Keypair::new().pubkey();
+    let permission = |capabilities| SignerPermission {
+        signer,
+        capabilities,
+        schemas: vec![],
+    };
+
+    let invalid_permissions = [
+        // No capabilities.
+        vec![permission(0)],
+        // Unknown capability bit.
+        vec![permission(SignerPermission::ALL + 1)],
+        // Duplicate signer.
+        vec![
+            permission(SignerPermission::ATTEST),
+            permission(SignerPermission::CLOSE),
+        ],
+    ];
+    for permissions in invalid_permissions {
+        let ix = change_permissions_ix(&fixtures, fixtures.authority.pubkey(), permissions);
+        let err = send(&fixtures, ix, &fixtures.authority).await.unwrap_err();
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(0, InstructionError::Custom(31))
+        );
+    }
+}
+
+#[tokio::test]
+async fn change_signer_permissions_fail_malformed_data() {
+    let fixtures = setup().await;
+    let permission = SignerPermission {
+        signer: fixtures.closer.pubkey(),
+        capabilities: SignerPermission::CLOSE,
+        schemas: vec![],
+    };
+
+    // Trailing bytes after the last entry.
+    let mut trailing = change_permissions_ix(
+        &fixtures,
+        fixtures.authority.pubkey(),
+        vec![permission.clone()],
+    );
+    trailing.data.push(0);
+
+    // A count far beyond the entries provided.
+    let mut overstated =
+        change_permissions_ix(&fixtures, fixtures.authority.pubkey(), vec![permission]);
+    overstated.data[1..5].copy_from_slice(&u32::MAX.to_le_bytes());
+
+    for ix in [trailing, overstated] {
+        let err = send(&fixtures, ix, &fixtures.authority).await.unwrap_err();
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
+        );
+    }
+}
+
+#[tokio::test]
+async fn change_signer_permissions_fail_not_authority() {
+    let fixtures = setup().await;
+    let impostor = Keypair::new();
+
+    let ix = change_permissions_ix(
+        &fixtures,
+        impostor.pubkey(),
+        vec![SignerPermission {
+            signer: impostor.pubkey(),
+            capabilities: SignerPermission::ALL,
+            schemas: vec![],
+        }],
+    );
+    let err = send(&fixtures, ix, &impostor).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
@@ -1,15 +1,23 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use helpers::program_test_context;
 use solana_attestation_service_client::instructions::{
-    CloseAttestationBuilder, CreateAttestationBuilder, CreateCredentialBuilder, CreateSchemaBuilder,
+    ChangeCredentialAdminsBuilder, CloseAttestationBuilder, CreateAttestationBuilder,
+    CreateCredentialBuilder, CreateSchemaBuilder, RevokeAttestationBuilder,
 };
 use solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID;
-use solana_attestation_service_client::types::CloseAttestationEvent;
+use solana_attestation_service_client::types::{
+    CloseAttestationEvent, SubjectCloseAttestationEvent,
+};
 use solana_attestation_service_macros::SchemaStructSerialize;
 use solana_program_test::ProgramTestContext;
 use solana_sdk::clock::Clock;
 use solana_sdk::{
-    pubkey::Pubkey, signature::Keypair, signer::Signer, system_program, transaction::Transaction,
+    instruction::{AccountMeta, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
 };
 
 mod helpers;
@@ -25,6 +33,7 @@ struct TestFixtures {
     credential: Pubkey,
     schema: Pubkey,
     authority: Keypair,
+    issuer: Keypair,
 }
 
 pub const EVENT_IX_TAG: u64 = 0x1d9acb512ea545e4;
@@ -34,6 +43,7 @@ async fn setup() -> TestFixtures {
     let ctx = program_test_context().await;
 
     let authority = Keypair::new();
+    let issuer = Keypair::new();
     let credential_name = "test";
     let (credential_pda, _bump) = Pubkey::find_program_address(
         &[
@@ -50,7 +60,7 @@ async fn setup() -> TestFixtures {
         .authority(authority.pubkey())
         .system_program(system_program::ID)
         .name(credential_name.to_string())
-        .signers(vec![authority.pubkey()])
+        .signers(vec![authority.pubkey(), issuer.pubkey()])
         .instruction();
 
     // Create Schema
@@ -95,9 +105,61 @@ async fn setup() -> TestFixtures {
         credential: credential_pda,
         schema: schema_pda,
         authority,
+        issuer,
     }
 }
 
+async fn create_attestation(
+    ctx: &ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    signer: &Keypair,
+    nonce: Pubkey,
+) -> Pubkey {
+    let attestation_data = TestData {
+        name: "attest".to_string(),
+        location: 11,
+    };
+    let mut serialized_attestation_data = Vec::new();
+    attestation_data
+        .serialize(&mut serialized_attestation_data)
+        .unwrap();
+    let attestation_pda = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &credential.to_bytes(),
+            &schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0;
+    let create_attestation_ix = CreateAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(signer.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(serialized_attestation_data)
+        .expiry(0)
+        .nonce(nonce)
+        .instruction();
+
+    let create_tx = Transaction::new_signed_with_payer(
+        &[create_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, signer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(create_tx)
+        .await
+        .unwrap();
+
+    attestation_pda
+}
+
 #[tokio::test]
 async fn close_attestation_success() {
     let TestFixtures {
@@ -105,6 +167,7 @@ async fn close_attestation_success() {
         credential,
         schema,
         authority,
+        ..
     } = setup().await;
 
     // Create Attestation
@@ -175,6 +238,7 @@ async fn close_attestation_success() {
         .payer(ctx.payer.pubkey())
         .authority(authority.pubkey())
         .credential(credential)
+        .schema(schema)
         .attestation(attestation_pda)
         .event_authority(event_auth_pda)
         .system_program(system_program::ID)
@@ -254,3 +318,440 @@ async fn close_attestation_success() {
         post_payer_lamports,
     )
 }
+
+#[tokio::test]
+async fn close_attestation_fail_payer_mismatch() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        issuer,
+        ..
+    } = setup().await;
+
+    // Issuance is sponsored by ctx.payer.
+    let attestation_pda =
+        create_attestation(&ctx, credential, schema, &issuer, Pubkey::new_unique()).await;
+
+    let (event_auth_pda, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+
+    // An authorized signer cannot redirect the rent to itself.
+    let close_attestation_ix = CloseAttestationBuilder::new()
+        .payer(issuer.pubkey())
+        .authority(issuer.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .attestation(attestation_pda)
+        .event_authority(event_auth_pda)
+        .system_program(system_program::ID)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .instruction();
+    let close_tx = Transaction::new_signed_with_payer(
+        &[close_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &issuer],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(close_tx)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(14))
+    );
+}
+
+#[tokio::test]
+async fn close_attestation_credential_authority_override() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        authority,
+        issuer,
+    } = setup().await;
+
+    let attestation_pda =
+        create_attestation(&ctx, credential, schema, &issuer, Pubkey::new_unique()).await;
+    let pda_lamports = ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .unwrap()
+        .map(|acc| acc.lamports)
+        .unwrap_or(0);
+
+    let (event_auth_pda, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+
+    // Credential authority may send the rent to a different destination.
+    let destination = Pubkey::new_unique();
+    let close_attestation_ix = CloseAttestationBuilder::new()
+        .payer(destination)
+        .authority(authority.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .attestation(attestation_pda)
+        .event_authority(event_auth_pda)
+        .system_program(system_program::ID)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .instruction();
+    let close_tx = Transaction::new_signed_with_payer(
+        &[close_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(close_tx)
+        .await
+        .unwrap();
+
+    let destination_lamports = ctx.banks_client.get_balance(destination).await.unwrap();
+    assert_eq!(destination_lamports, pda_lamports);
+}
+
+#[tokio::test]
+async fn close_attestation_credential_admins_override() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        authority,
+        issuer,
+    } = setup().await;
+
+    // Require two admins to approve, which the authority alone cannot.
+    let admins = [Keypair::new(), Keypair::new()];
+    let change_admins_ix = ChangeCredentialAdminsBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential)
+        .system_program(system_program::ID)
+        .admin_threshold(2)
+        .admins(admins.iter().map(|admin| admin.pubkey()).collect())
+        .instruction();
+    let change_admins_tx = Transaction::new_signed_with_payer(
+        &[change_admins_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(change_admins_tx)
+        .await
+        .unwrap();
+
+    let attestation_pda =
+        create_attestation(&ctx, credential, schema, &issuer, Pubkey::new_unique()).await;
+
+    let (event_auth_pda, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+    let destination = Pubkey::new_unique();
+    let close_attestation_ix = |co_signers: &[&Keypair]| {
+        CloseAttestationBuilder::new()
+            .payer(destination)
+            .authority(authority.pubkey())
+            .credential(credential)
+            .schema(schema)
+            .attestation(attestation_pda)
+            .event_authority(event_auth_pda)
+            .system_program(system_program::ID)
+            .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+            .add_remaining_accounts(
+                &co_signers
+                    .iter()
+                    .map(|admin| AccountMeta::new_readonly(admin.pubkey(), true))
+                    .collect::<Vec<_>>(),
+            )
+            .instruction()
+    };
+
+    let close_tx = Transaction::new_signed_with_payer(
+        &[close_attestation_ix(&[&admins[0]])],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority, &admins[0]],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(close_tx)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(14))
+    );
+
+    let close_tx = Transaction::new_signed_with_payer(
+        &[close_attestation_ix(&[&admins[0], &admins[1]])],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority, &admins[0], &admins[1]],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(close_tx)
+        .await
+        .unwrap();
+    assert!(ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn close_attestation_by_subject_success() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        issuer,
+        ..
+    } = setup().await;
+
+    // Attestation about the subject's wallet, sponsored by ctx.payer.
+    let subject = Keypair::new();
+    let attestation_pda =
+        create_attestation(&ctx, credential, schema, &issuer, subject.pubkey()).await;
+    let pda_lamports = ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .unwrap()
+        .map(|acc| acc.lamports)
+        .unwrap_or(0);
+    let initial_payer_lamports = ctx
+        .banks_client
+        .get_balance(ctx.payer.pubkey())
+        .await
+        .unwrap();
+
+    let (event_auth_pda, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+
+    let close_attestation_ix = CloseAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(subject.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .attestation(attestation_pda)
+        .event_authority(event_auth_pda)
+        .system_program(system_program::ID)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .instruction();
+    let close_tx = Transaction::new_signed_with_payer(
+        &[close_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &subject],
+        ctx.last_blockhash,
+    );
+
+    // Simulate transaction to check the event records the subject.
+    let simulate_res = ctx
+        .banks_client
+        .simulate_transaction(close_tx.clone())
+        .await
+        .unwrap();
+    let inner_ixs = simulate_res
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap();
+    let mut event_found = false;
+    for inner_instr_group in inner_ixs {
+        for inner_instr in inner_instr_group {
+            let program_id = inner_instr
+                .instruction
+                .program_id(&close_tx.message.account_keys);
+
+            if program_id.eq(&SOLANA_ATTESTATION_SERVICE_ID) {
+                let data = inner_instr.instruction.data;
+                if data.starts_with(EVENT_IX_TAG_LE) {
+                    let event = SubjectCloseAttestationEvent::try_from_slice(&data[8..]).unwrap();
+                    assert_eq!(event.discriminator, 3);
+                    assert_eq!(event.schema, schema);
+                    assert_eq!(event.subject, subject.pubkey());
+                    assert_eq!(event.revoked_at, 0);
+                    event_found = true;
+                }
+            }
+        }
+    }
+    assert!(event_found);
+
+    ctx.banks_client
+        .process_transaction(close_tx)
+        .await
+        .unwrap();
+
+    let attestation_account = ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .expect("get_account");
+    assert!(attestation_account.is_none());
+
+    // Rent goes back to the original payer (minus 10000 for tx fees).
+    let post_payer_lamports = ctx
+        .banks_client
+        .get_balance(ctx.payer.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(
+        initial_payer_lamports + pda_lamports - 10_000,
+        post_payer_lamports,
+    );
+}
+
+#[tokio::test]
+async fn close_attestation_by_subject_fail_payer_mismatch() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        issuer,
+        ..
+    } = setup().await;
+
+    let subject = Keypair::new();
+    let attestation_pda =
+        create_attestation(&ctx, credential, schema, &issuer, subject.pubkey()).await;
+
+    let (event_auth_pda, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+
+    // Subject cannot claim the rent that was funded by someone else.
+    let close_attestation_ix = CloseAttestationBuilder::new()
+        .payer(subject.pubkey())
+        .authority(subject.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .attestation(attestation_pda)
+        .event_authority(event_auth_pda)
+        .system_program(system_program::ID)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .instruction();
+    let close_tx = Transaction::new_signed_with_payer(
+        &[close_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &subject],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(close_tx)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(14))
+    );
+}
+
+#[tokio::test]
+async fn close_attestation_by_subject_revoked_success() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        authority,
+        issuer,
+    } = setup().await;
+
+    let subject = Keypair::new();
+    let attestation_pda =
+        create_attestation(&ctx, credential, schema, &issuer, subject.pubkey()).await;
+
+    let revoke_attestation_ix = RevokeAttestationBuilder::new()
+        .authority(authority.pubkey())
+        .credential(credential)
+        .attestation(attestation_pda)
+        .reason(1)
+        .instruction();
+    let revoke_tx = Transaction::new_signed_with_payer(
+        &[revoke_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(revoke_tx)
+        .await
+        .unwrap();
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+
+    let (event_auth_pda, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+
+    // Revoking does not take away the subject's right to close.
+    let close_attestation_ix = CloseAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(subject.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .attestation(attestation_pda)
+        .event_authority(event_auth_pda)
+        .system_program(system_program::ID)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .instruction();
+    let close_tx = Transaction::new_signed_with_payer(
+        &[close_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &subject],
+        ctx.last_blockhash,
+    );
+
+    // The event records that the Attestation was revoked.
+    let simulate_res = ctx
+        .banks_client
+        .simulate_transaction(close_tx.clone())
+        .await
+        .unwrap();
+    let inner_ixs = simulate_res
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap();
+    let mut event_found = false;
+    for inner_instr_group in inner_ixs {
+        for inner_instr in inner_instr_group {
+            let program_id = inner_instr
+                .instruction
+                .program_id(&close_tx.message.account_keys);
+
+            if program_id.eq(&SOLANA_ATTESTATION_SERVICE_ID) {
+                let data = inner_instr.instruction.data;
+                if data.starts_with(EVENT_IX_TAG_LE) {
+                    let event = SubjectCloseAttestationEvent::try_from_slice(&data[8..]).unwrap();
+                    assert_eq!(event.subject, subject.pubkey());
+                    assert_eq!(event.revoked_at, clock.unix_timestamp);
+                    event_found = true;
+                }
+            }
+        }
+    }
+    assert!(event_found);
+
+    ctx.banks_client
+        .process_transaction(close_tx)
+        .await
+        .unwrap();
+
+    let attestation_account = ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .expect("get_account");
+    assert!(attestation_account.is_none());
+}
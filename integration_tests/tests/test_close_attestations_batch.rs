@@ -0,0 +1,484 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::Schema,
+    instructions::{
+        CloseAttestationsBatchBuilder, CloseAttestationsBatchChunker,
+        CreateAttestationsBatchPacker, CreateCredentialBuilder, CreateSchemaBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    types::{CloseAttestationsBatchEvent, CreateAttestationEntry},
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{AccountMeta, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    authority: Keypair,
+    issuer: Keypair,
+}
+
+pub const EVENT_IX_TAG: u64 = 0x1d9acb512ea545e4;
+pub const EVENT_IX_TAG_LE: &[u8] = EVENT_IX_TAG.to_le_bytes().as_slice();
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let issuer = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey(), issuer.pubkey()])
+        .instruction();
+
+    // Create Schema
+    let schema_name = "test_data";
+    let description = "schema for test data";
+    let schema_data = TestData::get_serialized_representation();
+    let field_names = vec!["name".into(), "location".into()];
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description(description.to_string())
+        .name(schema_name.to_string())
+        .layout(schema_data)
+        .field_names(field_names)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        authority,
+        issuer,
+    }
+}
+
+/// Creates another Schema under the fixture Credential.
+async fn create_schema(fixtures: &TestFixtures, schema_name: &str) -> Pubkey {
+    let ctx = &fixtures.ctx;
+    let schema_pda = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &fixtures.credential.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0;
+    let ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("another schema for test data".to_string())
+        .name(schema_name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    schema_pda
+}
+
+/// Creates `count` Attestations signed by the issuer and returns their addresses.
+async fn create_attestations(fixtures: &TestFixtures, schema: Pubkey, count: u8) -> Vec<Pubkey> {
+    let ctx = &fixtures.ctx;
+    let entries: Vec<_> = (0..count)
+        .map(|i| {
+            let mut data = Vec::new();
+            TestData {
+                name: format!("attest-{i}"),
+                location: i,
+            }
+            .serialize(&mut data)
+            .unwrap();
+            CreateAttestationEntry {
+                nonce: Pubkey::new_unique(),
+                data,
+                expiry: 0,
+            }
+        })
+        .collect();
+
+    let instructions = CreateAttestationsBatchPacker::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.issuer.pubkey())
+        .credential(fixtures.credential)
+        .schema(schema)
+        .add_entries(&entries)
+        .instructions();
+    for ix in instructions {
+        let transaction = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &fixtures.issuer],
+            ctx.last_blockhash,
+        );
+        ctx.banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap();
+    }
+
+    entries
+        .iter()
+        .map(|entry| {
+            Pubkey::find_program_address(
+                &[
+                    b"attestation",
+                    &fixtures.credential.to_bytes(),
+                    &schema.to_bytes(),
+                    &entry.nonce.to_bytes(),
+                ],
+                &SOLANA_ATTESTATION_SERVICE_ID,
+            )
+            .0
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn close_attestations_batch_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let attestations = create_attestations(&fixtures, fixtures.schema, 4).await;
+
+    let mut close_ixs = CloseAttestationsBatchChunker::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.issuer.pubkey())
+        .credential(fixtures.credential)
+        .add_attestations(fixtures.schema, &attestations)
+        .instructions();
+    assert_eq!(close_ixs.len(), 1);
+    let close_tx = Transaction::new_signed_with_payer(
+        &[close_ixs.remove(0)],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.issuer],
+        ctx.last_blockhash,
+    );
+
+    // Simulate transaction to check that a single event lists every Attestation.
+    let simulate_res = ctx
+        .banks_client
+        .simulate_transaction(close_tx.clone())
+        .await
+        .unwrap();
+    let inner_ixs = simulate_res
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap();
+    let mut events = Vec::new();
+    for inner_instr_group in inner_ixs {
+        for inner_instr in inner_instr_group {
+            let program_id = inner_instr
+                .instruction
+                .program_id(&close_tx.message.account_keys);
+            let data = inner_instr.instruction.data;
+            if program_id.eq(&SOLANA_ATTESTATION_SERVICE_ID) && data.starts_with(EVENT_IX_TAG_LE) {
+                events.push(CloseAttestationsBatchEvent::try_from_slice(&data[8..]).unwrap());
+            }
+        }
+    }
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(event.discriminator, 4);
+    assert_eq!(event.closed.len(), attestations.len());
+    for (closed, attestation) in event.closed.iter().zip(&attestations) {
+        assert_eq!(closed.schema, fixtures.schema);
+        assert_eq!(&closed.attestation, attestation);
+    }
+
+    ctx.banks_client
+        .process_transaction(close_tx)
+        .await
+        .unwrap();
+
+    for attestation in attestations {
+        let attestation_account = ctx.banks_client.get_account(attestation).await.unwrap();
+        assert!(attestation_account.is_none());
+    }
+}
+
+#[tokio::test]
+async fn close_attestations_batch_chunked_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let attestations = create_attestations(&fixtures, fixtures.schema, 40).await;
+
+    let close_ixs = CloseAttestationsBatchChunker::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.issuer.pubkey())
+        .credential(fixtures.credential)
+        .add_attestations(fixtures.schema, &attestations)
+        .instructions();
+    // More Attestation accounts than fit in one transaction.
+    assert!(close_ixs.len() > 1);
+
+    for ix in close_ixs {
+        let transaction = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &fixtures.issuer],
+            ctx.last_blockhash,
+        );
+        ctx.banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap();
+    }
+
+    for attestation in attestations {
+        let attestation_account = ctx.banks_client.get_account(attestation).await.unwrap();
+        assert!(attestation_account.is_none());
+    }
+}
+
+#[tokio::test]
+async fn close_attestations_batch_multiple_schemas_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let other_schema = create_schema(&fixtures, "other_data").await;
+    let attestations = create_attestations(&fixtures, fixtures.schema, 2).await;
+    let other_attestations = create_attestations(&fixtures, other_schema, 3).await;
+
+    let close_ixs = CloseAttestationsBatchChunker::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.issuer.pubkey())
+        .credential(fixtures.credential)
+        .add_attestations(fixtures.schema, &attestations)
+        .add_attestations(other_schema, &other_attestations)
+        .instructions();
+    assert_eq!(close_ixs.len(), 1);
+    let transaction = Transaction::new_signed_with_payer(
+        &close_ixs,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.issuer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Each Schema's count drops by its own closed Attestations.
+    for schema in [fixtures.schema, other_schema] {
+        let schema_account = ctx.banks_client.get_account(schema).await.unwrap().unwrap();
+        let schema = Schema::try_from_slice(&schema_account.data).unwrap();
+        assert_eq!(schema.attestation_count, 0);
+    }
+    for attestation in attestations.iter().chain(&other_attestations) {
+        let attestation_account = ctx.banks_client.get_account(*attestation).await.unwrap();
+        assert!(attestation_account.is_none());
+    }
+}
+
+#[tokio::test]
+async fn close_attestations_batch_fail_missing_schema() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let attestations = create_attestations(&fixtures, fixtures.schema, 2).await;
+
+    // The Attestations are passed without their Schema.
+    let event_authority =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID).0;
+    let remaining_accounts: Vec<_> = attestations
+        .iter()
+        .map(|attestation| AccountMeta::new(*attestation, false))
+        .collect();
+    let close_ix = CloseAttestationsBatchBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.issuer.pubkey())
+        .credential(fixtures.credential)
+        .event_authority(event_authority)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.issuer],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+}
+
+#[tokio::test]
+async fn close_attestations_batch_fail_payer_mismatch() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let attestations = create_attestations(&fixtures, fixtures.schema, 2).await;
+
+    // Issuer is not the Credential authority, so rent must return to the original payer.
+    let other_payer = Pubkey::new_unique();
+    let close_ix = CloseAttestationsBatchChunker::new()
+        .fee_payer(ctx.payer.pubkey())
+        .payer(other_payer)
+        .authority(fixtures.issuer.pubkey())
+        .credential(fixtures.credential)
+        .add_attestations(fixtures.schema, &attestations)
+        .instructions()
+        .remove(0);
+    let transaction = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.issuer],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(14))
+    );
+}
+
+#[tokio::test]
+async fn close_attestations_batch_authority_override_payer() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let attestations = create_attestations(&fixtures, fixtures.schema, 2).await;
+
+    let other_payer = Pubkey::new_unique();
+    let close_ix = CloseAttestationsBatchChunker::new()
+        .fee_payer(ctx.payer.pubkey())
+        .payer(other_payer)
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .add_attestations(fixtures.schema, &attestations)
+        .instructions()
+        .remove(0);
+    let transaction = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let other_payer_account = ctx
+        .banks_client
+        .get_account(other_payer)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(other_payer_account.lamports > 0);
+}
+
+#[tokio::test]
+async fn close_attestations_batch_fail_unauthorized_signer() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let attestations = create_attestations(&fixtures, fixtures.schema, 2).await;
+
+    let unauthorized = Keypair::new();
+    let close_ix = CloseAttestationsBatchChunker::new()
+        .payer(ctx.payer.pubkey())
+        .authority(unauthorized.pubkey())
+        .credential(fixtures.credential)
+        .add_attestations(fixtures.schema, &attestations)
+        .instructions()
+        .remove(0);
+    let transaction = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &unauthorized],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(5))
+    );
+}
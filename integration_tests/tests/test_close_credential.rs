@@ -0,0 +1,224 @@
+use borsh::BorshDeserialize;
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    instructions::{
+        find_attestation_tree_pda, CloseCredentialBuilder, CloseSchemaBuilder,
+        CreateCredentialBuilder, CreateSchemaBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    types::CloseCredentialEvent,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    authority: Keypair,
+}
+
+pub const EVENT_IX_TAG: u64 = 0x1d9acb512ea545e4;
+pub const EVENT_IX_TAG_LE: &[u8] = EVENT_IX_TAG.to_le_bytes().as_slice();
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        authority,
+    }
+}
+
+async fn send(fixtures: &TestFixtures, ix: Instruction) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+fn event_authority() -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID).0
+}
+
+fn close_credential_ix(fixtures: &TestFixtures, payer: Pubkey) -> Instruction {
+    CloseCredentialBuilder::new()
+        .payer(payer)
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .event_authority(event_authority())
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .instruction()
+}
+
+#[tokio::test]
+async fn close_credential_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let credential_lamports = ctx
+        .banks_client
+        .get_balance(fixtures.credential)
+        .await
+        .unwrap();
+
+    let destination = Pubkey::new_unique();
+    let close_tx = Transaction::new_signed_with_payer(
+        &[close_credential_ix(&fixtures, destination)],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+
+    // Simulate transaction to check if event is emitted correctly.
+    let simulate_res = ctx
+        .banks_client
+        .simulate_transaction(close_tx.clone())
+        .await
+        .unwrap();
+    let inner_ixs = simulate_res
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap();
+    let mut events = Vec::new();
+    for inner_instr_group in inner_ixs {
+        for inner_instr in inner_instr_group {
+            let program_id = inner_instr
+                .instruction
+                .program_id(&close_tx.message.account_keys);
+            let data = inner_instr.instruction.data;
+            if program_id.eq(&SOLANA_ATTESTATION_SERVICE_ID) && data.starts_with(EVENT_IX_TAG_LE) {
+                events.push(CloseCredentialEvent::try_from_slice(&data[8..]).unwrap());
+            }
+        }
+    }
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].discriminator, 7);
+    assert_eq!(events[0].credential, fixtures.credential);
+    assert_eq!(events[0].authority, fixtures.authority.pubkey());
+
+    ctx.banks_client
+        .process_transaction(close_tx)
+        .await
+        .unwrap();
+
+    let credential_account = ctx
+        .banks_client
+        .get_account(fixtures.credential)
+        .await
+        .unwrap();
+    assert!(credential_account.is_none());
+    let destination_lamports = ctx.banks_client.get_balance(destination).await.unwrap();
+    assert_eq!(destination_lamports, credential_lamports);
+}
+
+#[tokio::test]
+async fn close_credential_fail_open_schemas() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let schema_name = "test_data";
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &fixtures.credential.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("schema for test data".to_string())
+        .name(schema_name.to_string())
+        .layout(vec![12])
+        .field_names(vec!["name".into()])
+        .instruction();
+    send(&fixtures, ix).await.unwrap();
+
+    let ix = close_credential_ix(&fixtures, ctx.payer.pubkey());
+    let err = send(&fixtures, ix).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(30))
+    );
+
+    // Closing the Schema releases the Credential.
+    let ix = CloseSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(schema_pda)
+        .attestation_tree(find_attestation_tree_pda(&schema_pda))
+        .schema_mint(
+            Pubkey::find_program_address(
+                &[b"schemaMint", &schema_pda.to_bytes()],
+                &SOLANA_ATTESTATION_SERVICE_ID,
+            )
+            .0,
+        )
+        .event_authority(event_authority())
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .instruction();
+    send(&fixtures, ix).await.unwrap();
+
+    let ix = close_credential_ix(&fixtures, fixtures.authority.pubkey());
+    send(&fixtures, ix).await.unwrap();
+    let credential_account = ctx
+        .banks_client
+        .get_account(fixtures.credential)
+        .await
+        .unwrap();
+    assert!(credential_account.is_none());
+}
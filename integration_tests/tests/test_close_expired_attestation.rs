@@ -0,0 +1,398 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    instructions::{
+        CloseExpiredAttestationBuilder, CreateAttestationBuilder, CreateCredentialBuilder,
+        CreateSchemaBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    types::CloseAttestationEvent,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    clock::Clock,
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    attestation: Pubkey,
+    expiry: i64,
+    serialized_attestation_data: Vec<u8>,
+}
+
+pub const EVENT_IX_TAG: u64 = 0x1d9acb512ea545e4;
+pub const EVENT_IX_TAG_LE: &[u8] = EVENT_IX_TAG.to_le_bytes().as_slice();
+
+async fn setup(never_expires: bool) -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .instruction();
+
+    // Create Schema
+    let schema_name = "test_data";
+    let description = "schema for test data";
+    let schema_data = TestData::get_serialized_representation();
+    let field_names = vec!["name".into(), "location".into()];
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description(description.to_string())
+        .name(schema_name.to_string())
+        .layout(schema_data.clone())
+        .field_names(field_names)
+        .instruction();
+
+    // Create Attestation
+    let attestation_data = TestData {
+        name: "attest".to_string(),
+        location: 11,
+    };
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let expiry: i64 = if never_expires {
+        0
+    } else {
+        clock.unix_timestamp + 60
+    };
+    let mut serialized_attestation_data = Vec::new();
+    attestation_data
+        .serialize(&mut serialized_attestation_data)
+        .unwrap();
+    let nonce = Pubkey::new_unique();
+    let attestation_pda = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &credential_pda.to_bytes(),
+            &schema_pda.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0;
+    let create_attestation_ix = CreateAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(serialized_attestation_data.clone())
+        .expiry(expiry)
+        .nonce(nonce)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            create_credential_ix,
+            create_schema_ix,
+            create_attestation_ix,
+        ],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        attestation: attestation_pda,
+        expiry,
+        serialized_attestation_data,
+    }
+}
+
+async fn warp_to_timestamp(ctx: &ProgramTestContext, unix_timestamp: i64) {
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp = unix_timestamp;
+    ctx.set_sysvar(&clock);
+}
+
+fn close_expired_attestation_ix(
+    payer: Pubkey,
+    credential: Pubkey,
+    schema: Pubkey,
+    attestation: Pubkey,
+) -> Instruction {
+    let (event_auth_pda, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+
+    CloseExpiredAttestationBuilder::new()
+        .payer(payer)
+        .schema(schema)
+        .credential(credential)
+        .attestation(attestation)
+        .event_authority(event_auth_pda)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .instruction()
+}
+
+#[tokio::test]
+async fn close_expired_attestation_success() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        attestation,
+        expiry,
+        serialized_attestation_data,
+    } = setup(false).await;
+
+    warp_to_timestamp(&ctx, expiry + 1).await;
+
+    // Cleanup bot pays the fee, no issuer keys are involved.
+    let cranker = Keypair::new();
+    let fund_cranker_ix = solana_sdk::system_instruction::transfer(
+        &ctx.payer.pubkey(),
+        &cranker.pubkey(),
+        1_000_000_000,
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &[fund_cranker_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let attestation_lamports = ctx
+        .banks_client
+        .get_account(attestation)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let payer_lamports_before = ctx
+        .banks_client
+        .get_balance(ctx.payer.pubkey())
+        .await
+        .unwrap();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[close_expired_attestation_ix(
+            ctx.payer.pubkey(),
+            credential,
+            schema,
+            attestation,
+        )],
+        Some(&cranker.pubkey()),
+        &[&cranker],
+        ctx.last_blockhash,
+    );
+
+    // Simulate transaction to check if event is emitted correctly.
+    let simulate_res = ctx
+        .banks_client
+        .simulate_transaction(transaction.clone())
+        .await
+        .unwrap();
+    let inner_ixs = simulate_res
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap();
+    let mut event_found = false;
+    for inner_instr_group in inner_ixs {
+        for inner_instr in inner_instr_group {
+            let program_id = inner_instr
+                .instruction
+                .program_id(&transaction.message.account_keys);
+
+            if program_id.eq(&SOLANA_ATTESTATION_SERVICE_ID) {
+                let data = inner_instr.instruction.data;
+                if data.starts_with(EVENT_IX_TAG_LE) {
+                    let event = CloseAttestationEvent::try_from_slice(&data[8..]).unwrap();
+                    assert_eq!(event.discriminator, 0);
+                    assert_eq!(event.schema, schema);
+                    assert_eq!(event.attestation_data, serialized_attestation_data);
+                    event_found = true;
+                }
+            }
+        }
+    }
+    assert!(event_found);
+
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let attestation_account = ctx.banks_client.get_account(attestation).await.unwrap();
+    assert!(attestation_account.is_none());
+
+    // Rent is returned to the original payer, not the cranker.
+    let payer_lamports_after = ctx
+        .banks_client
+        .get_balance(ctx.payer.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(
+        payer_lamports_after,
+        payer_lamports_before + attestation_lamports
+    );
+}
+
+#[tokio::test]
+async fn close_expired_attestation_fail_not_expired() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        attestation,
+        expiry,
+        ..
+    } = setup(false).await;
+
+    // Expiry must be strictly in the past.
+    warp_to_timestamp(&ctx, expiry).await;
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[close_expired_attestation_ix(
+            ctx.payer.pubkey(),
+            credential,
+            schema,
+            attestation,
+        )],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(13))
+    );
+}
+
+#[tokio::test]
+async fn close_expired_attestation_fail_never_expires() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        attestation,
+        ..
+    } = setup(true).await;
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    warp_to_timestamp(&ctx, clock.unix_timestamp + 365 * 24 * 60 * 60).await;
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[close_expired_attestation_ix(
+            ctx.payer.pubkey(),
+            credential,
+            schema,
+            attestation,
+        )],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(13))
+    );
+}
+
+#[tokio::test]
+async fn close_expired_attestation_fail_wrong_payer() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        attestation,
+        expiry,
+        ..
+    } = setup(false).await;
+
+    warp_to_timestamp(&ctx, expiry + 1).await;
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[close_expired_attestation_ix(
+            Pubkey::new_unique(),
+            credential,
+            schema,
+            attestation,
+        )],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(14))
+    );
+}
@@ -0,0 +1,384 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::{Credential, Schema},
+    instructions::{
+        find_attestation_tree_pda, CloseAttestationBuilder, CloseSchemaBuilder,
+        CreateAttestationBuilder, CreateAttestationTreeBuilder, CreateCredentialBuilder,
+        CreateSchemaBuilder, TokenizeSchemaBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    types::CloseSchemaEvent,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    authority: Keypair,
+}
+
+pub const EVENT_IX_TAG: u64 = 0x1d9acb512ea545e4;
+pub const EVENT_IX_TAG_LE: &[u8] = EVENT_IX_TAG.to_le_bytes().as_slice();
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .instruction();
+
+    let schema_name = "test_data";
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("schema for test data".to_string())
+        .name(schema_name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        authority,
+    }
+}
+
+async fn send(fixtures: &TestFixtures, ix: Instruction) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+async fn fetch_schema(fixtures: &TestFixtures) -> Schema {
+    let account = fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.schema)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    Schema::try_from_slice(&account.data).unwrap()
+}
+
+fn schema_mint_pda(schema: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"schemaMint", &schema.to_bytes()],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0
+}
+
+fn event_authority() -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID).0
+}
+
+fn close_schema_ix(fixtures: &TestFixtures, payer: Pubkey) -> Instruction {
+    CloseSchemaBuilder::new()
+        .payer(payer)
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation_tree(find_attestation_tree_pda(&fixtures.schema))
+        .schema_mint(schema_mint_pda(&fixtures.schema))
+        .event_authority(event_authority())
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .instruction()
+}
+
+async fn create_attestation(fixtures: &TestFixtures) -> Pubkey {
+    let nonce = Pubkey::new_unique();
+    let attestation_pda = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &fixtures.credential.to_bytes(),
+            &fixtures.schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0;
+    let mut data = Vec::new();
+    TestData {
+        name: "attest".to_string(),
+        location: 11,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    let ix = CreateAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(data)
+        .expiry(0)
+        .nonce(nonce)
+        .instruction();
+    send(fixtures, ix).await.unwrap();
+
+    attestation_pda
+}
+
+#[tokio::test]
+async fn close_schema_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let credential_account = ctx
+        .banks_client
+        .get_account(fixtures.credential)
+        .await
+        .unwrap()
+        .unwrap();
+    let credential = Credential::try_from_slice(&credential_account.data).unwrap();
+    assert_eq!(credential.schema_count, 1);
+
+    let schema_lamports = ctx.banks_client.get_balance(fixtures.schema).await.unwrap();
+
+    // The rent may be sent to any account chosen by the authority.
+    let destination = Pubkey::new_unique();
+    let close_tx = Transaction::new_signed_with_payer(
+        &[close_schema_ix(&fixtures, destination)],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+
+    // Simulate transaction to check if event is emitted correctly.
+    let simulate_res = ctx
+        .banks_client
+        .simulate_transaction(close_tx.clone())
+        .await
+        .unwrap();
+    let inner_ixs = simulate_res
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap();
+    let mut events = Vec::new();
+    for inner_instr_group in inner_ixs {
+        for inner_instr in inner_instr_group {
+            let program_id = inner_instr
+                .instruction
+                .program_id(&close_tx.message.account_keys);
+            let data = inner_instr.instruction.data;
+            if program_id.eq(&SOLANA_ATTESTATION_SERVICE_ID) && data.starts_with(EVENT_IX_TAG_LE) {
+                events.push(CloseSchemaEvent::try_from_slice(&data[8..]).unwrap());
+            }
+        }
+    }
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].discriminator, 6);
+    assert_eq!(events[0].credential, fixtures.credential);
+    assert_eq!(events[0].schema, fixtures.schema);
+
+    ctx.banks_client
+        .process_transaction(close_tx)
+        .await
+        .unwrap();
+
+    let schema_account = ctx.banks_client.get_account(fixtures.schema).await.unwrap();
+    assert!(schema_account.is_none());
+
+    let destination_lamports = ctx.banks_client.get_balance(destination).await.unwrap();
+    assert_eq!(destination_lamports, schema_lamports);
+
+    let credential_account = ctx
+        .banks_client
+        .get_account(fixtures.credential)
+        .await
+        .unwrap()
+        .unwrap();
+    let credential = Credential::try_from_slice(&credential_account.data).unwrap();
+    assert_eq!(credential.schema_count, 0);
+}
+
+#[tokio::test]
+async fn close_schema_fail_open_attestations() {
+    let fixtures = setup().await;
+    let attestation = create_attestation(&fixtures).await;
+    assert_eq!(fetch_schema(&fixtures).await.attestation_count, 1);
+
+    let payer = fixtures.ctx.payer.pubkey();
+    let err = send(&fixtures, close_schema_ix(&fixtures, payer))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(29))
+    );
+
+    // Closing the Attestation releases the Schema.
+    let ix = CloseAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(attestation)
+        .event_authority(event_authority())
+        .system_program(system_program::ID)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .instruction();
+    send(&fixtures, ix).await.unwrap();
+    assert_eq!(fetch_schema(&fixtures).await.attestation_count, 0);
+
+    let ix = close_schema_ix(&fixtures, fixtures.authority.pubkey());
+    send(&fixtures, ix).await.unwrap();
+}
+
+#[tokio::test]
+async fn close_schema_fail_attestation_tree() {
+    let fixtures = setup().await;
+    let ix = CreateAttestationTreeBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation_tree(find_attestation_tree_pda(&fixtures.schema))
+        .max_depth(3)
+        .max_buffer_size(2)
+        .instruction();
+    send(&fixtures, ix).await.unwrap();
+
+    // Compressed Attestations may still be live in the tree.
+    let payer = fixtures.ctx.payer.pubkey();
+    let err = send(&fixtures, close_schema_ix(&fixtures, payer))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(29))
+    );
+}
+
+#[tokio::test]
+async fn close_schema_fail_tokenized() {
+    let fixtures = setup().await;
+    let sas_pda = Pubkey::find_program_address(&[b"sas"], &SOLANA_ATTESTATION_SERVICE_ID).0;
+    let ix = TokenizeSchemaBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .mint(schema_mint_pda(&fixtures.schema))
+        .sas_pda(sas_pda)
+        .max_size(100)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .instruction();
+    send(&fixtures, ix).await.unwrap();
+
+    // The Schema mint cannot be closed, so neither can the Schema.
+    let payer = fixtures.ctx.payer.pubkey();
+    let err = send(&fixtures, close_schema_ix(&fixtures, payer))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(32))
+    );
+}
+
+#[tokio::test]
+async fn close_schema_fail_not_authority() {
+    let fixtures = setup().await;
+    let impostor = Keypair::new();
+
+    let ix = CloseSchemaBuilder::new()
+        .payer(impostor.pubkey())
+        .authority(impostor.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation_tree(find_attestation_tree_pda(&fixtures.schema))
+        .schema_mint(schema_mint_pda(&fixtures.schema))
+        .event_authority(event_authority())
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .instruction();
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &impostor],
+        ctx.last_blockhash,
+    );
+    let err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
@@ -0,0 +1,478 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::AttestationTree,
+    instructions::{
+        find_attestation_tree_pda, proof_account_metas, AttestationTreeReplica,
+        CreateAttestationTreeBuilder, CreateCompressedAttestationBuilder, CreateCredentialBuilder,
+        CreateSchemaBuilder, RevokeCompressedAttestationBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    types::{CompressedAttestation, CompressedAttestationEvent},
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    authority: Keypair,
+    tree: Pubkey,
+    event_authority: Pubkey,
+}
+
+pub const EVENT_IX_TAG: u64 = 0x1d9acb512ea545e4;
+pub const EVENT_IX_TAG_LE: &[u8] = EVENT_IX_TAG.to_le_bytes().as_slice();
+
+const MAX_DEPTH: u8 = 10;
+const MAX_BUFFER_SIZE: u8 = 8;
+// Deepest tree, with the largest changelog buffer that fits the account.
+const LIMIT_DEPTH: u8 = 16;
+const LIMIT_BUFFER_SIZE: u8 = 13;
+// Largest Attestation data a leaf of the deepest tree can be revoked with.
+const LIMIT_DATA_LEN: usize = 110;
+
+/// Create a Credential and a Schema, without an AttestationTree.
+async fn setup_schema() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .instruction();
+
+    // Create Schema
+    let schema_name = "test_data";
+    let description = "schema for test data";
+    let schema_data = TestData::get_serialized_representation();
+    let field_names = vec!["name".into(), "location".into()];
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description(description.to_string())
+        .name(schema_name.to_string())
+        .layout(schema_data)
+        .field_names(field_names)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        authority,
+        tree: find_attestation_tree_pda(&schema_pda),
+        event_authority,
+    }
+}
+
+fn create_tree_ix(fixtures: &TestFixtures, max_depth: u8, max_buffer_size: u8) -> Instruction {
+    CreateAttestationTreeBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation_tree(fixtures.tree)
+        .max_depth(max_depth)
+        .max_buffer_size(max_buffer_size)
+        .instruction()
+}
+
+/// Bind an AttestationTree to a new Schema.
+async fn setup(max_depth: u8, max_buffer_size: u8) -> TestFixtures {
+    let fixtures = setup_schema().await;
+    let ix = create_tree_ix(&fixtures, max_depth, max_buffer_size);
+    send(&fixtures, ix, &fixtures.authority).await.unwrap();
+    fixtures
+}
+
+async fn fetch_tree(fixtures: &TestFixtures) -> AttestationTree {
+    let tree_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.tree)
+        .await
+        .unwrap()
+        .unwrap();
+    AttestationTree::try_from_slice(&tree_account.data).unwrap()
+}
+
+fn current_root(tree: &AttestationTree) -> [u8; 32] {
+    tree.changelog[(tree.sequence % tree.max_buffer_size as u64) as usize].root
+}
+
+/// Sends `ix` signed by `signer`, returning the CompressedAttestationEvents
+/// it emitted.
+async fn send(
+    fixtures: &TestFixtures,
+    ix: Instruction,
+    signer: &Keypair,
+) -> Result<Vec<CompressedAttestationEvent>, TransactionError> {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, signer],
+        ctx.last_blockhash,
+    );
+
+    // Simulate transaction to collect the emitted events.
+    let simulate_res = ctx
+        .banks_client
+        .simulate_transaction(transaction.clone())
+        .await
+        .unwrap();
+    if let Some(Err(err)) = simulate_res.result {
+        return Err(err);
+    }
+    let mut events = Vec::new();
+    for inner_instr_group in simulate_res
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+    {
+        for inner_instr in inner_instr_group {
+            let program_id = inner_instr
+                .instruction
+                .program_id(&transaction.message.account_keys);
+            let data = inner_instr.instruction.data;
+            if program_id.eq(&SOLANA_ATTESTATION_SERVICE_ID) && data.starts_with(EVENT_IX_TAG_LE) {
+                events.push(CompressedAttestationEvent::try_from_slice(&data[8..]).unwrap());
+            }
+        }
+    }
+
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())?;
+    Ok(events)
+}
+
+fn create_compressed_ix(fixtures: &TestFixtures, authority: &Pubkey, location: u8) -> Instruction {
+    create_compressed_ix_with_name(fixtures, authority, "attest", location)
+}
+
+fn create_compressed_ix_with_name(
+    fixtures: &TestFixtures,
+    authority: &Pubkey,
+    name: &str,
+    location: u8,
+) -> Instruction {
+    let mut data = Vec::new();
+    TestData {
+        name: name.to_string(),
+        location,
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    CreateCompressedAttestationBuilder::new()
+        .authority(*authority)
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation_tree(fixtures.tree)
+        .event_authority(fixtures.event_authority)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .nonce(Pubkey::new_unique())
+        .data(data)
+        .expiry(0)
+        .instruction()
+}
+
+fn revoke_compressed_ix(
+    fixtures: &TestFixtures,
+    root: [u8; 32],
+    leaf_index: u32,
+    attestation: CompressedAttestation,
+    proof: &[[u8; 32]],
+) -> Instruction {
+    RevokeCompressedAttestationBuilder::new()
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .attestation_tree(fixtures.tree)
+        .event_authority(fixtures.event_authority)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .root(root)
+        .leaf_index(leaf_index)
+        .attestation(attestation)
+        .reason(3)
+        .add_remaining_accounts(&proof_account_metas(proof))
+        .instruction()
+}
+
+/// Creates `count` compressed Attestations, applying their events to `replica`.
+async fn create_compressed(
+    fixtures: &TestFixtures,
+    replica: &mut AttestationTreeReplica,
+    count: u8,
+) {
+    for location in 0..count {
+        let ix = create_compressed_ix(fixtures, &fixtures.authority.pubkey(), location);
+        let events = send(fixtures, ix, &fixtures.authority).await.unwrap();
+        assert_eq!(events.len(), 1);
+        replica.apply_event(&events[0]);
+    }
+}
+
+#[tokio::test]
+async fn compressed_attestation_success() {
+    let fixtures = setup(MAX_DEPTH, MAX_BUFFER_SIZE).await;
+
+    let tree = fetch_tree(&fixtures).await;
+    assert_eq!(tree.schema, fixtures.schema);
+    assert_eq!(tree.max_depth, MAX_DEPTH);
+    assert_eq!(tree.num_leaves, 0);
+
+    let mut replica = AttestationTreeReplica::new(fixtures.tree, MAX_DEPTH);
+    assert_eq!(replica.root(), current_root(&tree));
+
+    create_compressed(&fixtures, &mut replica, 3).await;
+
+    // The root rebuilt from events matches the on-chain root.
+    let tree = fetch_tree(&fixtures).await;
+    assert_eq!(tree.num_leaves, 3);
+    assert_eq!(replica.root(), current_root(&tree));
+    let attestation = replica.attestation(1).unwrap().clone();
+    assert_eq!(attestation.schema, fixtures.schema);
+    assert_eq!(attestation.signer, fixtures.authority.pubkey());
+    assert!(!attestation.is_revoked());
+
+    // Revoke with a proof rebuilt from events.
+    let ix = revoke_compressed_ix(&fixtures, replica.root(), 1, attestation, &replica.proof(1));
+    let events = send(&fixtures, ix, &fixtures.authority).await.unwrap();
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(event.discriminator, 5);
+    assert_eq!(event.leaf_index, 1);
+    assert!(event.attestation.is_revoked());
+    assert_eq!(event.attestation.revoked_by, fixtures.authority.pubkey());
+    assert_eq!(event.attestation.revocation_reason, 3);
+    replica.apply_event(event);
+
+    let tree = fetch_tree(&fixtures).await;
+    assert_eq!(tree.sequence, event.sequence);
+    assert_eq!(replica.root(), current_root(&tree));
+}
+
+#[tokio::test]
+async fn revoke_compressed_attestation_stale_root_success() {
+    let fixtures = setup(MAX_DEPTH, MAX_BUFFER_SIZE).await;
+    let mut replica = AttestationTreeReplica::new(fixtures.tree, MAX_DEPTH);
+    create_compressed(&fixtures, &mut replica, 3).await;
+
+    // Proof computed before the tree changes again.
+    let root = replica.root();
+    let proof = replica.proof(0);
+    let attestation = replica.attestation(0).unwrap().clone();
+    create_compressed(&fixtures, &mut replica, 2).await;
+
+    let ix = revoke_compressed_ix(&fixtures, root, 0, attestation, &proof);
+    let events = send(&fixtures, ix, &fixtures.authority).await.unwrap();
+    replica.apply_event(&events[0]);
+
+    let tree = fetch_tree(&fixtures).await;
+    assert_eq!(replica.root(), current_root(&tree));
+}
+
+#[tokio::test]
+async fn revoke_compressed_attestation_fail_invalid_proof() {
+    let fixtures = setup(MAX_DEPTH, MAX_BUFFER_SIZE).await;
+    let mut replica = AttestationTreeReplica::new(fixtures.tree, MAX_DEPTH);
+    create_compressed(&fixtures, &mut replica, 3).await;
+
+    // Proof for a different leaf.
+    let ix = revoke_compressed_ix(
+        &fixtures,
+        replica.root(),
+        0,
+        replica.attestation(0).unwrap().clone(),
+        &replica.proof(1),
+    );
+    let err = send(&fixtures, ix, &fixtures.authority).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(21))
+    );
+
+    // Leaf data that does not match the stored hash.
+    let mut attestation = replica.attestation(0).unwrap().clone();
+    attestation.data[0] ^= 1;
+    let ix = revoke_compressed_ix(&fixtures, replica.root(), 0, attestation, &replica.proof(0));
+    let err = send(&fixtures, ix, &fixtures.authority).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(21))
+    );
+}
+
+#[tokio::test]
+async fn revoke_compressed_attestation_fail_already_revoked() {
+    let fixtures = setup(MAX_DEPTH, MAX_BUFFER_SIZE).await;
+    let mut replica = AttestationTreeReplica::new(fixtures.tree, MAX_DEPTH);
+    create_compressed(&fixtures, &mut replica, 1).await;
+
+    let ix = revoke_compressed_ix(
+        &fixtures,
+        replica.root(),
+        0,
+        replica.attestation(0).unwrap().clone(),
+        &replica.proof(0),
+    );
+    let events = send(&fixtures, ix, &fixtures.authority).await.unwrap();
+    replica.apply_event(&events[0]);
+
+    let ix = revoke_compressed_ix(
+        &fixtures,
+        replica.root(),
+        0,
+        replica.attestation(0).unwrap().clone(),
+        &replica.proof(0),
+    );
+    let err = send(&fixtures, ix, &fixtures.authority).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(12))
+    );
+}
+
+#[tokio::test]
+async fn create_compressed_attestation_fail_tree_full() {
+    let fixtures = setup(1, MAX_BUFFER_SIZE).await;
+    let mut replica = AttestationTreeReplica::new(fixtures.tree, 1);
+    create_compressed(&fixtures, &mut replica, 2).await;
+
+    let ix = create_compressed_ix(&fixtures, &fixtures.authority.pubkey(), 2);
+    let err = send(&fixtures, ix, &fixtures.authority).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(20))
+    );
+}
+
+#[tokio::test]
+async fn create_compressed_attestation_fail_unauthorized_signer() {
+    let fixtures = setup(MAX_DEPTH, MAX_BUFFER_SIZE).await;
+
+    let unauthorized = Keypair::new();
+    let ix = create_compressed_ix(&fixtures, &unauthorized.pubkey(), 0);
+    let err = send(&fixtures, ix, &unauthorized).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(5))
+    );
+}
+
+#[tokio::test]
+async fn compressed_attestation_at_limits_success() {
+    let fixtures = setup(LIMIT_DEPTH, LIMIT_BUFFER_SIZE).await;
+    let tree_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.tree)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(tree_account.data.len() <= 8_192);
+
+    // The largest leaf data still revocable at the deepest tree.
+    let mut replica = AttestationTreeReplica::new(fixtures.tree, LIMIT_DEPTH);
+    let authority = fixtures.authority.pubkey();
+    let name = "a".repeat(LIMIT_DATA_LEN - 5);
+    let ix = create_compressed_ix_with_name(&fixtures, &authority, &name, 0);
+    let events = send(&fixtures, ix, &fixtures.authority).await.unwrap();
+    replica.apply_event(&events[0]);
+    let attestation = replica.attestation(0).unwrap().clone();
+    assert_eq!(attestation.data.len(), LIMIT_DATA_LEN);
+
+    // Revoked with a transaction at the size limit.
+    let ix = revoke_compressed_ix(&fixtures, replica.root(), 0, attestation, &replica.proof(0));
+    let events = send(&fixtures, ix, &fixtures.authority).await.unwrap();
+    assert!(events[0].attestation.is_revoked());
+    replica.apply_event(&events[0]);
+
+    let tree = fetch_tree(&fixtures).await;
+    assert_eq!(replica.root(), current_root(&tree));
+
+    // One more byte could never be revoked.
+    let name = "a".repeat(LIMIT_DATA_LEN - 4);
+    let ix = create_compressed_ix_with_name(&fixtures, &authority, &name, 0);
+    let err = send(&fixtures, ix, &fixtures.authority).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(34))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_tree_fail_over_limits() {
+    let fixtures = setup_schema().await;
+
+    for (max_depth, max_buffer_size) in [(LIMIT_DEPTH + 1, 1), (LIMIT_DEPTH, LIMIT_BUFFER_SIZE + 1)]
+    {
+        let ix = create_tree_ix(&fixtures, max_depth, max_buffer_size);
+        let err = send(&fixtures, ix, &fixtures.authority).await.unwrap_err();
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
+        );
+    }
+}
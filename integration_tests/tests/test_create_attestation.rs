@@ -164,6 +164,12 @@ async fn create_attestation_success() {
     assert_eq!(attestation.signer, authority.pubkey());
     assert_eq!(attestation.nonce, nonce);
     assert_eq!(attestation.token_account, Pubkey::default());
+    assert!(!attestation.is_revoked());
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    assert_eq!(attestation.created_at, clock.unix_timestamp);
+    assert_eq!(attestation.updated_at, clock.unix_timestamp);
+    assert_eq!(attestation.payer, ctx.payer.pubkey());
 }
 
 #[tokio::test]
@@ -0,0 +1,580 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::{Attestation, SignatureReceipt},
+    instructions::{
+        create_attestation_message, find_signature_receipt_pda, CloseAttestationBuilder,
+        CloseSignatureReceiptBuilder, CreateAttestationWithSignatureBuilder,
+        CreateCredentialBuilder, CreateSchemaBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    clock::Clock,
+    ed25519_instruction::new_ed25519_instruction_with_signature,
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_instruction, system_program, sysvar,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    issuer: Keypair,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let issuer = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![issuer.pubkey()])
+        .instruction();
+
+    // Create Schema
+    let schema_name = "test_data";
+    let description = "schema for test data";
+    let schema_data = TestData::get_serialized_representation();
+    let field_names = vec!["name".into(), "location".into()];
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description(description.to_string())
+        .name(schema_name.to_string())
+        .layout(schema_data)
+        .field_names(field_names)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        issuer,
+    }
+}
+
+struct SignedAttestation {
+    nonce: Pubkey,
+    data: Vec<u8>,
+    expiry: i64,
+    deadline: i64,
+    message: Vec<u8>,
+}
+
+impl SignedAttestation {
+    fn new(fixtures: &TestFixtures, expiry: i64, deadline: i64) -> Self {
+        let attestation_data = TestData {
+            name: "attest".to_string(),
+            location: 11,
+        };
+        let mut data = Vec::new();
+        attestation_data.serialize(&mut data).unwrap();
+        let nonce = Pubkey::new_unique();
+        let message = create_attestation_message(
+            &fixtures.credential,
+            &fixtures.schema,
+            &nonce,
+            &data,
+            expiry,
+            deadline,
+        );
+
+        Self {
+            nonce,
+            data,
+            expiry,
+            deadline,
+            message,
+        }
+    }
+
+    fn attestation_pda(&self, fixtures: &TestFixtures) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                b"attestation",
+                &fixtures.credential.to_bytes(),
+                &fixtures.schema.to_bytes(),
+                &self.nonce.to_bytes(),
+            ],
+            &SOLANA_ATTESTATION_SERVICE_ID,
+        )
+        .0
+    }
+
+    /// Ed25519 verification of `message` signed by `signer`, followed by the
+    /// CreateAttestationWithSignature instruction.
+    fn instructions(
+        &self,
+        fixtures: &TestFixtures,
+        signer: &Keypair,
+        message: &[u8],
+    ) -> [Instruction; 2] {
+        let signature = signer.sign_message(message);
+        let ed25519_ix = new_ed25519_instruction_with_signature(
+            message,
+            signature.as_ref().try_into().unwrap(),
+            &signer.pubkey().to_bytes(),
+        );
+        let create_ix = CreateAttestationWithSignatureBuilder::new()
+            .payer(fixtures.ctx.payer.pubkey())
+            .authority(signer.pubkey())
+            .credential(fixtures.credential)
+            .schema(fixtures.schema)
+            .attestation(self.attestation_pda(fixtures))
+            .signature_receipt(find_signature_receipt_pda(&self.message))
+            .instructions_sysvar(sysvar::instructions::ID)
+            .nonce(self.nonce)
+            .data(self.data.clone())
+            .expiry(self.expiry)
+            .deadline(self.deadline)
+            .instruction();
+
+        [ed25519_ix, create_ix]
+    }
+}
+
+async fn current_timestamp(ctx: &ProgramTestContext) -> i64 {
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp
+}
+
+async fn warp_to_timestamp(ctx: &ProgramTestContext, unix_timestamp: i64) {
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp = unix_timestamp;
+    ctx.set_sysvar(&clock);
+}
+
+/// Submits `signed` with the fixtures' payer funding the receipt.
+async fn submit_signed_attestation(fixtures: &TestFixtures, signed: &SignedAttestation) {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &signed.instructions(fixtures, &fixtures.issuer, &signed.message),
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+}
+
+/// Funded keypair paying the fees of a close, so the payer's balance only
+/// changes by the returned rent.
+async fn funded_cranker(ctx: &ProgramTestContext) -> Keypair {
+    let cranker = Keypair::new();
+    let transaction = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(
+            &ctx.payer.pubkey(),
+            &cranker.pubkey(),
+            1_000_000_000,
+        )],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+    cranker
+}
+
+#[tokio::test]
+async fn create_attestation_with_signature_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let now = current_timestamp(ctx).await;
+    let signed = SignedAttestation::new(&fixtures, now + 3600, now + 60);
+
+    // Relayer submits without the issuer signing the transaction.
+    let transaction = Transaction::new_signed_with_payer(
+        &signed.instructions(&fixtures, &fixtures.issuer, &signed.message),
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let attestation_account = ctx
+        .banks_client
+        .get_account(signed.attestation_pda(&fixtures))
+        .await
+        .unwrap()
+        .unwrap();
+    let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert_eq!(attestation.nonce, signed.nonce);
+    assert_eq!(attestation.data, signed.data);
+    assert_eq!(attestation.expiry, signed.expiry);
+    assert_eq!(attestation.signer, fixtures.issuer.pubkey());
+    assert_eq!(attestation.payer, ctx.payer.pubkey());
+
+    let receipt_account = ctx
+        .banks_client
+        .get_account(find_signature_receipt_pda(&signed.message))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(receipt_account.owner, SOLANA_ATTESTATION_SERVICE_ID);
+    let receipt = SignatureReceipt::try_from_slice(&receipt_account.data).unwrap();
+    assert_eq!(receipt.payer, ctx.payer.pubkey());
+    assert_eq!(receipt.deadline, signed.deadline);
+}
+
+#[tokio::test]
+async fn create_attestation_with_signature_fail_replay() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let now = current_timestamp(ctx).await;
+    let signed = SignedAttestation::new(&fixtures, 0, now + 60);
+    let instructions = signed.instructions(&fixtures, &fixtures.issuer, &signed.message);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Close the Attestation so only the receipt prevents resubmission.
+    let (event_auth_pda, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+    let close_attestation_ix = CloseAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.issuer.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(signed.attestation_pda(&fixtures))
+        .event_authority(event_auth_pda)
+        .system_program(system_program::ID)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[close_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.issuer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Prepend a transfer so the replayed transaction has a new signature.
+    let transfer_ix = system_instruction::transfer(&ctx.payer.pubkey(), &Pubkey::new_unique(), 1);
+    let [ed25519_ix, create_ix] = instructions;
+    let transaction = Transaction::new_signed_with_payer(
+        &[transfer_ix, ed25519_ix, create_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(2, InstructionError::Custom(18))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_signature_fail_deadline_passed() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let now = current_timestamp(ctx).await;
+    let signed = SignedAttestation::new(&fixtures, 0, now - 1);
+    let transaction = Transaction::new_signed_with_payer(
+        &signed.instructions(&fixtures, &fixtures.issuer, &signed.message),
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(17))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_signature_fail_message_mismatch() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let now = current_timestamp(ctx).await;
+    let signed = SignedAttestation::new(&fixtures, 0, now + 60);
+    // Issuer signed a different expiry than the one submitted.
+    let other_message = create_attestation_message(
+        &fixtures.credential,
+        &fixtures.schema,
+        &signed.nonce,
+        &signed.data,
+        now + 3600,
+        signed.deadline,
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &signed.instructions(&fixtures, &fixtures.issuer, &other_message),
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(16))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_signature_fail_unauthorized_signer() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let now = current_timestamp(ctx).await;
+    let signed = SignedAttestation::new(&fixtures, 0, now + 60);
+    let unauthorized = Keypair::new();
+    let transaction = Transaction::new_signed_with_payer(
+        &signed.instructions(&fixtures, &unauthorized, &signed.message),
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(5))
+    );
+}
+
+#[tokio::test]
+async fn close_signature_receipt_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let now = current_timestamp(ctx).await;
+    let signed = SignedAttestation::new(&fixtures, 0, now + 60);
+    submit_signed_attestation(&fixtures, &signed).await;
+
+    let receipt = find_signature_receipt_pda(&signed.message);
+    let receipt_lamports = ctx
+        .banks_client
+        .get_account(receipt)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let cranker = funded_cranker(ctx).await;
+    let payer_lamports_before = ctx
+        .banks_client
+        .get_balance(ctx.payer.pubkey())
+        .await
+        .unwrap();
+
+    warp_to_timestamp(ctx, signed.deadline + 1).await;
+
+    // Anyone may close the receipt once the message can no longer be used.
+    let close_ix = CloseSignatureReceiptBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .signature_receipt(receipt)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&cranker.pubkey()),
+        &[&cranker],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let receipt_account = ctx.banks_client.get_account(receipt).await.unwrap();
+    assert!(receipt_account.is_none());
+
+    // Rent is returned to the original payer, not the cranker.
+    let payer_lamports_after = ctx
+        .banks_client
+        .get_balance(ctx.payer.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(
+        payer_lamports_after,
+        payer_lamports_before + receipt_lamports
+    );
+
+    // The expired message still cannot be resubmitted.
+    let transfer_ix = system_instruction::transfer(&ctx.payer.pubkey(), &Pubkey::new_unique(), 1);
+    let [ed25519_ix, create_ix] = signed.instructions(&fixtures, &fixtures.issuer, &signed.message);
+    let transaction = Transaction::new_signed_with_payer(
+        &[transfer_ix, ed25519_ix, create_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(2, InstructionError::Custom(17))
+    );
+}
+
+#[tokio::test]
+async fn close_signature_receipt_fail_deadline_not_passed() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let now = current_timestamp(ctx).await;
+    let signed = SignedAttestation::new(&fixtures, 0, now + 60);
+    submit_signed_attestation(&fixtures, &signed).await;
+
+    // The message is still valid at its deadline.
+    warp_to_timestamp(ctx, signed.deadline).await;
+
+    let close_ix = CloseSignatureReceiptBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .signature_receipt(find_signature_receipt_pda(&signed.message))
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(36))
+    );
+}
+
+#[tokio::test]
+async fn close_signature_receipt_fail_wrong_payer() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let now = current_timestamp(ctx).await;
+    let signed = SignedAttestation::new(&fixtures, 0, now + 60);
+    submit_signed_attestation(&fixtures, &signed).await;
+
+    warp_to_timestamp(ctx, signed.deadline + 1).await;
+
+    let close_ix = CloseSignatureReceiptBuilder::new()
+        .payer(Pubkey::new_unique())
+        .signature_receipt(find_signature_receipt_pda(&signed.message))
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(14))
+    );
+}
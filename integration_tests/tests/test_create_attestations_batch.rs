@@ -0,0 +1,319 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::Attestation,
+    instructions::{
+        CreateAttestationsBatchBuilder, CreateAttestationsBatchPacker, CreateCredentialBuilder,
+        CreateSchemaBuilder, MAX_TRANSACTION_SIZE,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    types::CreateAttestationEntry,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    authority: Keypair,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .instruction();
+
+    // Create Schema
+    let schema_name = "test_data";
+    let description = "schema for test data";
+    let schema_data = TestData::get_serialized_representation();
+    let field_names = vec!["name".into(), "location".into()];
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description(description.to_string())
+        .name(schema_name.to_string())
+        .layout(schema_data)
+        .field_names(field_names)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        authority,
+    }
+}
+
+fn attestation_entry(index: u8, expiry: i64) -> CreateAttestationEntry {
+    let attestation_data = TestData {
+        name: format!("attest-{index}"),
+        location: index,
+    };
+    let mut serialized_attestation_data = Vec::new();
+    attestation_data
+        .serialize(&mut serialized_attestation_data)
+        .unwrap();
+
+    CreateAttestationEntry {
+        nonce: Pubkey::new_unique(),
+        data: serialized_attestation_data,
+        expiry,
+    }
+}
+
+fn attestation_pda(fixtures: &TestFixtures, nonce: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &fixtures.credential.to_bytes(),
+            &fixtures.schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0
+}
+
+#[tokio::test]
+async fn create_attestations_batch_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let expiry = clock.unix_timestamp + 60;
+    let entries: Vec<_> = (0..20).map(|i| attestation_entry(i, expiry)).collect();
+
+    let instructions = CreateAttestationsBatchPacker::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .add_entries(&entries)
+        .instructions();
+
+    // Entries do not fit in a single transaction, so they are split up.
+    assert!(instructions.len() > 1);
+
+    for ix in instructions {
+        let transaction = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &fixtures.authority],
+            ctx.last_blockhash,
+        );
+        assert!(bincode_size(&transaction) <= MAX_TRANSACTION_SIZE);
+        ctx.banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap();
+    }
+
+    for entry in entries {
+        let attestation_account = ctx
+            .banks_client
+            .get_account(attestation_pda(&fixtures, &entry.nonce))
+            .await
+            .unwrap()
+            .unwrap();
+        let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+        assert_eq!(attestation.nonce, entry.nonce);
+        assert_eq!(attestation.data, entry.data);
+        assert_eq!(attestation.expiry, expiry);
+        assert_eq!(attestation.signer, fixtures.authority.pubkey());
+        assert_eq!(attestation.payer, ctx.payer.pubkey());
+    }
+}
+
+fn bincode_size(transaction: &Transaction) -> usize {
+    // Signatures are prefixed by a 1 byte compact-u16 length.
+    1 + transaction.signatures.len() * 64 + transaction.message.serialize().len()
+}
+
+#[tokio::test]
+async fn create_attestations_batch_fail_unauthorized_signer() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let unauthorized = Keypair::new();
+    let entries: Vec<_> = (0..2).map(|i| attestation_entry(i, 0)).collect();
+    let ix = CreateAttestationsBatchPacker::new()
+        .payer(ctx.payer.pubkey())
+        .authority(unauthorized.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .add_entries(&entries)
+        .instructions()
+        .remove(0);
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &unauthorized],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(5))
+    );
+}
+
+#[tokio::test]
+async fn create_attestations_batch_fail_mismatched_attestation() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let entries: Vec<_> = (0..2).map(|i| attestation_entry(i, 0)).collect();
+    // Accounts passed in the opposite order to the entries.
+    let ix = CreateAttestationsBatchBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .entries(entries.clone())
+        .add_remaining_accounts(&[
+            AccountMeta::new(attestation_pda(&fixtures, &entries[1].nonce), false),
+            AccountMeta::new(attestation_pda(&fixtures, &entries[0].nonce), false),
+        ])
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
+
+#[tokio::test]
+async fn create_attestations_batch_fail_malformed_data() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let entries: Vec<_> = (0..2).map(|i| attestation_entry(i, 0)).collect();
+    let batch_ix = |entries: &[CreateAttestationEntry]| {
+        let attestations: Vec<_> = entries
+            .iter()
+            .map(|entry| AccountMeta::new(attestation_pda(&fixtures, &entry.nonce), false))
+            .collect();
+        CreateAttestationsBatchBuilder::new()
+            .payer(ctx.payer.pubkey())
+            .authority(fixtures.authority.pubkey())
+            .credential(fixtures.credential)
+            .schema(fixtures.schema)
+            .entries(entries.to_vec())
+            .add_remaining_accounts(&attestations)
+            .instruction()
+    };
+
+    // Trailing bytes after the last entry.
+    let mut trailing = batch_ix(&entries);
+    trailing.data.push(0);
+
+    // More entries than Attestation accounts.
+    let mut missing_account = batch_ix(&entries);
+    missing_account.accounts.pop();
+
+    // More Attestation accounts than entries.
+    let mut extra_account = batch_ix(&entries[..1]);
+    extra_account.accounts.push(AccountMeta::new(
+        attestation_pda(&fixtures, &entries[1].nonce),
+        false,
+    ));
+
+    for (ix, expected) in [
+        (trailing, InstructionError::InvalidInstructionData),
+        (missing_account, InstructionError::NotEnoughAccountKeys),
+        (extra_account, InstructionError::InvalidInstructionData),
+    ] {
+        let transaction = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &fixtures.authority],
+            ctx.last_blockhash,
+        );
+        let tx_err = ctx
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .err()
+            .expect("should error")
+            .unwrap();
+        assert_eq!(tx_err, TransactionError::InstructionError(0, expected));
+    }
+}
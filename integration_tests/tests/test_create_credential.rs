@@ -58,4 +58,5 @@ async fn create_credential_success() {
     assert_eq!(credential.name, name.as_bytes());
     assert_eq!(credential.authorized_signers[0], authority.pubkey());
     assert_eq!(credential.authorized_signers[1], ctx.payer.pubkey());
+    assert_eq!(credential.payer, ctx.payer.pubkey());
 }
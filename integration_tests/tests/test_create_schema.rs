@@ -110,4 +110,5 @@ async fn create_schema_success() {
     assert_eq!(schema.is_paused, false);
     assert_eq!(schema.version, 1);
     assert_eq!(schema.name, schema_name.as_bytes());
+    assert_eq!(schema.payer, ctx.payer.pubkey());
 }
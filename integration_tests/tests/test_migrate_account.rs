@@ -0,0 +1,394 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::{Attestation, Credential, Schema},
+    instructions::{
+        CloseAttestationBuilder, CloseExpiredAttestationBuilder, CreateAttestationBuilder,
+        MigrateAccountBuilder, RevokeAttestationBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    attestation: Pubkey,
+    authority: Keypair,
+    subject: Keypair,
+}
+
+fn serialized_vec(data: &[u8]) -> Vec<u8> {
+    [(data.len() as u32).to_le_bytes().as_slice(), data].concat()
+}
+
+fn set_legacy_account(ctx: &mut ProgramTestContext, address: &Pubkey, data: Vec<u8>) {
+    let account = Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: SOLANA_ATTESTATION_SERVICE_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    ctx.set_account(address, &AccountSharedData::from(account));
+}
+
+/// Store a Credential, Schema and Attestation in the layouts they had before
+/// fields were appended to them.
+async fn setup(expiry: i64) -> TestFixtures {
+    let mut ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let credential = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0;
+    let mut data = vec![0];
+    data.extend(authority.pubkey().to_bytes());
+    data.extend(serialized_vec(credential_name.as_bytes()));
+    data.extend(1u32.to_le_bytes());
+    data.extend(authority.pubkey().to_bytes());
+    set_legacy_account(&mut ctx, &credential, data);
+
+    let schema_name = "test_data";
+    let schema = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0;
+    let field_names = borsh::to_vec(&vec!["name".to_string(), "location".to_string()]).unwrap();
+    let mut data = vec![1];
+    data.extend(credential.to_bytes());
+    data.extend(serialized_vec(schema_name.as_bytes()));
+    data.extend(serialized_vec(b"schema for test data"));
+    data.extend(serialized_vec(&[12, 0]));
+    data.extend(serialized_vec(&field_names));
+    data.extend([0, 1]);
+    set_legacy_account(&mut ctx, &schema, data);
+
+    let subject = Keypair::new();
+    let attestation = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &credential.to_bytes(),
+            &schema.to_bytes(),
+            &subject.pubkey().to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0;
+    let attestation_data = borsh::to_vec(&TestData {
+        name: "legacy".to_string(),
+        location: 11,
+    })
+    .unwrap();
+    let mut data = vec![2];
+    data.extend(subject.pubkey().to_bytes());
+    data.extend(credential.to_bytes());
+    data.extend(schema.to_bytes());
+    data.extend(serialized_vec(&attestation_data));
+    data.extend(authority.pubkey().to_bytes());
+    data.extend(expiry.to_le_bytes());
+    data.extend(Pubkey::default().to_bytes());
+    set_legacy_account(&mut ctx, &attestation, data);
+
+    TestFixtures {
+        ctx,
+        credential,
+        schema,
+        attestation,
+        authority,
+        subject,
+    }
+}
+
+async fn send(
+    fixtures: &TestFixtures,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&[&ctx.payer], signers].concat(),
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+fn migrate_ix(fixtures: &TestFixtures, account: Pubkey) -> Instruction {
+    MigrateAccountBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .account(account)
+        .system_program(system_program::ID)
+        .instruction()
+}
+
+fn revoke_ix(fixtures: &TestFixtures) -> Instruction {
+    RevokeAttestationBuilder::new()
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .attestation(fixtures.attestation)
+        .reason(1)
+        .instruction()
+}
+
+fn event_authority() -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID).0
+}
+
+async fn fetch_data(fixtures: &TestFixtures, address: Pubkey) -> Vec<u8> {
+    fixtures
+        .ctx
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .unwrap()
+        .data
+}
+
+#[tokio::test]
+async fn migrate_account_success() {
+    let fixtures = setup(0).await;
+
+    // Without a payer, older Attestations cannot grow to the current layout.
+    let err = send(&fixtures, revoke_ix(&fixtures), &[&fixtures.authority])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(33))
+    );
+
+    for account in [fixtures.credential, fixtures.schema, fixtures.attestation] {
+        send(&fixtures, migrate_ix(&fixtures, account), &[])
+            .await
+            .unwrap();
+    }
+
+    let credential =
+        Credential::try_from_slice(&fetch_data(&fixtures, fixtures.credential).await).unwrap();
+    assert_eq!(
+        credential.authorized_signers,
+        vec![fixtures.authority.pubkey()]
+    );
+    assert_eq!(credential.payer, Pubkey::default());
+    assert_eq!(credential.schema_count, u32::MAX);
+
+    let schema = Schema::try_from_slice(&fetch_data(&fixtures, fixtures.schema).await).unwrap();
+    assert_eq!(schema.layout, vec![12, 0]);
+    assert_eq!(schema.version, 1);
+    assert_eq!(schema.attestation_count, u64::MAX);
+
+    let attestation =
+        Attestation::try_from_slice(&fetch_data(&fixtures, fixtures.attestation).await).unwrap();
+    assert_eq!(attestation.signer, fixtures.authority.pubkey());
+    assert_eq!(attestation.revoked_at, 0);
+    assert_eq!(attestation.created_at, 0);
+    assert_eq!(attestation.payer, Pubkey::default());
+
+    let attestation_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.attestation)
+        .await
+        .unwrap()
+        .unwrap();
+    let rent: Rent = fixtures.ctx.banks_client.get_sysvar().await.unwrap();
+    assert_eq!(
+        attestation_account.lamports,
+        rent.minimum_balance(attestation_account.data.len())
+    );
+
+    send(&fixtures, revoke_ix(&fixtures), &[&fixtures.authority])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn create_attestation_under_legacy_schema() {
+    let fixtures = setup(0).await;
+    let schema_data = fetch_data(&fixtures, fixtures.schema).await;
+
+    let nonce = Pubkey::new_unique();
+    let attestation = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &fixtures.credential.to_bytes(),
+            &fixtures.schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0;
+    let ix = CreateAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(attestation)
+        .system_program(system_program::ID)
+        .data(
+            borsh::to_vec(&TestData {
+                name: "new".to_string(),
+                location: 1,
+            })
+            .unwrap(),
+        )
+        .expiry(0)
+        .nonce(nonce)
+        .instruction();
+    send(&fixtures, ix, &[&fixtures.authority]).await.unwrap();
+
+    // The Schema has no attestation count to adjust, so it is left untouched.
+    assert_eq!(fetch_data(&fixtures, fixtures.schema).await, schema_data);
+}
+
+#[tokio::test]
+async fn close_expired_migrated_attestation() {
+    // Expired long ago.
+    let fixtures = setup(1).await;
+    for account in [fixtures.credential, fixtures.schema, fixtures.attestation] {
+        send(&fixtures, migrate_ix(&fixtures, account), &[])
+            .await
+            .unwrap();
+    }
+    let attestation_lamports = fixtures
+        .ctx
+        .banks_client
+        .get_balance(fixtures.attestation)
+        .await
+        .unwrap();
+
+    let close_expired_ix = |payer: Pubkey| {
+        CloseExpiredAttestationBuilder::new()
+            .payer(payer)
+            .credential(fixtures.credential)
+            .schema(fixtures.schema)
+            .attestation(fixtures.attestation)
+            .event_authority(event_authority())
+            .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+            .instruction()
+    };
+
+    // No payer is stored, so the rent goes to the Credential authority.
+    let err = send(
+        &fixtures,
+        close_expired_ix(fixtures.ctx.payer.pubkey()),
+        &[],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(14))
+    );
+
+    send(
+        &fixtures,
+        close_expired_ix(fixtures.authority.pubkey()),
+        &[],
+    )
+    .await
+    .unwrap();
+    let banks_client = &fixtures.ctx.banks_client;
+    assert!(banks_client
+        .get_account(fixtures.attestation)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        banks_client
+            .get_balance(fixtures.authority.pubkey())
+            .await
+            .unwrap(),
+        attestation_lamports
+    );
+}
+
+#[tokio::test]
+async fn close_migrated_attestation_by_subject() {
+    let fixtures = setup(0).await;
+    for account in [fixtures.credential, fixtures.schema, fixtures.attestation] {
+        send(&fixtures, migrate_ix(&fixtures, account), &[])
+            .await
+            .unwrap();
+    }
+
+    let close_ix = |payer: Pubkey| {
+        CloseAttestationBuilder::new()
+            .payer(payer)
+            .authority(fixtures.subject.pubkey())
+            .credential(fixtures.credential)
+            .schema(fixtures.schema)
+            .attestation(fixtures.attestation)
+            .event_authority(event_authority())
+            .system_program(system_program::ID)
+            .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+            .instruction()
+    };
+
+    // The subject cannot claim the rent of an Attestation without a stored
+    // payer, it goes to the Credential authority.
+    let err = send(
+        &fixtures,
+        close_ix(fixtures.subject.pubkey()),
+        &[&fixtures.subject],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(14))
+    );
+
+    send(
+        &fixtures,
+        close_ix(fixtures.authority.pubkey()),
+        &[&fixtures.subject],
+    )
+    .await
+    .unwrap();
+    assert!(fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.attestation)
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,338 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::Attestation,
+    instructions::{
+        CreateAttestationBuilder, CreateCredentialBuilder, CreateSchemaBuilder,
+        RenewAttestationBuilder, RevokeAttestationBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    types::RenewAttestationEvent,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    clock::Clock,
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    authority: Keypair,
+    attestation: Pubkey,
+    expiry: i64,
+}
+
+pub const EVENT_IX_TAG: u64 = 0x1d9acb512ea545e4;
+pub const EVENT_IX_TAG_LE: &[u8] = EVENT_IX_TAG.to_le_bytes().as_slice();
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .instruction();
+
+    // Create Schema
+    let schema_name = "test_data";
+    let description = "schema for test data";
+    let schema_data = TestData::get_serialized_representation();
+    let field_names = vec!["name".into(), "location".into()];
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description(description.to_string())
+        .name(schema_name.to_string())
+        .layout(schema_data.clone())
+        .field_names(field_names)
+        .instruction();
+
+    // Create Attestation
+    let attestation_data = TestData {
+        name: "attest".to_string(),
+        location: 11,
+    };
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let expiry: i64 = clock.unix_timestamp + 60;
+    let mut serialized_attestation_data = Vec::new();
+    attestation_data
+        .serialize(&mut serialized_attestation_data)
+        .unwrap();
+    let nonce = Pubkey::new_unique();
+    let attestation_pda = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &credential_pda.to_bytes(),
+            &schema_pda.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0;
+    let create_attestation_ix = CreateAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(serialized_attestation_data)
+        .expiry(expiry)
+        .nonce(nonce)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            create_credential_ix,
+            create_schema_ix,
+            create_attestation_ix,
+        ],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        authority,
+        attestation: attestation_pda,
+        expiry,
+    }
+}
+
+fn renew_attestation_ix(fixtures: &TestFixtures, authority: &Pubkey, expiry: i64) -> Instruction {
+    let (event_auth_pda, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+
+    RenewAttestationBuilder::new()
+        .authority(*authority)
+        .credential(fixtures.credential)
+        .attestation(fixtures.attestation)
+        .event_authority(event_auth_pda)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .expiry(expiry)
+        .instruction()
+}
+
+async fn renew_attestation_err(fixtures: &TestFixtures, ixs: &[Instruction]) -> TransactionError {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        ixs,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap()
+}
+
+#[tokio::test]
+async fn renew_attestation_success() {
+    let fixtures = setup().await;
+    let new_expiry = fixtures.expiry + 3600;
+
+    let ix = renew_attestation_ix(&fixtures, &fixtures.authority.pubkey(), new_expiry);
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+
+    // Simulate transaction to check if event is emitted correctly.
+    let simulate_res = ctx
+        .banks_client
+        .simulate_transaction(transaction.clone())
+        .await
+        .unwrap();
+    let inner_ixs = simulate_res
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap();
+    let mut event_found = false;
+    for inner_instr_group in inner_ixs {
+        for inner_instr in inner_instr_group {
+            let program_id = inner_instr
+                .instruction
+                .program_id(&transaction.message.account_keys);
+
+            if program_id.eq(&SOLANA_ATTESTATION_SERVICE_ID) {
+                let data = inner_instr.instruction.data;
+                if data.starts_with(EVENT_IX_TAG_LE) {
+                    let event = RenewAttestationEvent::try_from_slice(&data[8..]).unwrap();
+                    assert_eq!(event.discriminator, 2);
+                    assert_eq!(event.schema, fixtures.schema);
+                    assert_eq!(event.attestation, fixtures.attestation);
+                    assert_eq!(event.old_expiry, fixtures.expiry);
+                    assert_eq!(event.new_expiry, new_expiry);
+                    event_found = true;
+                }
+            }
+        }
+    }
+    assert!(event_found);
+
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let attestation_account = ctx
+        .banks_client
+        .get_account(fixtures.attestation)
+        .await
+        .unwrap()
+        .unwrap();
+    let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert_eq!(attestation.expiry, new_expiry);
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    assert_eq!(attestation.updated_at, clock.unix_timestamp);
+}
+
+#[tokio::test]
+async fn renew_attestation_never_expires() {
+    let fixtures = setup().await;
+
+    let ix = renew_attestation_ix(&fixtures, &fixtures.authority.pubkey(), 0);
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let attestation_account = ctx
+        .banks_client
+        .get_account(fixtures.attestation)
+        .await
+        .unwrap()
+        .unwrap();
+    let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert_eq!(attestation.expiry, 0);
+}
+
+#[tokio::test]
+async fn renew_attestation_fail_expiry_in_past() {
+    let fixtures = setup().await;
+
+    let clock: Clock = fixtures.ctx.banks_client.get_sysvar().await.unwrap();
+    let ix = renew_attestation_ix(
+        &fixtures,
+        &fixtures.authority.pubkey(),
+        clock.unix_timestamp - 1,
+    );
+    let tx_err = renew_attestation_err(&fixtures, &[ix]).await;
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(6))
+    );
+}
+
+#[tokio::test]
+async fn renew_attestation_fail_revoked() {
+    let fixtures = setup().await;
+
+    let revoke_ix = RevokeAttestationBuilder::new()
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .attestation(fixtures.attestation)
+        .reason(0)
+        .instruction();
+    let renew_ix = renew_attestation_ix(
+        &fixtures,
+        &fixtures.authority.pubkey(),
+        fixtures.expiry + 3600,
+    );
+    let tx_err = renew_attestation_err(&fixtures, &[revoke_ix, renew_ix]).await;
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(12))
+    );
+}
+
+#[tokio::test]
+async fn renew_attestation_fail_unauthorized_signer() {
+    let fixtures = setup().await;
+
+    let unauthorized = Keypair::new();
+    let ix = renew_attestation_ix(&fixtures, &unauthorized.pubkey(), fixtures.expiry + 3600);
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &unauthorized],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(5))
+    );
+}
@@ -0,0 +1,276 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::Attestation,
+    instructions::{
+        CreateAttestationBuilder, CreateCredentialBuilder, CreateSchemaBuilder,
+        RevokeAttestationBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    clock::Clock,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    authority: Keypair,
+    attestation: Pubkey,
+    serialized_attestation_data: Vec<u8>,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .instruction();
+
+    // Create Schema
+    let schema_name = "test_data";
+    let description = "schema for test data";
+    let schema_data = TestData::get_serialized_representation();
+    let field_names = vec!["name".into(), "location".into()];
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description(description.to_string())
+        .name(schema_name.to_string())
+        .layout(schema_data.clone())
+        .field_names(field_names)
+        .instruction();
+
+    // Create Attestation
+    let attestation_data = TestData {
+        name: "attest".to_string(),
+        location: 11,
+    };
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let expiry: i64 = clock.unix_timestamp + 60;
+    let mut serialized_attestation_data = Vec::new();
+    attestation_data
+        .serialize(&mut serialized_attestation_data)
+        .unwrap();
+    let nonce = Pubkey::new_unique();
+    let attestation_pda = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &credential_pda.to_bytes(),
+            &schema_pda.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0;
+    let create_attestation_ix = CreateAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(serialized_attestation_data.clone())
+        .expiry(expiry)
+        .nonce(nonce)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            create_credential_ix,
+            create_schema_ix,
+            create_attestation_ix,
+        ],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        authority,
+        attestation: attestation_pda,
+        serialized_attestation_data,
+    }
+}
+
+#[tokio::test]
+async fn revoke_attestation_success() {
+    let TestFixtures {
+        ctx,
+        credential,
+        authority,
+        attestation,
+        serialized_attestation_data,
+    } = setup().await;
+
+    let revoke_attestation_ix = RevokeAttestationBuilder::new()
+        .authority(authority.pubkey())
+        .credential(credential)
+        .attestation(attestation)
+        .reason(3)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[revoke_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Attestation account should still exist with the revocation recorded.
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let attestation_account = ctx
+        .banks_client
+        .get_account(attestation)
+        .await
+        .unwrap()
+        .unwrap();
+    let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert!(attestation.is_revoked());
+    assert_eq!(attestation.revoked_at, clock.unix_timestamp);
+    assert_eq!(attestation.revoked_by, authority.pubkey());
+    assert_eq!(attestation.revocation_reason, 3);
+    assert_eq!(attestation.updated_at, clock.unix_timestamp);
+    assert_eq!(attestation.data, serialized_attestation_data);
+}
+
+#[tokio::test]
+async fn revoke_attestation_fail_already_revoked() {
+    let TestFixtures {
+        ctx,
+        credential,
+        authority,
+        attestation,
+        ..
+    } = setup().await;
+
+    let revoke_attestation_ix = RevokeAttestationBuilder::new()
+        .authority(authority.pubkey())
+        .credential(credential)
+        .attestation(attestation)
+        .reason(1)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[revoke_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Revoking again should not overwrite the original revocation.
+    let revoke_attestation_ix = RevokeAttestationBuilder::new()
+        .authority(authority.pubkey())
+        .credential(credential)
+        .attestation(attestation)
+        .reason(2)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[revoke_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(12))
+    );
+}
+
+#[tokio::test]
+async fn revoke_attestation_fail_unauthorized_signer() {
+    let TestFixtures {
+        ctx,
+        credential,
+        attestation,
+        ..
+    } = setup().await;
+
+    let unauthorized = Keypair::new();
+    let revoke_attestation_ix = RevokeAttestationBuilder::new()
+        .authority(unauthorized.pubkey())
+        .credential(credential)
+        .attestation(attestation)
+        .reason(1)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[revoke_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &unauthorized],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(5))
+    );
+}
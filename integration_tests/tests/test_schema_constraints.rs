@@ -0,0 +1,327 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::Schema,
+    instructions::{
+        AttestationDataError, CreateAttestationBuilder, CreateCredentialBuilder,
+        CreateSchemaBuilder, FieldConstraint, UpdateAttestationBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    age: u8,
+    country: String,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    authority: Keypair,
+}
+
+fn constraints() -> Vec<FieldConstraint> {
+    vec![
+        FieldConstraint::non_empty(0),
+        FieldConstraint::max_len(0, 16),
+        FieldConstraint::min(1, &18u8),
+        FieldConstraint::max(1, &120u8),
+        FieldConstraint::one_of(2, &["US".to_string(), "FR".to_string()]),
+    ]
+}
+
+fn create_schema_ix(
+    ctx: &ProgramTestContext,
+    authority: &Pubkey,
+    credential: &Pubkey,
+    constraints: &[FieldConstraint],
+) -> (Instruction, Pubkey) {
+    let schema_name = "test_data";
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(*authority)
+        .credential(*credential)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("schema with constraints".to_string())
+        .name(schema_name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "age".into(), "country".into()])
+        .constraints(FieldConstraint::to_bytes(constraints))
+        .instruction();
+    (ix, schema_pda)
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .instruction();
+    let (create_schema_ix, schema_pda) =
+        create_schema_ix(&ctx, &authority.pubkey(), &credential_pda, &constraints());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        authority,
+    }
+}
+
+async fn fetch_schema(fixtures: &TestFixtures) -> Schema {
+    let schema_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.schema)
+        .await
+        .unwrap()
+        .unwrap();
+    Schema::try_from_slice(&schema_account.data).unwrap()
+}
+
+fn serialize(name: &str, age: u8, country: &str) -> Vec<u8> {
+    let mut data = Vec::new();
+    TestData {
+        name: name.to_string(),
+        age,
+        country: country.to_string(),
+    }
+    .serialize(&mut data)
+    .unwrap();
+    data
+}
+
+fn create_attestation_ix(fixtures: &TestFixtures, nonce: Pubkey, data: Vec<u8>) -> Instruction {
+    let (attestation_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &fixtures.credential.to_bytes(),
+            &fixtures.schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    CreateAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(data)
+        .expiry(0)
+        .nonce(nonce)
+        .instruction()
+}
+
+async fn send(fixtures: &TestFixtures, ix: Instruction) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+#[tokio::test]
+async fn create_schema_with_constraints_success() {
+    let fixtures = setup().await;
+
+    let schema = fetch_schema(&fixtures).await;
+    assert_eq!(schema.field_constraints().unwrap(), constraints());
+}
+
+#[tokio::test]
+async fn create_schema_fail_invalid_constraint() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let credential_name = "other";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &fixtures.authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(fixtures.authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![fixtures.authority.pubkey()])
+        .instruction();
+    // MaxLen does not apply to an integer field.
+    let (create_schema_ix, _schema_pda) = create_schema_ix(
+        ctx,
+        &fixtures.authority.pubkey(),
+        &credential_pda,
+        &[FieldConstraint::max_len(1, 3)],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    let err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(1, InstructionError::Custom(24))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_constraints_success() {
+    let fixtures = setup().await;
+    let schema = fetch_schema(&fixtures).await;
+
+    for (name, age, country) in [("alice", 18, "US"), ("bob", 120, "FR")] {
+        let data = serialize(name, age, country);
+        assert_eq!(schema.validate_attestation_data(&data), Ok(()));
+        send(
+            &fixtures,
+            create_attestation_ix(&fixtures, Pubkey::new_unique(), data),
+        )
+        .await
+        .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn create_attestation_fail_constraint_violated() {
+    let fixtures = setup().await;
+    let schema = fetch_schema(&fixtures).await;
+    let constraints = constraints();
+
+    for (data, violated) in [
+        (serialize("", 21, "US"), &constraints[0]),
+        (
+            serialize("a very long name indeed", 21, "US"),
+            &constraints[1],
+        ),
+        (serialize("alice", 17, "US"), &constraints[2]),
+        (serialize("alice", 121, "US"), &constraints[3]),
+        (serialize("alice", 21, "DE"), &constraints[4]),
+    ] {
+        assert_eq!(
+            schema.validate_attestation_data(&data),
+            Err(AttestationDataError::ConstraintViolated(violated.clone()))
+        );
+        let err = send(
+            &fixtures,
+            create_attestation_ix(&fixtures, Pubkey::new_unique(), data),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(0, InstructionError::Custom(25))
+        );
+    }
+}
+
+#[tokio::test]
+async fn update_attestation_fail_constraint_violated() {
+    let fixtures = setup().await;
+    let nonce = Pubkey::new_unique();
+    send(
+        &fixtures,
+        create_attestation_ix(&fixtures, nonce, serialize("alice", 21, "US")),
+    )
+    .await
+    .unwrap();
+
+    let (attestation_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &fixtures.credential.to_bytes(),
+            &fixtures.schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let (event_auth_pda, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+    let update_ix = UpdateAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(attestation_pda)
+        .event_authority(event_auth_pda)
+        .system_program(system_program::ID)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .data(serialize("alice", 15, "US"))
+        .instruction();
+    let err = send(&fixtures, update_ix).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(25))
+    );
+}
@@ -0,0 +1,602 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::{Attestation, Schema},
+    instructions::{
+        AttestationDataError, CreateAttestationBuilder, CreateCredentialBuilder,
+        CreateSchemaBuilder, FieldConstraint,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    types::{Decimal, Timestamp},
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    clock::Clock,
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, BorshDeserialize, SchemaStructSerialize, Debug, PartialEq)]
+struct DocumentData {
+    owner: Pubkey,
+    digest: [u8; 32],
+    signature: [u8; 64],
+}
+
+#[derive(BorshSerialize, BorshDeserialize, SchemaStructSerialize, Debug, PartialEq)]
+struct OptionalData {
+    name: Option<String>,
+    age: Option<u8>,
+    scores: Option<Vec<i32>>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, SchemaStructSerialize, Debug, PartialEq)]
+struct License {
+    jurisdiction: String,
+    expiry: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, SchemaStructSerialize, Debug, PartialEq)]
+struct DriverData {
+    name: String,
+    primary: License,
+    endorsements: Vec<License>,
+}
+
+/// Field names of `DriverData`, each struct field followed by its own fields.
+fn driver_field_names() -> Vec<String> {
+    [
+        "name",
+        "primary",
+        "jurisdiction",
+        "expiry",
+        "endorsements",
+        "jurisdiction",
+        "expiry",
+    ]
+    .map(String::from)
+    .to_vec()
+}
+
+#[derive(BorshSerialize, BorshDeserialize, SchemaStructSerialize, Debug, PartialEq)]
+struct ScoreData {
+    ratio: f64,
+    score: Decimal<2>,
+    expiry: Timestamp,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, SchemaStructSerialize, Debug, PartialEq)]
+enum Tier {
+    Basic,
+    Pro,
+    Enterprise,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, SchemaStructSerialize, Debug, PartialEq)]
+struct MembershipData {
+    member: String,
+    tier: Tier,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    authority: Keypair,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        authority,
+    }
+}
+
+/// Create a Schema named `name` with `layout`, `constraints` and Enum
+/// `enum_variants`, returning its address.
+async fn create_schema(
+    fixtures: &TestFixtures,
+    name: &str,
+    layout: Vec<u8>,
+    field_names: Vec<String>,
+    constraints: &[FieldConstraint],
+    enum_variants: Vec<String>,
+) -> Pubkey {
+    let ctx = &fixtures.ctx;
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &fixtures.credential.to_bytes(),
+            name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description(format!("{name} schema"))
+        .name(name.to_string())
+        .layout(layout)
+        .field_names(field_names)
+        .constraints(FieldConstraint::to_bytes(constraints))
+        .enum_variants(enum_variants)
+        .instruction();
+    send(fixtures, create_schema_ix).await.unwrap();
+    schema_pda
+}
+
+async fn fetch_schema(fixtures: &TestFixtures, schema: Pubkey) -> Schema {
+    let schema_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(schema)
+        .await
+        .unwrap()
+        .unwrap();
+    Schema::try_from_slice(&schema_account.data).unwrap()
+}
+
+/// Create an Attestation of `schema` with `data`, returning its address.
+async fn create_attestation(
+    fixtures: &TestFixtures,
+    schema: Pubkey,
+    data: Vec<u8>,
+) -> Result<Pubkey, TransactionError> {
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &fixtures.credential.to_bytes(),
+            &schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_attestation_ix = CreateAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(data)
+        .expiry(0)
+        .nonce(nonce)
+        .instruction();
+    send(fixtures, create_attestation_ix).await?;
+    Ok(attestation_pda)
+}
+
+async fn fetch_attestation_data(fixtures: &TestFixtures, attestation: Pubkey) -> Vec<u8> {
+    let attestation_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(attestation)
+        .await
+        .unwrap()
+        .unwrap();
+    Attestation::try_from_slice(&attestation_account.data)
+        .unwrap()
+        .data
+}
+
+async fn send(fixtures: &TestFixtures, ix: Instruction) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+#[tokio::test]
+async fn create_attestation_with_option_fields() {
+    let fixtures = setup().await;
+    let layout = OptionalData::get_serialized_representation();
+    assert_eq!(layout, vec![38, 26, 46]);
+    let schema = create_schema(
+        &fixtures,
+        "optional",
+        layout,
+        vec!["name".into(), "age".into(), "scores".into()],
+        &[],
+        vec![],
+    )
+    .await;
+    let schema_account = fetch_schema(&fixtures, schema).await;
+
+    for value in [
+        OptionalData {
+            name: Some("alice".to_string()),
+            age: None,
+            scores: Some(vec![1, -2]),
+        },
+        OptionalData {
+            name: None,
+            age: Some(21),
+            scores: None,
+        },
+    ] {
+        let data = borsh::to_vec(&value).unwrap();
+        assert_eq!(schema_account.validate_attestation_data(&data), Ok(()));
+        let attestation = create_attestation(&fixtures, schema, data).await.unwrap();
+
+        let stored = fetch_attestation_data(&fixtures, attestation).await;
+        assert_eq!(OptionalData::try_from_slice(&stored).unwrap(), value);
+    }
+}
+
+#[tokio::test]
+async fn create_attestation_fail_invalid_option_tag() {
+    let fixtures = setup().await;
+    let schema = create_schema(
+        &fixtures,
+        "optional",
+        OptionalData::get_serialized_representation(),
+        vec!["name".into(), "age".into(), "scores".into()],
+        &[],
+        vec![],
+    )
+    .await;
+
+    // Tag of `age` is neither 0 (None) nor 1 (Some).
+    let data = vec![0, 2, 21, 0];
+    let schema_account = fetch_schema(&fixtures, schema).await;
+    assert!(schema_account.validate_attestation_data(&data).is_err());
+    let err = create_attestation(&fixtures, schema, data)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(6))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_fixed_size_fields() {
+    let fixtures = setup().await;
+    let layout = DocumentData::get_serialized_representation();
+    assert_eq!(layout, vec![52, 53, 54]);
+    let schema = create_schema(
+        &fixtures,
+        "document",
+        layout,
+        vec!["owner".into(), "digest".into(), "signature".into()],
+        &[],
+        vec![],
+    )
+    .await;
+    let schema_account = fetch_schema(&fixtures, schema).await;
+
+    let value = DocumentData {
+        owner: Pubkey::new_unique(),
+        digest: [7; 32],
+        signature: [9; 64],
+    };
+    let data = borsh::to_vec(&value).unwrap();
+    // Fixed size fields are stored without a length prefix.
+    assert_eq!(data.len(), 128);
+    assert_eq!(schema_account.validate_attestation_data(&data), Ok(()));
+    let attestation = create_attestation(&fixtures, schema, data.clone())
+        .await
+        .unwrap();
+    let stored = fetch_attestation_data(&fixtures, attestation).await;
+    assert_eq!(DocumentData::try_from_slice(&stored).unwrap(), value);
+
+    // Truncated signature
+    let err = create_attestation(&fixtures, schema, data[..127].to_vec())
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(6))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_struct_fields() {
+    let fixtures = setup().await;
+    let layout = DriverData::get_serialized_representation();
+    assert_eq!(layout, vec![12, 55, 2, 12, 8, 56, 2, 12, 8]);
+    let schema = create_schema(
+        &fixtures,
+        "driver",
+        layout,
+        driver_field_names(),
+        &[],
+        vec![],
+    )
+    .await;
+    let schema_account = fetch_schema(&fixtures, schema).await;
+    assert_eq!(schema_account.field_index("endorsements"), Some(2));
+
+    let value = DriverData {
+        name: "alice".to_string(),
+        primary: License {
+            jurisdiction: "CA".to_string(),
+            expiry: 1_900_000_000,
+        },
+        endorsements: vec![
+            License {
+                jurisdiction: "NV".to_string(),
+                expiry: 1_800_000_000,
+            },
+            License {
+                jurisdiction: "OR".to_string(),
+                expiry: 1_700_000_000,
+            },
+        ],
+    };
+    let data = borsh::to_vec(&value).unwrap();
+    assert_eq!(schema_account.validate_attestation_data(&data), Ok(()));
+    let attestation = create_attestation(&fixtures, schema, data.clone())
+        .await
+        .unwrap();
+    let stored = fetch_attestation_data(&fixtures, attestation).await;
+    assert_eq!(DriverData::try_from_slice(&stored).unwrap(), value);
+
+    // Truncated last endorsement
+    let err = create_attestation(&fixtures, schema, data[..data.len() - 1].to_vec())
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(6))
+    );
+}
+
+#[tokio::test]
+async fn create_schema_fail_struct_field_names_mismatch() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[b"schema", &fixtures.credential.to_bytes(), b"driver", &[1]],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    // Nested field names are missing.
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("driver schema".to_string())
+        .name("driver".to_string())
+        .layout(DriverData::get_serialized_representation())
+        .field_names(vec!["name".into(), "primary".into(), "endorsements".into()])
+        .instruction();
+    let err = send(&fixtures, create_schema_ix).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_numeric_fields() {
+    let fixtures = setup().await;
+    let layout = ScoreData::get_serialized_representation();
+    assert_eq!(layout, vec![58, 59, 2, 60]);
+    let schema = create_schema(
+        &fixtures,
+        "score",
+        layout,
+        vec!["ratio".into(), "score".into(), "expiry".into()],
+        &[
+            FieldConstraint::max(0, &1.0f64),
+            FieldConstraint::min(1, &Decimal::<2>(0)),
+            // Must not be expired when attested, nor expire more than a day
+            // later.
+            FieldConstraint::not_before(2, 0),
+            FieldConstraint::not_after(2, 86_400),
+        ],
+        vec![],
+    )
+    .await;
+    let schema_account = fetch_schema(&fixtures, schema).await;
+    let clock: Clock = fixtures.ctx.banks_client.get_sysvar().await.unwrap();
+
+    let value = ScoreData {
+        ratio: 0.25,
+        score: Decimal(71_250),
+        expiry: Timestamp(clock.unix_timestamp + 3600),
+    };
+    assert_eq!(value.score.to_string(), "712.50");
+    let data = borsh::to_vec(&value).unwrap();
+    assert_eq!(
+        schema_account.validate_attestation_data_at(&data, clock.unix_timestamp),
+        Ok(())
+    );
+    let attestation = create_attestation(&fixtures, schema, data).await.unwrap();
+    let stored = fetch_attestation_data(&fixtures, attestation).await;
+    assert_eq!(ScoreData::try_from_slice(&stored).unwrap(), value);
+
+    for (value, violated) in [
+        (
+            ScoreData {
+                ratio: 1.5,
+                ..value
+            },
+            FieldConstraint::max(0, &1.0f64),
+        ),
+        (
+            ScoreData {
+                score: Decimal(-1),
+                ..value
+            },
+            FieldConstraint::min(1, &Decimal::<2>(0)),
+        ),
+        (
+            ScoreData {
+                expiry: Timestamp(clock.unix_timestamp - 1),
+                ..value
+            },
+            FieldConstraint::not_before(2, 0),
+        ),
+        (
+            ScoreData {
+                expiry: Timestamp(clock.unix_timestamp + 86_401),
+                ..value
+            },
+            FieldConstraint::not_after(2, 86_400),
+        ),
+    ] {
+        let data = borsh::to_vec(&value).unwrap();
+        assert_eq!(
+            schema_account.validate_attestation_data_at(&data, clock.unix_timestamp),
+            Err(AttestationDataError::ConstraintViolated(violated))
+        );
+        let err = create_attestation(&fixtures, schema, data)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(0, InstructionError::Custom(25))
+        );
+    }
+}
+
+#[tokio::test]
+async fn create_attestation_with_enum_field() {
+    let fixtures = setup().await;
+    let layout = MembershipData::get_serialized_representation();
+    assert_eq!(layout, vec![12, 61, 3]);
+    let schema = create_schema(
+        &fixtures,
+        "membership",
+        layout,
+        vec!["member".into(), "tier".into()],
+        &[FieldConstraint::one_of(1, &[Tier::Pro, Tier::Enterprise])],
+        MembershipData::get_enum_variant_names(),
+    )
+    .await;
+    let schema_account = fetch_schema(&fixtures, schema).await;
+    assert_eq!(
+        schema_account.enum_variants(),
+        vec![vec![
+            "Basic".to_string(),
+            "Pro".to_string(),
+            "Enterprise".to_string()
+        ]]
+    );
+
+    let value = MembershipData {
+        member: "alice".to_string(),
+        tier: Tier::Enterprise,
+    };
+    let data = borsh::to_vec(&value).unwrap();
+    assert_eq!(schema_account.validate_attestation_data(&data), Ok(()));
+    let attestation = create_attestation(&fixtures, schema, data.clone())
+        .await
+        .unwrap();
+    let stored = fetch_attestation_data(&fixtures, attestation).await;
+    assert_eq!(MembershipData::try_from_slice(&stored).unwrap(), value);
+
+    // Out of range discriminant
+    let mut invalid = data.clone();
+    *invalid.last_mut().unwrap() = 3;
+    assert_eq!(
+        schema_account.validate_attestation_data(&invalid),
+        Err(AttestationDataError::InvalidLayout)
+    );
+    let err = create_attestation(&fixtures, schema, invalid)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(6))
+    );
+
+    // Basic is not allowed by the constraint.
+    let mut basic = data;
+    *basic.last_mut().unwrap() = 0;
+    let err = create_attestation(&fixtures, schema, basic)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(25))
+    );
+}
+
+#[tokio::test]
+async fn create_schema_fail_enum_variants_mismatch() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &fixtures.credential.to_bytes(),
+            b"membership",
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    // Only 2 of the 3 variants are named.
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("membership schema".to_string())
+        .name("membership".to_string())
+        .layout(MembershipData::get_serialized_representation())
+        .field_names(vec!["member".into(), "tier".into()])
+        .enum_variants(vec!["Basic".into(), "Pro".into()])
+        .instruction();
+    let err = send(&fixtures, create_schema_ix).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+}
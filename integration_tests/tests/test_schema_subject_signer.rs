@@ -0,0 +1,256 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::{Attestation, Schema},
+    instructions::{
+        CreateAttestationBuilder, CreateAttestationsBatchBuilder, CreateCredentialBuilder,
+        CreateSchemaBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    types::CreateAttestationEntry,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{AccountMeta, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    authority: Keypair,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .instruction();
+
+    // Create Schema
+    let schema_name = "test_data";
+    let description = "schema for test data";
+    let schema_data = TestData::get_serialized_representation();
+    let field_names = vec!["name".into(), "location".into()];
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description(description.to_string())
+        .name(schema_name.to_string())
+        .layout(schema_data)
+        .field_names(field_names)
+        // Bind Attestation nonces to the subject's wallet
+        .requires_subject_signer(true)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        authority,
+    }
+}
+
+fn attestation_data() -> Vec<u8> {
+    let attestation_data = TestData {
+        name: "attest".to_string(),
+        location: 11,
+    };
+    let mut serialized_attestation_data = Vec::new();
+    attestation_data
+        .serialize(&mut serialized_attestation_data)
+        .unwrap();
+    serialized_attestation_data
+}
+
+fn attestation_pda(fixtures: &TestFixtures, nonce: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &fixtures.credential.to_bytes(),
+            &fixtures.schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0
+}
+
+fn create_attestation_builder(fixtures: &TestFixtures, nonce: Pubkey) -> CreateAttestationBuilder {
+    let mut builder = CreateAttestationBuilder::new();
+    builder
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(attestation_pda(fixtures, &nonce))
+        .system_program(system_program::ID)
+        .data(attestation_data())
+        .expiry(0)
+        .nonce(nonce);
+    builder
+}
+
+#[tokio::test]
+async fn subject_bound_attestation_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let schema_account = ctx
+        .banks_client
+        .get_account(fixtures.schema)
+        .await
+        .unwrap()
+        .unwrap();
+    let schema = Schema::try_from_slice(&schema_account.data).unwrap();
+    assert!(schema.requires_subject_signer);
+
+    // Subject co-signs with the wallet used as nonce.
+    let subject = Keypair::new();
+    let create_attestation_ix = create_attestation_builder(&fixtures, subject.pubkey())
+        .add_remaining_account(AccountMeta::new_readonly(subject.pubkey(), true))
+        .instruction();
+    let attestation_pda = create_attestation_ix.accounts[4].pubkey;
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority, &subject],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let attestation_account = ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert_eq!(attestation.nonce, subject.pubkey());
+}
+
+#[tokio::test]
+async fn subject_bound_attestation_fail_missing_subject_signature() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let subject = Keypair::new();
+    let create_attestation_ix = create_attestation_builder(&fixtures, subject.pubkey())
+        .add_remaining_account(AccountMeta::new_readonly(subject.pubkey(), false))
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(15))
+    );
+}
+
+#[tokio::test]
+async fn subject_bound_attestations_batch_fail_not_supported() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let subject = Keypair::new();
+    let entry = CreateAttestationEntry {
+        nonce: subject.pubkey(),
+        data: attestation_data(),
+        expiry: 0,
+    };
+    let ix = CreateAttestationsBatchBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .entries(vec![entry])
+        .add_remaining_account(AccountMeta::new(
+            attestation_pda(&fixtures, &subject.pubkey()),
+            false,
+        ))
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(35))
+    );
+}
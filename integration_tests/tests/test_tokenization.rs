@@ -50,6 +50,7 @@ struct TestFixtures {
     recipient: Pubkey,
     recipient_token_account: Pubkey,
     nonce: Pubkey,
+    subject: Keypair,
     serialized_attestation_data: Vec<u8>,
 }
 
@@ -119,7 +120,9 @@ async fn setup() -> TestFixtures {
         &SOLANA_ATTESTATION_SERVICE_ID,
     );
 
-    let nonce = Pubkey::new_unique();
+    // Attestation nonce is the subject's wallet.
+    let subject = Keypair::new();
+    let nonce = subject.pubkey();
     let attestation_pda = Pubkey::find_program_address(
         &[
             b"attestation",
@@ -164,6 +167,7 @@ async fn setup() -> TestFixtures {
         recipient_token_account,
         serialized_attestation_data,
         nonce,
+        subject,
     }
 }
 
@@ -182,6 +186,7 @@ async fn tokenize_schema_success() {
         recipient_token_account: _,
         nonce: _,
         serialized_attestation_data: _,
+        ..
     } = setup().await;
 
     let max_size = 100;
@@ -257,6 +262,7 @@ async fn create_tokenized_attestation_success() {
         recipient_token_account,
         nonce,
         serialized_attestation_data,
+        ..
     } = setup().await;
 
     let tokenize_schema_ix = TokenizeSchemaBuilder::new()
@@ -437,6 +443,7 @@ async fn close_tokenized_attestation_success() {
         recipient_token_account,
         nonce,
         serialized_attestation_data,
+        ..
     } = setup().await;
 
     let tokenize_schema_ix = TokenizeSchemaBuilder::new()
@@ -497,6 +504,7 @@ async fn close_tokenized_attestation_success() {
         .payer(ctx.payer.pubkey())
         .authority(authority.pubkey())
         .credential(credential)
+        .schema(schema)
         .attestation(attestation_pda)
         .event_authority(event_auth_pda)
         .system_program(system_program::ID)
@@ -564,6 +572,7 @@ async fn update_tokenized_attestation_success() {
         recipient_token_account,
         nonce,
         serialized_attestation_data,
+        ..
     } = setup().await;
 
     let tokenize_schema_ix = TokenizeSchemaBuilder::new()
@@ -624,6 +633,7 @@ async fn update_tokenized_attestation_success() {
         .payer(ctx.payer.pubkey())
         .authority(authority.pubkey())
         .credential(credential)
+        .schema(schema)
         .attestation(attestation_pda)
         .event_authority(event_auth_pda)
         .system_program(system_program::ID)
@@ -753,3 +763,133 @@ async fn update_tokenized_attestation_success() {
     assert_eq!(token_account.mint, attestation_mint_pda);
     assert_eq!(token_account.amount, 1);
 }
+
+#[tokio::test]
+async fn close_tokenized_attestation_by_subject_success() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        authority,
+        sas_pda,
+        schema_mint_pda,
+        attestation_pda,
+        attestation_mint_pda,
+        recipient,
+        recipient_token_account,
+        nonce,
+        serialized_attestation_data,
+        subject,
+    } = setup().await;
+
+    let tokenize_schema_ix = TokenizeSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .mint(schema_mint_pda)
+        .sas_pda(sas_pda)
+        .max_size(100)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .instruction();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let expiry: i64 = clock.unix_timestamp + 60;
+    let name = "Test Asset".to_string();
+    let uri = "https://x.com".to_string();
+    let symbol = "VAT".to_string();
+    let mint_account_space = 686;
+    let create_attestation_ix = CreateTokenizedAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .schema_mint(schema_mint_pda)
+        .attestation_mint(attestation_mint_pda)
+        .sas_pda(sas_pda)
+        .recipient_token_account(recipient_token_account)
+        .recipient(recipient)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .associated_token_program(ATA_PROGRAM_ID)
+        .data(serialized_attestation_data.clone())
+        .expiry(expiry)
+        .nonce(nonce)
+        .name(name.clone())
+        .uri(uri.clone())
+        .symbol(symbol.clone())
+        .mint_account_space(mint_account_space)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[tokenize_schema_ix, create_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let (event_auth_pda, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+
+    // Subject closes the Attestation about themselves.
+    let close_attestation_ix = CloseTokenizedAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(subject.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .attestation(attestation_pda)
+        .event_authority(event_auth_pda)
+        .system_program(system_program::ID)
+        .attestation_program(
+            solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID,
+        )
+        .attestation_mint(attestation_mint_pda)
+        .sas_pda(sas_pda)
+        .attestation_token_account(recipient_token_account)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[close_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &subject],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let recipient_token_account_data = ctx
+        .banks_client
+        .get_account(recipient_token_account)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Check that attestation account is closed.
+    let attestation_account = ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .expect("get_account");
+    assert!(attestation_account.is_none());
+
+    // Check that mint account is closed.
+    let mint_account = ctx
+        .banks_client
+        .get_account(attestation_mint_pda)
+        .await
+        .expect("get_account");
+    assert!(mint_account.is_none());
+
+    // Verify that recipient has 0 attestation token.
+    let token_account =
+        Account::unpack(&recipient_token_account_data.data[..Account::LEN]).unwrap();
+    assert_eq!(token_account.mint, attestation_mint_pda);
+    assert_eq!(token_account.amount, 0);
+}
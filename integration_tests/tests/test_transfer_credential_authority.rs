@@ -0,0 +1,208 @@
+use borsh::BorshDeserialize;
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::Credential,
+    instructions::{
+        AcceptCredentialAuthorityBuilder, ChangeAuthorizedSignersBuilder, CreateCredentialBuilder,
+        ProposeCredentialAuthorityBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    authority: Keypair,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            name.as_bytes(),
+        ],
+        &Pubkey::from(SOLANA_ATTESTATION_SERVICE_ID),
+    );
+
+    let ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(name.to_string())
+        .signers(vec![authority.pubkey()])
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        authority,
+    }
+}
+
+async fn send(
+    fixtures: &TestFixtures,
+    ix: Instruction,
+    signer: &Keypair,
+) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, signer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+async fn fetch_credential(fixtures: &TestFixtures) -> Credential {
+    let account = fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.credential)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    Credential::try_from_slice(&account.data).unwrap()
+}
+
+fn propose_ix(fixtures: &TestFixtures, new_authority: Pubkey) -> Instruction {
+    ProposeCredentialAuthorityBuilder::new()
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .new_authority(new_authority)
+        .instruction()
+}
+
+fn accept_ix(fixtures: &TestFixtures, new_authority: Pubkey) -> Instruction {
+    AcceptCredentialAuthorityBuilder::new()
+        .new_authority(new_authority)
+        .credential(fixtures.credential)
+        .instruction()
+}
+
+fn change_signers_ix(
+    fixtures: &TestFixtures,
+    authority: Pubkey,
+    signers: Vec<Pubkey>,
+) -> Instruction {
+    ChangeAuthorizedSignersBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(authority)
+        .credential(fixtures.credential)
+        .system_program(system_program::ID)
+        .signers(signers)
+        .instruction()
+}
+
+#[tokio::test]
+async fn transfer_credential_authority_success() {
+    let fixtures = setup().await;
+    let new_authority = Keypair::new();
+
+    let ix = propose_ix(&fixtures, new_authority.pubkey());
+    send(&fixtures, ix, &fixtures.authority).await.unwrap();
+
+    let credential = fetch_credential(&fixtures).await;
+    assert_eq!(credential.authority, fixtures.authority.pubkey());
+    assert_eq!(credential.pending_authority, new_authority.pubkey());
+
+    // Only the proposed key can accept.
+    let stranger = Keypair::new();
+    let ix = accept_ix(&fixtures, stranger.pubkey());
+    let err = send(&fixtures, ix, &stranger).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(28))
+    );
+
+    let ix = accept_ix(&fixtures, new_authority.pubkey());
+    send(&fixtures, ix, &new_authority).await.unwrap();
+
+    // The Credential keeps its address but answers to the new authority.
+    let credential = fetch_credential(&fixtures).await;
+    assert_eq!(credential.authority, new_authority.pubkey());
+    assert_eq!(credential.pending_authority, Pubkey::default());
+
+    let ix = change_signers_ix(&fixtures, fixtures.authority.pubkey(), vec![]);
+    let err = send(&fixtures, ix, &fixtures.authority).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+
+    let new_signers = vec![new_authority.pubkey()];
+    let ix = change_signers_ix(&fixtures, new_authority.pubkey(), new_signers.clone());
+    send(&fixtures, ix, &new_authority).await.unwrap();
+    let credential = fetch_credential(&fixtures).await;
+    assert_eq!(credential.authorized_signers, new_signers);
+}
+
+#[tokio::test]
+async fn transfer_credential_authority_fail_cancelled() {
+    let fixtures = setup().await;
+    let new_authority = Keypair::new();
+
+    let ix = propose_ix(&fixtures, new_authority.pubkey());
+    send(&fixtures, ix, &fixtures.authority).await.unwrap();
+
+    // Proposing the default key withdraws the proposal.
+    let ix = propose_ix(&fixtures, Pubkey::default());
+    send(&fixtures, ix, &fixtures.authority).await.unwrap();
+
+    let ix = accept_ix(&fixtures, new_authority.pubkey());
+    let err = send(&fixtures, ix, &new_authority).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(28))
+    );
+
+    let credential = fetch_credential(&fixtures).await;
+    assert_eq!(credential.authority, fixtures.authority.pubkey());
+}
+
+#[tokio::test]
+async fn propose_credential_authority_fail_not_authority() {
+    let fixtures = setup().await;
+    let impostor = Keypair::new();
+
+    let ix = ProposeCredentialAuthorityBuilder::new()
+        .authority(impostor.pubkey())
+        .credential(fixtures.credential)
+        .new_authority(impostor.pubkey())
+        .instruction();
+    let err = send(&fixtures, ix, &impostor).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
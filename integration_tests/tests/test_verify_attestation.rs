@@ -0,0 +1,263 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    instructions::{
+        ChangeAuthorizedSignersBuilder, ChangeSchemaStatusBuilder, CreateAttestationBuilder,
+        CreateCredentialBuilder, CreateSchemaBuilder, RevokeAttestationBuilder,
+        VerifyAttestationBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    types::{AttestationStatus, VerifyAttestationResult},
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    clock::Clock, instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    system_program, transaction::Transaction,
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    attestation: Pubkey,
+    nonce: Pubkey,
+    expiry: i64,
+    authority: Keypair,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .instruction();
+
+    // Create Schema
+    let schema_name = "test_data";
+    let description = "schema for test data";
+    let schema_data = TestData::get_serialized_representation();
+    let field_names = vec!["name".into(), "location".into()];
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description(description.to_string())
+        .name(schema_name.to_string())
+        .layout(schema_data)
+        .field_names(field_names)
+        .instruction();
+
+    // Create Attestation
+    let attestation_data = TestData {
+        name: "attest".to_string(),
+        location: 11,
+    };
+    let mut serialized_attestation_data = Vec::new();
+    attestation_data
+        .serialize(&mut serialized_attestation_data)
+        .unwrap();
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let expiry = clock.unix_timestamp + 3600;
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &credential_pda.to_bytes(),
+            &schema_pda.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_attestation_ix = CreateAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(serialized_attestation_data)
+        .expiry(expiry)
+        .nonce(nonce)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            create_credential_ix,
+            create_schema_ix,
+            create_attestation_ix,
+        ],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        attestation: attestation_pda,
+        nonce,
+        expiry,
+        authority,
+    }
+}
+
+async fn send(fixtures: &TestFixtures, ix: Instruction) {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+}
+
+/// Simulates VerifyAttestation and decodes its return data.
+async fn verify(fixtures: &TestFixtures) -> VerifyAttestationResult {
+    let ctx = &fixtures.ctx;
+    let verify_ix = VerifyAttestationBuilder::new()
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(fixtures.attestation)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[verify_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let simulate_res = ctx
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    simulate_res.result.unwrap().unwrap();
+    let return_data = simulate_res
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap();
+    assert_eq!(return_data.program_id, SOLANA_ATTESTATION_SERVICE_ID);
+    VerifyAttestationResult::try_from_slice(&return_data.data).unwrap()
+}
+
+#[tokio::test]
+async fn verify_attestation_valid() {
+    let fixtures = setup().await;
+
+    let result = verify(&fixtures).await;
+    assert!(result.is_valid());
+    assert_eq!(result.nonce, fixtures.nonce);
+    assert_eq!(result.signer, fixtures.authority.pubkey());
+    assert_eq!(result.expiry, fixtures.expiry);
+}
+
+#[tokio::test]
+async fn verify_attestation_revoked() {
+    let fixtures = setup().await;
+
+    let revoke_ix = RevokeAttestationBuilder::new()
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .attestation(fixtures.attestation)
+        .reason(0)
+        .instruction();
+    send(&fixtures, revoke_ix).await;
+
+    let result = verify(&fixtures).await;
+    assert_eq!(result.status(), Some(AttestationStatus::Revoked));
+}
+
+#[tokio::test]
+async fn verify_attestation_expired() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp = fixtures.expiry + 1;
+    ctx.set_sysvar(&clock);
+
+    let result = verify(&fixtures).await;
+    assert_eq!(result.status(), Some(AttestationStatus::Expired));
+}
+
+#[tokio::test]
+async fn verify_attestation_signer_not_authorized() {
+    let fixtures = setup().await;
+
+    // Remove the Attestation's signer from the Credential.
+    let change_signers_ix = ChangeAuthorizedSignersBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .system_program(system_program::ID)
+        .signers(vec![Pubkey::new_unique()])
+        .instruction();
+    send(&fixtures, change_signers_ix).await;
+
+    let result = verify(&fixtures).await;
+    assert_eq!(
+        result.status(),
+        Some(AttestationStatus::SignerNotAuthorized)
+    );
+}
+
+#[tokio::test]
+async fn verify_attestation_schema_paused() {
+    let fixtures = setup().await;
+
+    let pause_schema_ix = ChangeSchemaStatusBuilder::new()
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .is_paused(true)
+        .instruction();
+    send(&fixtures, pause_schema_ix).await;
+
+    let result = verify(&fixtures).await;
+    assert_eq!(result.status(), Some(AttestationStatus::SchemaPaused));
+}
@@ -0,0 +1,268 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::Schema,
+    instructions::{
+        CompareOp, CreateAttestationBuilder, CreateCredentialBuilder, CreateSchemaBuilder,
+        Predicate, RevokeAttestationBuilder, VerifyAttestationPredicateBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct KycData {
+    age: u8,
+    country: String,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    attestation: Pubkey,
+    authority: Keypair,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .instruction();
+
+    // Create Schema
+    let schema_name = "kyc";
+    let description = "schema for kyc data";
+    let schema_data = KycData::get_serialized_representation();
+    let field_names = vec!["age".into(), "country".into()];
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description(description.to_string())
+        .name(schema_name.to_string())
+        .layout(schema_data)
+        .field_names(field_names)
+        .instruction();
+
+    // Create Attestation
+    let attestation_data = KycData {
+        age: 21,
+        country: "US".to_string(),
+    };
+    let mut serialized_attestation_data = Vec::new();
+    attestation_data
+        .serialize(&mut serialized_attestation_data)
+        .unwrap();
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &credential_pda.to_bytes(),
+            &schema_pda.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_attestation_ix = CreateAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(serialized_attestation_data)
+        .expiry(0)
+        .nonce(nonce)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            create_credential_ix,
+            create_schema_ix,
+            create_attestation_ix,
+        ],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        attestation: attestation_pda,
+        authority,
+    }
+}
+
+async fn fetch_schema(fixtures: &TestFixtures) -> Schema {
+    let schema_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.schema)
+        .await
+        .unwrap()
+        .unwrap();
+    Schema::try_from_slice(&schema_account.data).unwrap()
+}
+
+/// Simulates VerifyAttestationPredicate, returning whether the predicate
+/// was satisfied.
+async fn verify(
+    fixtures: &TestFixtures,
+    predicate: &Predicate,
+    require_satisfied: bool,
+) -> Result<bool, TransactionError> {
+    let ctx = &fixtures.ctx;
+    let verify_ix = VerifyAttestationPredicateBuilder::new()
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(fixtures.attestation)
+        .predicate(predicate.to_bytes())
+        .require_satisfied(require_satisfied)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[verify_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let simulate_res = ctx
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    simulate_res.result.unwrap()?;
+    let return_data = simulate_res
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap();
+    assert_eq!(return_data.program_id, SOLANA_ATTESTATION_SERVICE_ID);
+    Ok(return_data.data == [1])
+}
+
+#[tokio::test]
+async fn verify_attestation_predicate_success() {
+    let fixtures = setup().await;
+    let schema = fetch_schema(&fixtures).await;
+    let age = schema.field_index("age").unwrap();
+    let country = schema.field_index("country").unwrap();
+
+    // age >= 18 and country not in [KP, IR]
+    let predicate = Predicate::And(vec![
+        Predicate::compare(age, CompareOp::Ge, &18u8),
+        Predicate::one_of(country, &["KP".to_string(), "IR".to_string()]).negate(),
+    ]);
+    assert!(verify(&fixtures, &predicate, true).await.unwrap());
+
+    let predicate = Predicate::compare(country, CompareOp::Eq, &"US".to_string());
+    assert!(verify(&fixtures, &predicate, false).await.unwrap());
+}
+
+#[tokio::test]
+async fn verify_attestation_predicate_not_satisfied() {
+    let fixtures = setup().await;
+    let schema = fetch_schema(&fixtures).await;
+    let age = schema.field_index("age").unwrap();
+
+    let predicate = Predicate::compare(age, CompareOp::Ge, &25u8);
+    assert!(!verify(&fixtures, &predicate, false).await.unwrap());
+
+    let err = verify(&fixtures, &predicate, true).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(22))
+    );
+}
+
+#[tokio::test]
+async fn verify_attestation_predicate_revoked_not_satisfied() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let revoke_ix = RevokeAttestationBuilder::new()
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .attestation(fixtures.attestation)
+        .reason(0)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[revoke_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let predicate = Predicate::compare(0, CompareOp::Ge, &18u8);
+    assert!(!verify(&fixtures, &predicate, false).await.unwrap());
+}
+
+#[tokio::test]
+async fn verify_attestation_predicate_fail_invalid_predicate() {
+    let fixtures = setup().await;
+
+    // Field index outside the layout.
+    let predicate = Predicate::compare(2, CompareOp::Eq, &18u8);
+    let err = verify(&fixtures, &predicate, false).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(23))
+    );
+
+    // Operand serialized as a different type than the field.
+    let predicate = Predicate::compare(1, CompareOp::Eq, &18u8);
+    let err = verify(&fixtures, &predicate, false).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(23))
+    );
+}
@@ -8,6 +8,11 @@ pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
 pub const SAS_SEED: &[u8] = b"sas";
 pub const SCHEMA_MINT_SEED: &[u8] = b"schemaMint";
 pub const ATTESTATION_MINT_SEED: &[u8] = b"attestationMint";
+pub const SIGNATURE_RECEIPT_SEED: &[u8] = b"signatureReceipt";
+pub const ATTESTATION_TREE_SEED: &[u8] = b"attestationTree";
+
+pub const ED25519_PROGRAM_ID: Pubkey =
+    pinocchio_pubkey::pubkey!("Ed25519SigVerify111111111111111111111111111");
 
 // Anchor Compatitable Discriminator: Sha256(anchor:event)[..8]
 pub const EVENT_IX_TAG: u64 = 0x1d9acb512ea545e4;
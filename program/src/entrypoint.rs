@@ -23,11 +23,31 @@ pub fn process_instruction(
         3 => process_change_authorized_signers(program_id, accounts, instruction_data),
         4 => process_change_schema_description(program_id, accounts, instruction_data),
         5 => process_change_schema_version(program_id, accounts, instruction_data),
-        6 => process_create_attestation(program_id, accounts, instruction_data, None),
-        7 => process_close_attestation(program_id, accounts, None),
+        6 => process_create_attestation(program_id, accounts, instruction_data),
+        7 => process_close_attestation(program_id, accounts),
         9 => process_tokenize_schema(program_id, accounts, instruction_data),
         10 => process_create_tokenized_attestation(program_id, accounts, instruction_data),
         11 => process_close_tokenized_attestation(program_id, accounts),
+        12 => process_revoke_attestation(program_id, accounts, instruction_data),
+        13 => process_update_attestation(program_id, accounts, instruction_data),
+        14 => process_close_expired_attestation(program_id, accounts),
+        15 => process_renew_attestation(program_id, accounts, instruction_data),
+        16 => process_create_attestations_batch(program_id, accounts, instruction_data),
+        17 => process_close_attestations_batch(program_id, accounts),
+        18 => process_create_attestation_with_signature(program_id, accounts, instruction_data),
+        19 => process_create_attestation_tree(program_id, accounts, instruction_data),
+        20 => process_create_compressed_attestation(program_id, accounts, instruction_data),
+        21 => process_revoke_compressed_attestation(program_id, accounts, instruction_data),
+        22 => process_verify_attestation(program_id, accounts),
+        23 => process_verify_attestation_predicate(program_id, accounts, instruction_data),
+        24 => process_change_credential_admins(program_id, accounts, instruction_data),
+        25 => process_propose_credential_authority(program_id, accounts, instruction_data),
+        26 => process_accept_credential_authority(program_id, accounts),
+        27 => process_close_schema(program_id, accounts),
+        28 => process_close_credential(program_id, accounts),
+        29 => process_change_signer_permissions(program_id, accounts, instruction_data),
+        30 => process_migrate_account(program_id, accounts),
+        31 => process_close_signature_receipt(program_id, accounts),
         228 => process_emit_event(program_id, accounts), // matches EVENT_IX_TAG[0]
         _ => Err(ProgramError::InvalidInstructionData),
     }
@@ -27,6 +27,56 @@ pub enum AttestationServiceError {
     InvalidTokenAccount,
     // 11 Schema is paused
     SchemaPaused,
+    // 12 Attestation has been revoked
+    AttestationRevoked,
+    // 13 Attestation has not expired
+    AttestationNotExpired,
+    // 14 Incorrect rent payer account
+    InvalidPayer,
+    // 15 Attestation nonce has not signed as the subject
+    MissingSubjectSignature,
+    // 16 Ed25519 instruction is missing or does not match the expected message
+    InvalidSignatureInstruction,
+    // 17 Signed message deadline has passed
+    SignatureExpired,
+    // 18 Signed message has already been used
+    SignatureAlreadyUsed,
+    // 19 Referenced Attestation is invalid, expired or revoked
+    InvalidReference,
+    // 20 Attestation tree has no room for more leaves
+    TreeFull,
+    // 21 Merkle proof does not match the Attestation tree
+    InvalidProof,
+    // 22 Attestation does not satisfy the predicate
+    PredicateNotSatisfied,
+    // 23 Predicate is malformed or does not match the Schema layout
+    InvalidPredicate,
+    // 24 Field constraint is malformed or does not apply to the field's type
+    InvalidFieldConstraint,
+    // 25 Attestation data violates a field constraint of the Schema
+    FieldConstraintViolated,
+    // 26 Not enough Credential admins signed the instruction
+    InsufficientAdminSignatures,
+    // 27 Credential admin threshold or key set is invalid
+    InvalidAdminConfig,
+    // 28 Signer is not the Credential's pending authority
+    InvalidPendingAuthority,
+    // 29 Schema still has open Attestations
+    SchemaHasAttestations,
+    // 30 Credential still has open Schemas
+    CredentialHasSchemas,
+    // 31 Signer permission entries are duplicated or grant no valid capability
+    InvalidSignerPermissions,
+    // 32 Schema is tokenized and its mint cannot be closed
+    SchemaTokenized,
+    // 33 Account uses an older layout and must be migrated with MigrateAccount
+    AccountNotMigrated,
+    // 34 Attestation data is too large to revoke from the Attestation tree
+    CompressedAttestationTooLarge,
+    // 35 Schema requires subject signatures, which batch creation cannot carry
+    SubjectSignerNotSupported,
+    // 36 Signed message deadline has not passed, its receipt is still needed
+    SignatureNotExpired,
 }
 
 impl From<AttestationServiceError> for ProgramError {
@@ -4,11 +4,18 @@ use alloc::vec::Vec;
 use pinocchio::pubkey::Pubkey;
 use shank::ShankType;
 
-use crate::constants::EVENT_IX_TAG_LE;
+use crate::{constants::EVENT_IX_TAG_LE, state::CompressedAttestation};
 
 #[repr(u8)]
 pub enum EventDiscriminators {
     CloseEvent = 0,
+    UpdateEvent = 1,
+    RenewEvent = 2,
+    SubjectCloseEvent = 3,
+    CloseBatchEvent = 4,
+    CompressedEvent = 5,
+    SchemaCloseEvent = 6,
+    CredentialCloseEvent = 7,
 }
 
 #[derive(ShankType)]
@@ -34,3 +41,200 @@ impl CloseAttestationEvent {
         data
     }
 }
+
+#[derive(ShankType)]
+pub struct UpdateAttestationEvent {
+    /// Unique u8 byte for event type.
+    pub discriminator: u8,
+    /// Reference to the Schema this Attestation adheres to
+    pub schema: Pubkey,
+    /// The Attestation that was updated
+    pub attestation: Pubkey,
+    /// Data of the Attestation before the update
+    pub old_data: Vec<u8>,
+    /// Data of the Attestation after the update
+    pub new_data: Vec<u8>,
+}
+
+impl UpdateAttestationEvent {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        // Prepend IX Discriminator for emit_event.
+        data.extend_from_slice(EVENT_IX_TAG_LE);
+        data.push(self.discriminator);
+        data.extend_from_slice(self.schema.as_ref());
+        data.extend_from_slice(self.attestation.as_ref());
+        data.extend_from_slice(&(self.old_data.len() as u32).to_le_bytes());
+        data.extend_from_slice(&self.old_data);
+        data.extend_from_slice(&(self.new_data.len() as u32).to_le_bytes());
+        data.extend_from_slice(&self.new_data);
+
+        data
+    }
+}
+
+#[derive(ShankType)]
+pub struct RenewAttestationEvent {
+    /// Unique u8 byte for event type.
+    pub discriminator: u8,
+    /// Reference to the Schema this Attestation adheres to
+    pub schema: Pubkey,
+    /// The Attestation that was renewed
+    pub attestation: Pubkey,
+    /// Expiry of the Attestation before the renewal
+    pub old_expiry: i64,
+    /// Expiry of the Attestation after the renewal. 0 means never expired
+    pub new_expiry: i64,
+}
+
+impl RenewAttestationEvent {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        // Prepend IX Discriminator for emit_event.
+        data.extend_from_slice(EVENT_IX_TAG_LE);
+        data.push(self.discriminator);
+        data.extend_from_slice(self.schema.as_ref());
+        data.extend_from_slice(self.attestation.as_ref());
+        data.extend_from_slice(&self.old_expiry.to_le_bytes());
+        data.extend_from_slice(&self.new_expiry.to_le_bytes());
+
+        data
+    }
+}
+
+#[derive(ShankType)]
+pub struct SubjectCloseAttestationEvent {
+    /// Unique u8 byte for event type.
+    pub discriminator: u8,
+    /// Reference to the Schema this Attestation adheres to
+    pub schema: Pubkey,
+    /// The subject (Attestation nonce) that closed the Attestation
+    pub subject: Pubkey,
+    /// Time the Attestation was revoked, 0 if it was not
+    pub revoked_at: i64,
+    /// Data that was verified and matches the Schema
+    pub attestation_data: Vec<u8>,
+}
+
+impl SubjectCloseAttestationEvent {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        // Prepend IX Discriminator for emit_event.
+        data.extend_from_slice(EVENT_IX_TAG_LE);
+        data.push(self.discriminator);
+        data.extend_from_slice(self.schema.as_ref());
+        data.extend_from_slice(self.subject.as_ref());
+        data.extend_from_slice(&self.revoked_at.to_le_bytes());
+        data.extend_from_slice(&(self.attestation_data.len() as u32).to_le_bytes());
+        data.extend_from_slice(&self.attestation_data);
+
+        data
+    }
+}
+
+#[derive(ShankType)]
+pub struct ClosedAttestation {
+    /// Reference to the Schema the Attestation adhered to
+    pub schema: Pubkey,
+    /// The Attestation that was closed
+    pub attestation: Pubkey,
+}
+
+#[derive(ShankType)]
+pub struct CloseAttestationsBatchEvent {
+    /// Unique u8 byte for event type.
+    pub discriminator: u8,
+    /// Every Attestation closed by the instruction, in account order
+    pub closed: Vec<ClosedAttestation>,
+}
+
+impl CloseAttestationsBatchEvent {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        // Prepend IX Discriminator for emit_event.
+        data.extend_from_slice(EVENT_IX_TAG_LE);
+        data.push(self.discriminator);
+        data.extend_from_slice(&(self.closed.len() as u32).to_le_bytes());
+        for closed in &self.closed {
+            data.extend_from_slice(closed.schema.as_ref());
+            data.extend_from_slice(closed.attestation.as_ref());
+        }
+
+        data
+    }
+}
+
+#[derive(ShankType)]
+pub struct CompressedAttestationEvent {
+    /// Unique u8 byte for event type.
+    pub discriminator: u8,
+    /// The AttestationTree holding the leaf
+    pub tree: Pubkey,
+    /// Index of the leaf that was appended or replaced
+    pub leaf_index: u32,
+    /// Sequence number of the tree after the change
+    pub sequence: u64,
+    /// Full leaf data, hashed into the tree
+    pub attestation: CompressedAttestation,
+}
+
+impl CompressedAttestationEvent {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        // Prepend IX Discriminator for emit_event.
+        data.extend_from_slice(EVENT_IX_TAG_LE);
+        data.push(self.discriminator);
+        data.extend_from_slice(self.tree.as_ref());
+        data.extend_from_slice(&self.leaf_index.to_le_bytes());
+        data.extend_from_slice(&self.sequence.to_le_bytes());
+        data.extend_from_slice(&self.attestation.to_bytes());
+
+        data
+    }
+}
+
+#[derive(ShankType)]
+pub struct CloseSchemaEvent {
+    /// Unique u8 byte for event type.
+    pub discriminator: u8,
+    /// The Credential the Schema belonged to
+    pub credential: Pubkey,
+    /// The Schema that was closed
+    pub schema: Pubkey,
+}
+
+impl CloseSchemaEvent {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        // Prepend IX Discriminator for emit_event.
+        data.extend_from_slice(EVENT_IX_TAG_LE);
+        data.push(self.discriminator);
+        data.extend_from_slice(self.credential.as_ref());
+        data.extend_from_slice(self.schema.as_ref());
+
+        data
+    }
+}
+
+#[derive(ShankType)]
+pub struct CloseCredentialEvent {
+    /// Unique u8 byte for event type.
+    pub discriminator: u8,
+    /// The Credential that was closed
+    pub credential: Pubkey,
+    /// Authority of the Credential when it was closed
+    pub authority: Pubkey,
+}
+
+impl CloseCredentialEvent {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        // Prepend IX Discriminator for emit_event.
+        data.extend_from_slice(EVENT_IX_TAG_LE);
+        data.push(self.discriminator);
+        data.extend_from_slice(self.credential.as_ref());
+        data.extend_from_slice(self.authority.as_ref());
+
+        data
+    }
+}
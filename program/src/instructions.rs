@@ -3,7 +3,17 @@ extern crate alloc;
 use alloc::string::String;
 use alloc::vec::Vec;
 use pinocchio::pubkey::Pubkey;
-use shank::ShankInstruction;
+use shank::{ShankInstruction, ShankType};
+
+use crate::state::{CompressedAttestation, SignerPermission};
+
+/// A single Attestation to create in a CreateAttestationsBatch instruction.
+#[derive(Clone, Debug, PartialEq, ShankType)]
+pub struct CreateAttestationEntry {
+    pub nonce: Pubkey,
+    pub data: Vec<u8>,
+    pub expiry: i64,
+}
 
 /// Instructions for the Solana Attestation Service. This
 /// is currently not used in the program business logic, but
@@ -19,10 +29,12 @@ pub enum AttestationServiceInstruction {
     CreateCredential { name: String, signers: Vec<Pubkey> },
 
     /// Create a Schema for a Credential that can eventually be attested to.
+    /// Whether Attestation subjects must co-sign is fixed at creation.
     #[account(0, writable, signer, name = "payer")]
     #[account(1, signer, name = "authority")]
     #[account(
         2,
+        writable,
         name = "credential",
         desc = "Credential the Schema is associated with"
     )]
@@ -33,6 +45,9 @@ pub enum AttestationServiceInstruction {
         description: String,
         layout: Vec<u8>,
         field_names: Vec<String>,
+        constraints: Option<Vec<u8>>,
+        enum_variants: Option<Vec<String>>,
+        requires_subject_signer: Option<bool>,
     },
 
     /// Sets Schema is_paused status
@@ -84,6 +99,7 @@ pub enum AttestationServiceInstruction {
     #[account(1, signer, name = "authority")]
     #[account(
         2,
+        writable,
         name = "credential",
         desc = "Credential the Schema is associated with"
     )]
@@ -93,9 +109,14 @@ pub enum AttestationServiceInstruction {
     ChangeSchemaVersion {
         layout: Vec<u8>,
         field_names: Vec<String>,
+        constraints: Option<Vec<u8>>,
+        enum_variants: Option<Vec<String>>,
     },
 
     /// Create an Attestation for a Schema by an authorized signer.
+    /// If the Schema requires a subject signer, the nonce must sign and be
+    /// passed as an additional account. Referenced Attestations must also be
+    /// passed as additional accounts.
     #[account(0, writable, signer, name = "payer")]
     #[account(
         1,
@@ -108,28 +129,47 @@ pub enum AttestationServiceInstruction {
         name = "credential",
         desc = "Credential the Schema is associated with"
     )]
-    #[account(3, name = "schema", desc = "Schema the Attestation is associated with")]
+    #[account(
+        3,
+        writable,
+        name = "schema",
+        desc = "Schema the Attestation is associated with"
+    )]
     #[account(4, writable, name = "attestation")]
     #[account(5, name = "system_program")]
     CreateAttestation {
         nonce: Pubkey,
         data: Vec<u8>,
         expiry: i64,
+        references: Option<Vec<Pubkey>>,
     },
 
-    /// Close an Attestation account.
-    #[account(0, writable, signer, name = "payer")]
+    /// Close an Attestation account. May be signed by the Attestation's subject
+    /// (nonce) instead of an authorized signer. Credential admin co-signers
+    /// may follow the listed accounts.
+    #[account(
+        0,
+        writable,
+        name = "payer",
+        desc = "Receives the rent. Must be the original payer unless the Credential admins approve"
+    )]
     #[account(
         1,
         signer,
         name = "authority",
-        desc = "Authorized signer of the Schema's Credential"
+        desc = "Authorized signer of the Schema's Credential or the Attestation's subject"
     )]
     #[account(2, name = "credential")]
     #[account(3, writable, name = "attestation")]
     #[account(4, name = "event_authority")]
     #[account(5, name = "system_program")]
     #[account(6, name = "attestation_program")]
+    #[account(
+        7,
+        writable,
+        name = "schema",
+        desc = "Schema the Attestation is associated with"
+    )]
     CloseAttestation {},
 
     /// Invoked via CPI from SAS Program to log event via instruction data.
@@ -169,7 +209,12 @@ pub enum AttestationServiceInstruction {
         name = "credential",
         desc = "Credential the Schema is associated with"
     )]
-    #[account(3, name = "schema", desc = "Schema the Attestation is associated with")]
+    #[account(
+        3,
+        writable,
+        name = "schema",
+        desc = "Schema the Attestation is associated with"
+    )]
     #[account(4, writable, name = "attestation")]
     #[account(5, name = "system_program")]
     #[account(6, writable, name = "schema_mint", desc = "Mint of Schema Token")]
@@ -201,15 +246,23 @@ pub enum AttestationServiceInstruction {
         uri: String,
         symbol: String,
         mint_account_space: u16,
+        references: Option<Vec<Pubkey>>,
     },
 
-    /// Close an Attestation and Attestation token.
-    #[account(0, writable, signer, name = "payer")]
+    /// Close an Attestation and Attestation token. May be signed by the
+    /// Attestation's subject (nonce) instead of an authorized signer.
+    /// Credential admin co-signers may follow the listed accounts.
+    #[account(
+        0,
+        writable,
+        name = "payer",
+        desc = "Receives the rent. Must be the original payer unless the Credential admins approve"
+    )]
     #[account(
         1,
         signer,
         name = "authority",
-        desc = "Authorized signer of the Schema's Credential"
+        desc = "Authorized signer of the Schema's Credential or the Attestation's subject"
     )]
     #[account(2, name = "credential")]
     #[account(3, writable, name = "attestation")]
@@ -234,5 +287,367 @@ pub enum AttestationServiceInstruction {
         desc = "Associated token account of the related Attestation Token"
     )]
     #[account(10, name = "token_program")]
+    #[account(
+        11,
+        writable,
+        name = "schema",
+        desc = "Schema the Attestation is associated with"
+    )]
     CloseTokenizedAttestation {},
+
+    /// Revoke an Attestation while keeping the account on chain.
+    #[account(
+        0,
+        signer,
+        name = "authority",
+        desc = "Authorized signer of the Schema's Credential"
+    )]
+    #[account(1, name = "credential")]
+    #[account(2, writable, name = "attestation")]
+    RevokeAttestation { reason: u8 },
+
+    /// Update the data of an Attestation in place.
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Funds any growth. Must be the original payer if the Attestation shrinks"
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Authorized signer of the Schema's Credential"
+    )]
+    #[account(
+        2,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(3, name = "schema", desc = "Schema the Attestation is associated with")]
+    #[account(4, writable, name = "attestation")]
+    #[account(5, name = "event_authority")]
+    #[account(6, name = "system_program")]
+    #[account(7, name = "attestation_program")]
+    UpdateAttestation { data: Vec<u8> },
+
+    /// Permissionlessly close an Attestation whose expiry has passed.
+    #[account(
+        0,
+        writable,
+        name = "payer",
+        desc = "Original payer of the Attestation, or the Credential authority if none is stored, receives the rent"
+    )]
+    #[account(1, writable, name = "attestation")]
+    #[account(2, name = "event_authority")]
+    #[account(3, name = "attestation_program")]
+    #[account(
+        4,
+        writable,
+        name = "schema",
+        desc = "Schema the Attestation is associated with"
+    )]
+    #[account(
+        5,
+        name = "credential",
+        desc = "Credential the Attestation is associated with"
+    )]
+    CloseExpiredAttestation {},
+
+    /// Set a new expiry on an Attestation.
+    #[account(
+        0,
+        signer,
+        name = "authority",
+        desc = "Authorized signer of the Schema's Credential"
+    )]
+    #[account(1, name = "credential")]
+    #[account(2, writable, name = "attestation")]
+    #[account(3, name = "event_authority")]
+    #[account(4, name = "attestation_program")]
+    RenewAttestation { expiry: i64 },
+
+    /// Create many Attestations for one Schema by an authorized signer. One
+    /// writable Attestation account per entry, in order, follows the listed
+    /// accounts. Schemas requiring subject signers are not supported.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Authorized signer of the Schema's Credential"
+    )]
+    #[account(
+        2,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "schema",
+        desc = "Schema the Attestations are associated with"
+    )]
+    #[account(4, name = "system_program")]
+    CreateAttestationsBatch {
+        entries: Vec<CreateAttestationEntry>,
+    },
+
+    /// Close many Attestations of one Credential and emit a single event.
+    /// The writable Attestation accounts, the writable Schema of each and any
+    /// Credential admin co-signers follow the listed accounts in any order.
+    #[account(
+        0,
+        writable,
+        name = "payer",
+        desc = "Receives the rent. Must be the original payer of every Attestation unless the Credential admins approve"
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Authorized signer of the Schema's Credential"
+    )]
+    #[account(2, name = "credential")]
+    #[account(3, name = "event_authority")]
+    #[account(4, name = "system_program")]
+    #[account(5, name = "attestation_program")]
+    CloseAttestationsBatch {},
+
+    /// Create an Attestation from a message signed off-chain by an authorized
+    /// signer. Must be preceded by an Ed25519 program instruction verifying
+    /// the signature over `prefix || credential || schema || nonce ||
+    /// sha256(data) || expiry || deadline`.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(
+        1,
+        name = "authority",
+        desc = "Authorized signer of the Schema's Credential that signed the message"
+    )]
+    #[account(
+        2,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "schema",
+        desc = "Schema the Attestation is associated with"
+    )]
+    #[account(4, writable, name = "attestation")]
+    #[account(
+        5,
+        writable,
+        name = "signature_receipt",
+        desc = "PDA marking the signed message as used"
+    )]
+    #[account(6, name = "instructions_sysvar")]
+    #[account(7, name = "system_program")]
+    CreateAttestationWithSignature {
+        nonce: Pubkey,
+        data: Vec<u8>,
+        expiry: i64,
+        deadline: i64,
+    },
+
+    /// Bind a concurrent Merkle tree of compressed Attestations to a Schema.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Authority of the Schema's Credential"
+    )]
+    #[account(
+        2,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(3, name = "schema", desc = "Schema the AttestationTree is bound to")]
+    #[account(4, writable, name = "attestation_tree")]
+    #[account(5, name = "system_program")]
+    CreateAttestationTree { max_depth: u8, max_buffer_size: u8 },
+
+    /// Append a compressed Attestation to the Schema's AttestationTree. The
+    /// full leaf is emitted in a CompressedAttestationEvent.
+    #[account(
+        0,
+        signer,
+        name = "authority",
+        desc = "Authorized signer of the Schema's Credential"
+    )]
+    #[account(
+        1,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(2, name = "schema", desc = "Schema the Attestation is associated with")]
+    #[account(
+        3,
+        writable,
+        name = "attestation_tree",
+        desc = "AttestationTree bound to the Schema"
+    )]
+    #[account(4, name = "event_authority")]
+    #[account(5, name = "attestation_program")]
+    CreateCompressedAttestation {
+        nonce: Pubkey,
+        data: Vec<u8>,
+        expiry: i64,
+    },
+
+    /// Revoke a compressed Attestation by replacing its leaf. Proof nodes,
+    /// from the leaf's sibling up, follow the listed accounts.
+    #[account(
+        0,
+        signer,
+        name = "authority",
+        desc = "Authorized signer of the Schema's Credential"
+    )]
+    #[account(
+        1,
+        name = "credential",
+        desc = "Credential the Attestation is associated with"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "attestation_tree",
+        desc = "AttestationTree holding the Attestation"
+    )]
+    #[account(3, name = "event_authority")]
+    #[account(4, name = "attestation_program")]
+    RevokeCompressedAttestation {
+        root: [u8; 32],
+        leaf_index: u32,
+        attestation: CompressedAttestation,
+        reason: u8,
+    },
+
+    /// Verify an Attestation for CPI callers. Returns a VerifyAttestationResult
+    /// through return data.
+    #[account(0, name = "credential")]
+    #[account(1, name = "schema")]
+    #[account(2, name = "attestation")]
+    VerifyAttestation {},
+
+    /// Evaluate a predicate over the Attestation's data fields for CPI
+    /// callers. Returns a single byte through return data, 1 when the
+    /// Attestation is valid and satisfies the predicate.
+    #[account(0, name = "credential")]
+    #[account(1, name = "schema")]
+    #[account(2, name = "attestation")]
+    VerifyAttestationPredicate {
+        predicate: Vec<u8>,
+        require_satisfied: bool,
+    },
+
+    /// Sets the Credential's admin keys and threshold. Once a threshold is
+    /// set, admin instructions require that many admins to sign, with any
+    /// co-signers passed after the listed accounts. A zero threshold and no
+    /// admins restores single authority administration.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(1, signer, name = "authority")]
+    #[account(2, writable, name = "credential")]
+    #[account(3, name = "system_program")]
+    ChangeCredentialAdmins {
+        admin_threshold: u8,
+        admins: Vec<Pubkey>,
+    },
+
+    /// Propose a new authority for a Credential, which takes over once it
+    /// signs AcceptCredentialAuthority. Proposing the default key cancels a
+    /// pending proposal.
+    #[account(0, signer, name = "authority")]
+    #[account(1, writable, name = "credential")]
+    ProposeCredentialAuthority { new_authority: Pubkey },
+
+    /// Accept a pending authority proposal. The Credential PDA remains
+    /// derived from the original authority.
+    #[account(
+        0,
+        signer,
+        name = "new_authority",
+        desc = "Pending authority of the Credential"
+    )]
+    #[account(1, writable, name = "credential")]
+    AcceptCredentialAuthority {},
+
+    /// Close a Schema that has no open Attestations, no AttestationTree and
+    /// was never tokenized.
+    #[account(
+        0,
+        writable,
+        name = "payer",
+        desc = "Receives the rent. Must be the original payer unless the Credential admins approve"
+    )]
+    #[account(1, signer, name = "authority")]
+    #[account(
+        2,
+        writable,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(3, writable, name = "schema")]
+    #[account(
+        4,
+        name = "attestation_tree",
+        desc = "AttestationTree PDA of the Schema, which must not exist"
+    )]
+    #[account(
+        5,
+        name = "schema_mint",
+        desc = "Mint PDA of the Schema Token, which must not exist"
+    )]
+    #[account(6, name = "event_authority")]
+    #[account(7, name = "attestation_program")]
+    CloseSchema {},
+
+    /// Close a Credential once all of its Schemas are closed.
+    #[account(
+        0,
+        writable,
+        name = "payer",
+        desc = "Receives the rent. Must be the original payer unless the Credential admins approve"
+    )]
+    #[account(1, signer, name = "authority")]
+    #[account(2, writable, name = "credential")]
+    #[account(3, name = "event_authority")]
+    #[account(4, name = "attestation_program")]
+    CloseCredential {},
+
+    /// Sets the Credential's signer permissions. Each entry limits an
+    /// authorized signer to the capabilities it grants, optionally under a
+    /// subset of Schemas.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(1, signer, name = "authority")]
+    #[account(2, writable, name = "credential")]
+    #[account(3, name = "system_program")]
+    ChangeSignerPermissions { permissions: Vec<SignerPermission> },
+
+    /// Rewrite a Credential, Schema or Attestation created with an older
+    /// layout in the current one. Fields it lacked take their defaults.
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Funds the rent for the added fields"
+    )]
+    #[account(1, writable, name = "account")]
+    #[account(2, name = "system_program")]
+    MigrateAccount {},
+
+    /// Close a signature receipt once its message's deadline has passed.
+    /// Permissionless, rent is returned to the account that funded it.
+    #[account(
+        0,
+        writable,
+        name = "payer",
+        desc = "Original payer of the signature receipt, receives the rent"
+    )]
+    #[account(1, writable, name = "signature_receipt")]
+    CloseSignatureReceipt {},
 }
@@ -0,0 +1,43 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{
+    error::AttestationServiceError,
+    processor::{verify_owner_mutability, verify_signer, write_account_data},
+    state::{discriminator::AccountSerialize, Credential},
+};
+
+#[inline(always)]
+pub fn process_accept_credential_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [new_authority_info, credential_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: new authority should have signed
+    verify_signer(new_authority_info, false)?;
+
+    // Verify program ownership, mutability and PDAs.
+    verify_owner_mutability(credential_info, program_id, true)?;
+
+    let credential_data = credential_info.try_borrow_data()?;
+    let mut credential = Credential::try_from_bytes(&credential_data)?;
+    drop(credential_data); // Drop immutable borrow.
+
+    // Verify the signer is the proposed authority.
+    if credential.pending_authority.eq(&Pubkey::default())
+        || credential.pending_authority.ne(new_authority_info.key())
+    {
+        return Err(AttestationServiceError::InvalidPendingAuthority.into());
+    }
+
+    // The PDA stays seeded by the original authority.
+    credential.authority = credential.pending_authority;
+    credential.pending_authority = Pubkey::default();
+    write_account_data(credential_info, None, &credential.to_bytes())?;
+
+    Ok(())
+}
@@ -11,7 +11,10 @@ use pinocchio::{
 use pinocchio_system::instructions::Transfer;
 
 use crate::{
-    processor::{verify_owner_mutability, verify_signer, verify_system_program},
+    processor::{
+        verify_credential_admin, verify_owner_mutability, verify_signer, verify_system_program,
+        write_account_data,
+    },
     require_len,
     state::{discriminator::AccountSerialize, Credential},
 };
@@ -23,7 +26,8 @@ pub fn process_change_authorized_signers(
     instruction_data: &[u8],
 ) -> ProgramResult {
     let args = process_instruction_data(instruction_data)?;
-    let [payer_info, authority_info, credential_info, system_program] = accounts else {
+    let [payer_info, authority_info, credential_info, system_program, co_signers @ ..] = accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -38,10 +42,8 @@ pub fn process_change_authorized_signers(
     let mut credential = Credential::try_from_bytes(&data)?;
     drop(data); // Drop immutable borrow.
 
-    // Verify that signer matches credential authority.
-    if credential.authority.ne(authority_info.key()) {
-        return Err(ProgramError::IncorrectAuthority);
-    }
+    // Verify the Credential's admins approved the change.
+    verify_credential_admin(&credential, authority_info, co_signers)?;
 
     // Resize account if needed.
     let prev_space = credential_info.data_len();
@@ -77,8 +79,7 @@ pub fn process_change_authorized_signers(
     credential.authorized_signers = args.signers;
 
     // Write updated data.
-    let mut credential_data = credential_info.try_borrow_mut_data()?;
-    credential_data.copy_from_slice(&credential.to_bytes());
+    write_account_data(credential_info, Some(payer_info), &credential.to_bytes())?;
 
     Ok(())
 }
@@ -0,0 +1,82 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{
+    processor::{
+        verify_credential_admin, verify_owner_mutability, verify_signer, verify_system_program,
+        write_account_data,
+    },
+    require_len,
+    state::{discriminator::AccountSerialize, Credential},
+};
+
+#[inline(always)]
+pub fn process_change_credential_admins(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [payer_info, authority_info, credential_info, system_program, co_signers @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authority_info, false)?;
+    // Validate: system program
+    verify_system_program(system_program)?;
+    // Verify program ownership, mutability and PDAs.
+    verify_owner_mutability(credential_info, program_id, true)?;
+
+    let data = credential_info.try_borrow_data()?;
+    let mut credential = Credential::try_from_bytes(&data)?;
+    drop(data); // Drop immutable borrow.
+
+    // Verify the current admins approved the new configuration.
+    verify_credential_admin(&credential, authority_info, co_signers)?;
+    Credential::validate_admins(args.admin_threshold, &args.admins)?;
+
+    // Update admins on struct.
+    credential.admin_threshold = args.admin_threshold;
+    credential.admins = args.admins;
+
+    // Write updated data.
+    write_account_data(credential_info, Some(payer_info), &credential.to_bytes())?;
+
+    Ok(())
+}
+
+struct ChangeCredentialAdminsArgs {
+    admin_threshold: u8,
+    admins: Vec<Pubkey>,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<ChangeCredentialAdminsArgs, ProgramError> {
+    let mut offset: usize = 0;
+
+    require_len!(data, 1);
+    let admin_threshold = data[offset];
+    offset += 1;
+
+    require_len!(data, offset + 4);
+    let admins_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+    offset += 4;
+
+    require_len!(data, offset + admins_len * 32);
+    let mut admins = Vec::with_capacity(admins_len);
+    for _ in 0..admins_len {
+        let admin: Pubkey = data[offset..offset + 32].try_into().unwrap();
+        admins.push(admin);
+        offset += 32;
+    }
+
+    Ok(ChangeCredentialAdminsArgs {
+        admin_threshold,
+        admins,
+    })
+}
@@ -12,7 +12,10 @@ use pinocchio_system::instructions::Transfer;
 
 use crate::{
     error::AttestationServiceError,
-    processor::{verify_owner_mutability, verify_signer, verify_system_program},
+    processor::{
+        verify_credential_admin, verify_owner_mutability, verify_signer, verify_system_program,
+        write_account_data,
+    },
     require_len,
     state::{discriminator::AccountSerialize, Credential, Schema},
 };
@@ -24,7 +27,8 @@ pub fn process_change_schema_description(
     instruction_data: &[u8],
 ) -> ProgramResult {
     let args = process_instruction_data(instruction_data)?;
-    let [payer_info, authority_info, credential_info, schema_info, system_program] = accounts
+    let [payer_info, authority_info, credential_info, schema_info, system_program, co_signers @ ..] =
+        accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -39,10 +43,8 @@ pub fn process_change_schema_description(
 
     let credential = &Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
 
-    // Verify signer matches credential authority.
-    if credential.authority.ne(authority_info.key()) {
-        return Err(ProgramError::IncorrectAuthority);
-    }
+    // Verify the Credential's admins approved the change.
+    verify_credential_admin(credential, authority_info, co_signers)?;
 
     let schema_data = schema_info.try_borrow_data()?;
     let mut schema = Schema::try_from_bytes(&schema_data)?;
@@ -83,8 +85,7 @@ pub fn process_change_schema_description(
     }
 
     // Write updated data.
-    let mut schema_data = schema_info.try_borrow_mut_data()?;
-    schema_data.copy_from_slice(&schema.to_bytes());
+    write_account_data(schema_info, Some(payer_info), &schema.to_bytes())?;
 
     Ok(())
 }
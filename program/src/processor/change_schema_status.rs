@@ -5,7 +5,9 @@ use pinocchio_log::log;
 
 use crate::{
     error::AttestationServiceError,
-    processor::{verify_owner_mutability, verify_signer},
+    processor::{
+        verify_credential_admin, verify_owner_mutability, verify_signer, write_account_data,
+    },
     require_len,
     state::{discriminator::AccountSerialize, Credential, Schema},
 };
@@ -17,7 +19,7 @@ pub fn process_change_schema_status(
     instruction_data: &[u8],
 ) -> ProgramResult {
     let args = process_instruction_data(instruction_data)?;
-    let [authority_info, credential_info, schema_info] = accounts else {
+    let [authority_info, credential_info, schema_info, co_signers @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -30,13 +32,12 @@ pub fn process_change_schema_status(
 
     let credential = &Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
 
-    // Verify signer matches credential authority.
-    if credential.authority.ne(authority_info.key()) {
-        return Err(ProgramError::IncorrectAuthority);
-    }
+    // Verify the Credential's admins approved the change.
+    verify_credential_admin(credential, authority_info, co_signers)?;
 
-    let mut schema_data = schema_info.try_borrow_mut_data()?;
+    let schema_data = schema_info.try_borrow_data()?;
     let mut schema = Schema::try_from_bytes(&schema_data)?;
+    drop(schema_data); // Drop immutable borrow.
 
     // Verify that schema is under the same credential.
     if schema.credential.ne(credential_info.key()) {
@@ -45,7 +46,7 @@ pub fn process_change_schema_status(
 
     schema.is_paused = args.is_paused;
     log!("Setting schema's is_paused to: {}", args.is_paused as u8);
-    schema_data.copy_from_slice(&schema.to_bytes());
+    write_account_data(schema_info, None, &schema.to_bytes())?;
 
     Ok(())
 }
@@ -12,8 +12,9 @@ use crate::{
     constants::SCHEMA_SEED,
     error::AttestationServiceError,
     processor::{
-        create_pda_account, verify_owner_mutability, verify_signer, verify_system_account,
-        verify_system_program,
+        create_pda_account, process_constraints, process_enum_variants, validate_constraints,
+        verify_credential_admin, verify_owner_mutability, verify_signer, verify_system_account,
+        verify_system_program, write_account_data,
     },
     require_len,
     state::{discriminator::AccountSerialize, Credential, Schema},
@@ -26,7 +27,7 @@ pub fn process_change_schema_version(
     instruction_data: &[u8],
 ) -> ProgramResult {
     let args = process_instruction_data(instruction_data)?;
-    let [payer_info, authority_info, credential_info, existing_schema_info, new_schema_info, system_program] =
+    let [payer_info, authority_info, credential_info, existing_schema_info, new_schema_info, system_program, co_signers @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -39,15 +40,13 @@ pub fn process_change_schema_version(
     // Validate: system program
     verify_system_program(system_program)?;
     // Verify program ownership, mutability and PDAs.
-    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(credential_info, program_id, true)?;
     verify_owner_mutability(existing_schema_info, program_id, false)?;
 
-    let credential = &Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
+    let mut credential = Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
 
-    // Verify signer matches credential authority.
-    if credential.authority.ne(authority_info.key()) {
-        return Err(ProgramError::IncorrectAuthority);
-    }
+    // Verify the Credential's admins approved the change.
+    verify_credential_admin(&credential, authority_info, co_signers)?;
 
     let existing_schema_data = existing_schema_info.try_borrow_data()?;
     let existing_schema = Schema::try_from_bytes(&existing_schema_data)?;
@@ -83,6 +82,11 @@ pub fn process_change_schema_version(
     // field_names - 4 + length
     // is_paused - 1
     // version - 1
+    // payer - 32
+    // requires_subject_signer - 1
+    // constraints - 4 + length
+    // enum_variants - 4 + length
+    // attestation_count - 8
     let space = 1
         + 32
         + (4 + name.len())
@@ -90,7 +94,12 @@ pub fn process_change_schema_version(
         + (4 + args.layout.len())
         + (4 + args.field_names_bytes.len())
         + 1
-        + 1;
+        + 1
+        + 32
+        + 1
+        + (4 + args.constraints.len())
+        + (4 + args.enum_variants_bytes.len())
+        + 8;
     let rent = Rent::get()?;
     let bump_seed = [schema_bump];
     let signer_seeds = [
@@ -118,14 +127,25 @@ pub fn process_change_schema_version(
         field_names: args.field_names_bytes.to_vec(),
         is_paused: false,
         version: version[0],
+        payer: *payer_info.key(),
+        requires_subject_signer: existing_schema.requires_subject_signer,
+        constraints: args.constraints.to_vec(),
+        enum_variants: args.enum_variants_bytes.to_vec(),
+        attestation_count: 0,
     };
 
-    // Checks that layout and field names are valid.
-    schema.validate(args.field_names_count)?;
+    // Checks that layout, field names and enum variant names are valid.
+    schema.validate(args.field_names_count, args.enum_variants_count)?;
+    // Checks that constraints apply to the layout's fields.
+    validate_constraints(&schema.constraints, &schema.layout)?;
 
     let mut schema_data = new_schema_info.try_borrow_mut_data()?;
     schema_data.copy_from_slice(&schema.to_bytes());
 
+    // Track the new Schema on its Credential.
+    credential.adjust_schema_count(1)?;
+    write_account_data(credential_info, Some(payer_info), &credential.to_bytes())?;
+
     Ok(())
 }
 
@@ -133,6 +153,9 @@ struct ChangeSchemaVersionArgs<'a> {
     layout: &'a [u8],
     field_names_count: u32,
     field_names_bytes: &'a [u8],
+    constraints: &'a [u8],
+    enum_variants_count: u32,
+    enum_variants_bytes: &'a [u8],
 }
 
 fn process_instruction_data(data: &[u8]) -> Result<ChangeSchemaVersionArgs, ProgramError> {
@@ -162,10 +185,17 @@ fn process_instruction_data(data: &[u8]) -> Result<ChangeSchemaVersionArgs, Prog
 
     require_len!(data, offset + byte_len);
     let field_names_bytes = &data[offset..offset + byte_len];
+    offset += byte_len;
+
+    let (constraints, offset) = process_constraints(data, offset)?;
+    let (enum_variants_count, enum_variants_bytes, _) = process_enum_variants(data, offset)?;
 
     Ok(ChangeSchemaVersionArgs {
         layout,
         field_names_count,
         field_names_bytes,
+        constraints,
+        enum_variants_count,
+        enum_variants_bytes,
     })
 }
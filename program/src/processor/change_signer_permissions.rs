@@ -0,0 +1,76 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{
+    processor::{
+        verify_credential_admin, verify_owner_mutability, verify_signer, verify_system_program,
+        write_account_data,
+    },
+    require_len,
+    state::{discriminator::AccountSerialize, Credential, SignerPermission},
+};
+
+#[inline(always)]
+pub fn process_change_signer_permissions(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [payer_info, authority_info, credential_info, system_program, co_signers @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authority_info, false)?;
+    // Validate: system program
+    verify_system_program(system_program)?;
+    // Verify program ownership, mutability and PDAs.
+    verify_owner_mutability(credential_info, program_id, true)?;
+
+    let data = credential_info.try_borrow_data()?;
+    let mut credential = Credential::try_from_bytes(&data)?;
+    drop(data); // Drop immutable borrow.
+
+    // Verify the Credential's admins approved the change.
+    verify_credential_admin(&credential, authority_info, co_signers)?;
+    Credential::validate_signer_permissions(&args.permissions)?;
+
+    // Update signer_permissions on struct.
+    credential.signer_permissions = args.permissions;
+
+    // Write updated data.
+    write_account_data(credential_info, Some(payer_info), &credential.to_bytes())?;
+
+    Ok(())
+}
+
+struct ChangeSignerPermissionsArgs {
+    permissions: Vec<SignerPermission>,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<ChangeSignerPermissionsArgs, ProgramError> {
+    let mut offset: usize = 0;
+
+    require_len!(data, 4);
+    let permissions_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+    offset += 4;
+
+    // The count is untrusted, so grow the list only as entries are parsed.
+    let mut permissions = Vec::new();
+    for _ in 0..permissions_len {
+        let (permission, len) = SignerPermission::try_from_bytes(&data[offset..])?;
+        permissions.push(permission);
+        offset += len;
+    }
+    if offset != data.len() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok(ChangeSignerPermissionsArgs { permissions })
+}
@@ -1,27 +1,34 @@
 use pinocchio::{
-    account_info::AccountInfo,
-    instruction::{AccountMeta, Instruction, Seed, Signer},
-    program::invoke_signed,
-    program_error::ProgramError,
-    pubkey::Pubkey,
-    ProgramResult,
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
 };
 
 use crate::{
-    constants::{event_authority_pda, EVENT_AUTHORITY_SEED},
     error::AttestationServiceError,
-    events::{CloseAttestationEvent, EventDiscriminators},
-    state::{Attestation, Credential},
+    events::{CloseAttestationEvent, EventDiscriminators, SubjectCloseAttestationEvent},
+    state::{Attestation, Credential, Schema, SignerPermission},
 };
 
 use super::{
-    verify_current_program, verify_owner_mutability, verify_signer, verify_system_program,
+    invoke_emit_event, verify_current_program, verify_owner_mutability, verify_rent_recipient,
+    verify_signer, verify_system_program,
 };
 
 #[inline(always)]
-pub fn process_close_attestation(
+pub fn process_close_attestation(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [_, _, _, _, _, _, _, schema_info, co_signers @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    close_attestation_account(program_id, &accounts[..7], schema_info, co_signers, None)
+}
+
+/// Close an Attestation given the first seven accounts of CloseAttestation,
+/// and the Schema account and admin co-signers which follow them.
+#[inline(always)]
+pub fn close_attestation_account(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
+    schema_info: &AccountInfo,
+    co_signers: &[AccountInfo],
     token_account: Option<Pubkey>,
 ) -> ProgramResult {
     let [payer_info, authorized_signer, credential_info, attestation_info, event_authority_info, system_program, attestation_program] =
@@ -39,19 +46,30 @@ pub fn process_close_attestation(
     // Verify attestation program
     verify_current_program(attestation_program)?;
 
-    // Validate Credential and Attestation is owned by our program
+    // Validate Credential, Schema and Attestation is owned by our program
     verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(schema_info, program_id, true)?;
     verify_owner_mutability(attestation_info, program_id, true)?;
 
-    // Check that one of credential's authorized signers have signed.
     let credential_data = credential_info.try_borrow_data()?;
     let credential = Credential::try_from_bytes(&credential_data)?;
-    credential.validate_authorized_signer(authorized_signer.key())?;
 
     let attestation_data = attestation_info.try_borrow_data()?;
     let attestation = Attestation::try_from_bytes(&attestation_data)?;
     drop(attestation_data); // Drop immutable borrow.
 
+    // The subject may close an Attestation about themselves by signing with
+    // the nonce wallet, even once revoked. Otherwise one of credential's
+    // authorized signers must have signed.
+    let closed_by_subject = attestation.nonce.eq(authorized_signer.key());
+    if !closed_by_subject {
+        credential.validate_authorized_signer(
+            authorized_signer.key(),
+            &attestation.schema,
+            SignerPermission::CLOSE,
+        )?;
+    }
+
     // Verify token_account matches address in Attestation
     if let Some(token_account) = token_account {
         if token_account.ne(&attestation.token_account) {
@@ -66,6 +84,20 @@ pub fn process_close_attestation(
         return Err(AttestationServiceError::InvalidCredential.into());
     }
 
+    // Check that schema matches attestation's.
+    if attestation.schema.ne(schema_info.key()) {
+        return Err(AttestationServiceError::InvalidSchema.into());
+    }
+
+    // Validate rent is refunded to the account that funded the Attestation.
+    verify_rent_recipient(
+        &attestation.payer,
+        payer_info,
+        &credential,
+        authorized_signer,
+        co_signers,
+    )?;
+
     // Close account and transfer rent to payer.
     let payer_lamports = payer_info.lamports();
     *payer_info.try_borrow_mut_lamports().unwrap() = payer_lamports
@@ -73,30 +105,26 @@ pub fn process_close_attestation(
         .unwrap();
     *attestation_info.try_borrow_mut_lamports().unwrap() = 0;
     attestation_info.close()?;
+    Schema::adjust_attestation_count(&mut schema_info.try_borrow_mut_data()?, -1)?;
 
-    // Check that event authority PDA is valid.
-    if event_authority_info.key().ne(&event_authority_pda::ID) {
-        return Err(AttestationServiceError::InvalidEventAuthority.into());
-    }
-
-    // CPI to emit_event ix on same program to store event data in ix arg.
-    let event = CloseAttestationEvent {
-        discriminator: EventDiscriminators::CloseEvent as u8,
-        schema: attestation.schema,
-        attestation_data: attestation.data,
+    let event_data = if closed_by_subject {
+        SubjectCloseAttestationEvent {
+            discriminator: EventDiscriminators::SubjectCloseEvent as u8,
+            schema: attestation.schema,
+            subject: attestation.nonce,
+            revoked_at: attestation.revoked_at,
+            attestation_data: attestation.data,
+        }
+        .to_bytes()
+    } else {
+        CloseAttestationEvent {
+            discriminator: EventDiscriminators::CloseEvent as u8,
+            schema: attestation.schema,
+            attestation_data: attestation.data,
+        }
+        .to_bytes()
     };
-    invoke_signed(
-        &Instruction {
-            program_id,
-            accounts: &[AccountMeta::new(event_authority_info.key(), false, true)],
-            data: event.to_bytes().as_slice(),
-        },
-        &[event_authority_info],
-        &[Signer::from(&[
-            Seed::from(EVENT_AUTHORITY_SEED),
-            Seed::from(&[event_authority_pda::BUMP]),
-        ])],
-    )?;
+    invoke_emit_event(program_id, event_authority_info, &event_data)?;
 
     Ok(())
 }
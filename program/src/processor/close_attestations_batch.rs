@@ -0,0 +1,141 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{
+    error::AttestationServiceError,
+    events::{CloseAttestationsBatchEvent, ClosedAttestation, EventDiscriminators},
+    state::{discriminator::Discriminator, Attestation, Credential, Schema, SignerPermission},
+};
+
+use super::{
+    invoke_emit_event, verify_current_program, verify_owner_mutability, verify_rent_recipient,
+    verify_signer, verify_system_program,
+};
+
+#[inline(always)]
+pub fn process_close_attestations_batch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [payer_info, authorized_signer, credential_info, event_authority_info, system_program, attestation_program, remaining_infos @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authorized_signer, false)?;
+
+    // Validate system program
+    verify_system_program(system_program)?;
+
+    // Verify attestation program
+    verify_current_program(attestation_program)?;
+
+    // Validate Credential is owned by our program
+    verify_owner_mutability(credential_info, program_id, false)?;
+
+    let credential_data = credential_info.try_borrow_data()?;
+    let credential = Credential::try_from_bytes(&credential_data)?;
+
+    // The remaining accounts hold the Attestations to close and the Schema of
+    // each, told apart by their discriminator. Schemas are paired with the
+    // number of their Attestations closed. Signers among them are Credential
+    // admins approving the rent going elsewhere.
+    let mut schemas: Vec<(&AccountInfo, i64)> = Vec::new();
+    let mut attestation_infos = Vec::with_capacity(remaining_infos.len());
+    for info in remaining_infos {
+        if info.is_signer() {
+            continue;
+        }
+        verify_owner_mutability(info, program_id, true)?;
+        if info.try_borrow_data()?.first() == Some(&Schema::DISCRIMINATOR) {
+            schemas.push((info, 0));
+        } else {
+            attestation_infos.push(info);
+        }
+    }
+
+    if attestation_infos.is_empty() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let mut closed = Vec::with_capacity(attestation_infos.len());
+    for attestation_info in attestation_infos {
+        // Validate Attestation is owned by our program. A duplicated account
+        // fails here once it has been closed.
+        verify_owner_mutability(attestation_info, program_id, true)?;
+
+        let attestation_data = attestation_info.try_borrow_data()?;
+        let attestation = Attestation::try_from_bytes(&attestation_data)?;
+        drop(attestation_data); // Drop immutable borrow.
+
+        // Tokenized Attestations must go through CloseTokenizedAttestation.
+        if attestation.token_account.ne(&Pubkey::default()) {
+            return Err(AttestationServiceError::InvalidTokenAccount.into());
+        }
+
+        // Check that credential matches attestation's.
+        if !attestation.credential.eq(credential_info.key()) {
+            return Err(AttestationServiceError::InvalidCredential.into());
+        }
+
+        // Check that one of credential's authorized signers may close under
+        // the attestation's Schema.
+        credential.validate_authorized_signer(
+            authorized_signer.key(),
+            &attestation.schema,
+            SignerPermission::CLOSE,
+        )?;
+
+        // The attestation's Schema must be passed to count the close.
+        let (_, schema_closed) = schemas
+            .iter_mut()
+            .find(|(schema_info, _)| schema_info.key().eq(&attestation.schema))
+            .ok_or(AttestationServiceError::InvalidSchema)?;
+        *schema_closed += 1;
+
+        // Same refund rule as CloseAttestation, applied per Attestation.
+        verify_rent_recipient(
+            &attestation.payer,
+            payer_info,
+            &credential,
+            authorized_signer,
+            remaining_infos,
+        )?;
+
+        // Close account and transfer rent to payer.
+        let payer_lamports = payer_info.lamports();
+        *payer_info.try_borrow_mut_lamports().unwrap() = payer_lamports
+            .checked_add(attestation_info.lamports())
+            .unwrap();
+        *attestation_info.try_borrow_mut_lamports().unwrap() = 0;
+        attestation_info.close()?;
+
+        closed.push(ClosedAttestation {
+            schema: attestation.schema,
+            attestation: *attestation_info.key(),
+        });
+    }
+
+    for (schema_info, schema_closed) in schemas {
+        if schema_closed > 0 {
+            Schema::adjust_attestation_count(
+                &mut schema_info.try_borrow_mut_data()?,
+                -schema_closed,
+            )?;
+        }
+    }
+
+    let event = CloseAttestationsBatchEvent {
+        discriminator: EventDiscriminators::CloseBatchEvent as u8,
+        closed,
+    };
+    invoke_emit_event(program_id, event_authority_info, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{
+    error::AttestationServiceError,
+    events::{CloseCredentialEvent, EventDiscriminators},
+    state::Credential,
+};
+
+use super::{
+    invoke_emit_event, verify_credential_admin, verify_current_program, verify_owner_mutability,
+    verify_rent_recipient, verify_signer,
+};
+
+#[inline(always)]
+pub fn process_close_credential(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [payer_info, authority_info, credential_info, event_authority_info, attestation_program, co_signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authority_info, false)?;
+
+    // Verify attestation program
+    verify_current_program(attestation_program)?;
+
+    // Verify program ownership, mutability and PDAs.
+    verify_owner_mutability(credential_info, program_id, true)?;
+
+    // Validate payer is writable to receive the rent
+    if !payer_info.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let credential = Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
+
+    // Verify the Credential's admins approved the change.
+    verify_credential_admin(&credential, authority_info, co_signers)?;
+
+    // Schemas must be closed before their Credential.
+    if credential.schema_count != 0 {
+        return Err(AttestationServiceError::CredentialHasSchemas.into());
+    }
+
+    // Validate rent is refunded to the account that funded the Credential.
+    verify_rent_recipient(
+        &credential.payer,
+        payer_info,
+        &credential,
+        authority_info,
+        co_signers,
+    )?;
+
+    // Close account and transfer rent to payer.
+    let payer_lamports = payer_info.lamports();
+    *payer_info.try_borrow_mut_lamports().unwrap() = payer_lamports
+        .checked_add(credential_info.lamports())
+        .unwrap();
+    *credential_info.try_borrow_mut_lamports().unwrap() = 0;
+    credential_info.close()?;
+
+    let event = CloseCredentialEvent {
+        discriminator: EventDiscriminators::CredentialCloseEvent as u8,
+        credential: *credential_info.key(),
+        authority: credential.authority,
+    };
+    invoke_emit_event(program_id, event_authority_info, &event.to_bytes())?;
+
+    Ok(())
+}
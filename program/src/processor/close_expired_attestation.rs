@@ -0,0 +1,93 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    error::AttestationServiceError,
+    events::{CloseAttestationEvent, EventDiscriminators},
+    state::{Attestation, Credential, Schema},
+};
+
+use super::{invoke_emit_event, rent_funder, verify_current_program, verify_owner_mutability};
+
+/// Permissionless close of an Attestation whose expiry has passed. No signer
+/// is required, rent is always returned to the account that funded the
+/// Attestation, or the Credential authority if it predates the stored payer.
+#[inline(always)]
+pub fn process_close_expired_attestation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [payer_info, attestation_info, event_authority_info, attestation_program, schema_info, credential_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Verify attestation program
+    verify_current_program(attestation_program)?;
+
+    // Validate Credential, Schema and Attestation are owned by our program
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(schema_info, program_id, true)?;
+    verify_owner_mutability(attestation_info, program_id, true)?;
+
+    // Validate payer is writable to receive the rent
+    if !payer_info.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let attestation_data = attestation_info.try_borrow_data()?;
+    let attestation = Attestation::try_from_bytes(&attestation_data)?;
+    drop(attestation_data); // Drop immutable borrow.
+
+    // Tokenized Attestations must be closed through CloseTokenizedAttestation
+    // so the Attestation token is burned alongside the account.
+    if attestation.token_account.ne(&Pubkey::default()) {
+        return Err(AttestationServiceError::InvalidTokenAccount.into());
+    }
+
+    // Validate schema and credential match attestation's
+    if attestation.schema.ne(schema_info.key()) {
+        return Err(AttestationServiceError::InvalidSchema.into());
+    }
+    if attestation.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+
+    // Validate expiry is set and has passed
+    let clock = Clock::get()?;
+    if !attestation.is_expired(clock.unix_timestamp) {
+        return Err(AttestationServiceError::AttestationNotExpired.into());
+    }
+
+    // Validate rent is returned to the original payer
+    let credential_data = credential_info.try_borrow_data()?;
+    let credential = Credential::try_from_bytes(&credential_data)?;
+    drop(credential_data); // Drop immutable borrow.
+    if rent_funder(&attestation.payer, &credential).ne(payer_info.key()) {
+        return Err(AttestationServiceError::InvalidPayer.into());
+    }
+
+    // Close account and transfer rent to payer.
+    let payer_lamports = payer_info.lamports();
+    *payer_info.try_borrow_mut_lamports().unwrap() = payer_lamports
+        .checked_add(attestation_info.lamports())
+        .unwrap();
+    *attestation_info.try_borrow_mut_lamports().unwrap() = 0;
+    attestation_info.close()?;
+    Schema::adjust_attestation_count(&mut schema_info.try_borrow_mut_data()?, -1)?;
+
+    let event = CloseAttestationEvent {
+        discriminator: EventDiscriminators::CloseEvent as u8,
+        schema: attestation.schema,
+        attestation_data: attestation.data,
+    };
+    invoke_emit_event(program_id, event_authority_info, &event.to_bytes())?;
+
+    Ok(())
+}
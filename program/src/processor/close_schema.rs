@@ -0,0 +1,115 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+use pinocchio_token::TOKEN_2022_PROGRAM_ID;
+use solana_program::pubkey::Pubkey as SolanaPubkey;
+
+use crate::{
+    constants::{ATTESTATION_TREE_SEED, SCHEMA_MINT_SEED},
+    error::AttestationServiceError,
+    events::{CloseSchemaEvent, EventDiscriminators},
+    state::{discriminator::AccountSerialize, Credential, Schema},
+};
+
+use super::{
+    invoke_emit_event, verify_credential_admin, verify_current_program, verify_owner_mutability,
+    verify_rent_recipient, verify_signer, write_account_data,
+};
+
+#[inline(always)]
+pub fn process_close_schema(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [payer_info, authority_info, credential_info, schema_info, attestation_tree_info, schema_mint_info, event_authority_info, attestation_program, co_signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authority_info, false)?;
+
+    // Verify attestation program
+    verify_current_program(attestation_program)?;
+
+    // Verify program ownership, mutability and PDAs.
+    verify_owner_mutability(credential_info, program_id, true)?;
+    verify_owner_mutability(schema_info, program_id, true)?;
+
+    // Validate payer is writable to receive the rent
+    if !payer_info.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut credential = Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
+
+    // Verify the Credential's admins approved the change.
+    verify_credential_admin(&credential, authority_info, co_signers)?;
+
+    let schema = Schema::try_from_bytes(&schema_info.try_borrow_data()?)?;
+
+    // Verify that schema is under the same credential.
+    if schema.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidSchema.into());
+    }
+
+    // Attestations must be closed before the Schema they adhere to.
+    if schema.attestation_count != 0 {
+        return Err(AttestationServiceError::SchemaHasAttestations.into());
+    }
+
+    // Compressed Attestations are not counted, so the Schema must not have an
+    // AttestationTree. Re-creating the Schema would otherwise revive its leaves.
+    let (attestation_tree_pda, _) = SolanaPubkey::find_program_address(
+        &[ATTESTATION_TREE_SEED, schema_info.key()],
+        &SolanaPubkey::from(*program_id),
+    );
+    if attestation_tree_info
+        .key()
+        .ne(&attestation_tree_pda.to_bytes())
+    {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if attestation_tree_info.is_owned_by(program_id) {
+        return Err(AttestationServiceError::SchemaHasAttestations.into());
+    }
+
+    // The Schema mint has no close authority, so a tokenized Schema would
+    // leave it orphaned.
+    let (schema_mint_pda, _) = SolanaPubkey::find_program_address(
+        &[SCHEMA_MINT_SEED, schema_info.key()],
+        &SolanaPubkey::from(*program_id),
+    );
+    if schema_mint_info.key().ne(&schema_mint_pda.to_bytes()) {
+        return Err(AttestationServiceError::InvalidMint.into());
+    }
+    if schema_mint_info.is_owned_by(&TOKEN_2022_PROGRAM_ID) {
+        return Err(AttestationServiceError::SchemaTokenized.into());
+    }
+
+    // Validate rent is refunded to the account that funded the Schema.
+    verify_rent_recipient(
+        &schema.payer,
+        payer_info,
+        &credential,
+        authority_info,
+        co_signers,
+    )?;
+
+    // Close account and transfer rent to payer.
+    let payer_lamports = payer_info.lamports();
+    *payer_info.try_borrow_mut_lamports().unwrap() =
+        payer_lamports.checked_add(schema_info.lamports()).unwrap();
+    *schema_info.try_borrow_mut_lamports().unwrap() = 0;
+    schema_info.close()?;
+
+    credential.adjust_schema_count(-1)?;
+    write_account_data(credential_info, None, &credential.to_bytes())?;
+
+    let event = CloseSchemaEvent {
+        discriminator: EventDiscriminators::SchemaCloseEvent as u8,
+        credential: *credential_info.key(),
+        schema: *schema_info.key(),
+    };
+    invoke_emit_event(program_id, event_authority_info, &event.to_bytes())?;
+
+    Ok(())
+}
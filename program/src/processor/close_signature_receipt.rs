@@ -0,0 +1,58 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{error::AttestationServiceError, state::SignatureReceipt};
+
+use super::verify_owner_mutability;
+
+/// Permissionless close of a signature receipt whose message deadline has
+/// passed. The message can no longer be submitted, so the receipt is not
+/// needed for replay protection. Rent is returned to the account that funded
+/// the receipt.
+#[inline(always)]
+pub fn process_close_signature_receipt(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [payer_info, signature_receipt_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signature receipt is owned by our program
+    verify_owner_mutability(signature_receipt_info, program_id, true)?;
+
+    // Validate payer is writable to receive the rent
+    if !payer_info.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let signature_receipt =
+        SignatureReceipt::try_from_bytes(&signature_receipt_info.try_borrow_data()?)?;
+
+    // Validate the deadline has passed. CreateAttestationWithSignature accepts
+    // the message up to and including its deadline.
+    let clock = Clock::get()?;
+    if clock.unix_timestamp <= signature_receipt.deadline {
+        return Err(AttestationServiceError::SignatureNotExpired.into());
+    }
+
+    // Validate rent is returned to the original payer
+    if signature_receipt.payer.ne(payer_info.key()) {
+        return Err(AttestationServiceError::InvalidPayer.into());
+    }
+
+    // Close account and transfer rent to payer.
+    let payer_lamports = payer_info.lamports();
+    *payer_info.try_borrow_mut_lamports().unwrap() = payer_lamports
+        .checked_add(signature_receipt_info.lamports())
+        .unwrap();
+    *signature_receipt_info.try_borrow_mut_lamports().unwrap() = 0;
+    signature_receipt_info.close()?;
+
+    Ok(())
+}
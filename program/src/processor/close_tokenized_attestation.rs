@@ -14,14 +14,14 @@ use crate::{
 };
 use pinocchio_token::instructions::{BurnChecked, CloseAccount, TokenProgramVariant};
 
-use super::process_close_attestation;
+use super::close_attestation_account;
 
 #[inline(always)]
 pub fn process_close_tokenized_attestation(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [payer_info, _authorized_signer, _credential_info, attestation_info, _event_authority_info, _system_program, _attestation_program, attestation_mint_info, sas_pda_info, attestation_token_account, token_program] =
+    let [payer_info, _authorized_signer, _credential_info, attestation_info, _event_authority_info, _system_program, _attestation_program, attestation_mint_info, sas_pda_info, attestation_token_account, token_program, schema_info, co_signers @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -75,11 +75,13 @@ pub fn process_close_tokenized_attestation(
     )?;
 
     // Close Attestation: This needs to be called after closing of Mint due to Solana
-    // limitations around lamports balance. This also verifies accounts[0..7] and
-    // attestation_token_account.
-    process_close_attestation(
+    // limitations around lamports balance. This also verifies accounts[0..7],
+    // the Schema and attestation_token_account.
+    close_attestation_account(
         program_id,
         &accounts[0..7],
+        schema_info,
+        co_signers,
         Some(*attestation_token_account.key()),
     )?;
 
@@ -1,3 +1,6 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
 use pinocchio::{
     account_info::AccountInfo,
     instruction::Seed,
@@ -12,39 +15,104 @@ use crate::{
     constants::ATTESTATION_SEED,
     error::AttestationServiceError,
     require_len,
-    state::{discriminator::AccountSerialize, Attestation, Credential, Schema},
+    state::{discriminator::AccountSerialize, Attestation, Credential, Schema, SignerPermission},
 };
 
-use super::{create_pda_account, verify_owner_mutability, verify_signer, verify_system_program};
+use super::{
+    check_constraints, create_pda_account, verify_owner_mutability, verify_signer,
+    verify_system_program,
+};
 
 #[inline(always)]
 pub fn process_create_attestation(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
+) -> ProgramResult {
+    let (mut args, offset) = process_create_attestation_data(instruction_data)?;
+    args.references = process_references(instruction_data, offset)?;
+
+    create_attestation(program_id, accounts, &args, None)
+}
+
+/// Validate the issuer and create the Attestation. Shared by CreateAttestation
+/// and CreateTokenizedAttestation.
+pub fn create_attestation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &CreateAttestationArgs,
     token_account: Option<Pubkey>,
 ) -> ProgramResult {
-    let args = process_instruction_data(instruction_data)?;
-    let [payer_info, authorized_signer, credential_info, schema_info, attestation_info, system_program] =
+    let [_payer_info, authorized_signer, credential_info, schema_info, attestation_info, system_program, ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    let schema = validate_attestation_issuer(
+        program_id,
+        authorized_signer,
+        credential_info,
+        schema_info,
+        system_program,
+    )?;
+
+    create_attestation_account(
+        program_id,
+        accounts,
+        attestation_info,
+        &schema,
+        args,
+        token_account,
+    )
+}
+
+/// Validate the authorized signer, Credential and Schema that are shared by
+/// every Attestation created in an instruction. Returns the Schema.
+pub fn validate_attestation_issuer(
+    program_id: &Pubkey,
+    authorized_signer: &AccountInfo,
+    credential_info: &AccountInfo,
+    schema_info: &AccountInfo,
+    system_program: &AccountInfo,
+) -> Result<Schema, ProgramError> {
     // Validate: authority should have signed
     verify_signer(authorized_signer, false)?;
 
+    validate_attestation_schema(
+        program_id,
+        authorized_signer.key(),
+        credential_info,
+        schema_info,
+        system_program,
+    )
+}
+
+/// Same as `validate_attestation_issuer`, for callers that authenticate the
+/// authority by other means than a transaction signature.
+pub fn validate_attestation_schema(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    credential_info: &AccountInfo,
+    schema_info: &AccountInfo,
+    system_program: &AccountInfo,
+) -> Result<Schema, ProgramError> {
     // Validate system program
     verify_system_program(system_program)?;
-    // Validate Credential and Schema are owned by our program
+    // Validate Credential and Schema are owned by our program. The Schema is
+    // writable to count its Attestations.
     verify_owner_mutability(credential_info, program_id, false)?;
-    verify_owner_mutability(schema_info, program_id, false)?;
+    verify_owner_mutability(schema_info, program_id, true)?;
 
     let credential_data = credential_info.try_borrow_data()?;
     let credential = Credential::try_from_bytes(&credential_data)?;
 
-    // Validate Authority is an authorized signer
-    credential.validate_authorized_signer(authorized_signer.key())?;
+    // Validate Authority is an authorized signer able to attest to the Schema
+    credential.validate_authorized_signer(
+        authority,
+        schema_info.key(),
+        SignerPermission::ATTEST,
+    )?;
 
     let schema_data = schema_info.try_borrow_data()?;
     let schema = Schema::try_from_bytes(&schema_data)?;
@@ -59,12 +127,58 @@ pub fn process_create_attestation(
         return Err(AttestationServiceError::InvalidCredential.into());
     }
 
+    Ok(schema)
+}
+
+/// Create a single Attestation account. The first four `accounts` must be
+/// payer, authority, credential and schema, already validated with
+/// `validate_attestation_issuer`.
+pub fn create_attestation_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    attestation_info: &AccountInfo,
+    schema: &Schema,
+    args: &CreateAttestationArgs,
+    token_account: Option<Pubkey>,
+) -> ProgramResult {
+    let [payer_info, authorized_signer, credential_info, schema_info, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate the subject co-signed when the Schema binds the nonce to the
+    // subject's wallet. The subject may be passed as a remaining account.
+    if schema.requires_subject_signer
+        && !accounts
+            .iter()
+            .any(|info| info.key().eq(&args.nonce) && info.is_signer())
+    {
+        return Err(AttestationServiceError::MissingSubjectSignature.into());
+    }
+
     // Validate expiry is greater than current timestamp
     let clock = Clock::get()?;
     if args.expiry < clock.unix_timestamp && args.expiry != 0 {
         return Err(AttestationServiceError::InvalidAttestationData.into());
     }
 
+    // Validate every referenced Attestation is passed in, is a live
+    // Attestation of this program, and has not expired or been revoked.
+    for reference in &args.references {
+        let reference_info = accounts
+            .iter()
+            .find(|info| info.key().eq(reference))
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        if !reference_info.is_owned_by(program_id) || reference_info.data_is_empty() {
+            return Err(AttestationServiceError::InvalidReference.into());
+        }
+        let reference_data = reference_info.try_borrow_data()?;
+        let referenced = Attestation::try_from_bytes(&reference_data)
+            .map_err(|_| AttestationServiceError::InvalidReference)?;
+        if referenced.is_expired(clock.unix_timestamp) || referenced.is_revoked() {
+            return Err(AttestationServiceError::InvalidReference.into());
+        }
+    }
+
     // NOTE: this could be optimized further by removing the `solana-program` dependency
     // and using `pubkey::checked_create_program_address` from Pinocchio to verify the
     // pubkey and associated bump (needed to be added as arg) is valid.
@@ -94,7 +208,28 @@ pub fn process_create_attestation(
     // signer - 32
     // expiry - 8
     // token account - 32
-    let space = 1 + 32 + 32 + 32 + (4 + args.data.len()) + 32 + 8 + 32;
+    // revoked_at - 8
+    // revoked_by - 32
+    // revocation_reason - 1
+    // created_at - 8
+    // updated_at - 8
+    // payer - 32
+    // references - 4 + 32 * len
+    let space = 1
+        + 32
+        + 32
+        + 32
+        + (4 + args.data.len())
+        + 32
+        + 8
+        + 32
+        + 8
+        + 32
+        + 1
+        + 8
+        + 8
+        + 32
+        + (4 + 32 * args.references.len());
 
     let bump_seed = [attestation_bump];
     let signer_seeds = [
@@ -124,24 +259,45 @@ pub fn process_create_attestation(
         signer: *authorized_signer.key(),
         expiry: args.expiry,
         token_account: token_account.unwrap_or_default(),
+        revoked_at: 0,
+        revoked_by: Pubkey::default(),
+        revocation_reason: 0,
+        created_at: clock.unix_timestamp,
+        updated_at: clock.unix_timestamp,
+        payer: *payer_info.key(),
+        references: args.references.clone(),
     };
 
     // Validate the Attestation data matches the layout of the Schema
-    attestation.validate_data(schema.layout)?;
+    attestation.validate_data(schema.layout.clone())?;
+    // Validate the Attestation data satisfies the Schema's field constraints
+    check_constraints(
+        &schema.constraints,
+        &attestation.data,
+        &schema.layout,
+        clock.unix_timestamp,
+    )?;
 
     let mut attestation_data = attestation_info.try_borrow_mut_data()?;
     attestation_data.copy_from_slice(&attestation.to_bytes());
 
+    Schema::adjust_attestation_count(&mut schema_info.try_borrow_mut_data()?, 1)?;
+
     Ok(())
 }
 
-struct CreateAttestationArgs<'a> {
-    nonce: Pubkey,
-    data: &'a [u8],
-    expiry: i64,
+pub struct CreateAttestationArgs<'a> {
+    pub nonce: Pubkey,
+    pub data: &'a [u8],
+    pub expiry: i64,
+    pub references: Vec<Pubkey>,
 }
 
-fn process_instruction_data(data: &[u8]) -> Result<CreateAttestationArgs, ProgramError> {
+/// Parse the nonce, data and expiry shared by the CreateAttestation
+/// instructions. Returns the args and the offset of the remaining data.
+pub fn process_create_attestation_data(
+    data: &[u8],
+) -> Result<(CreateAttestationArgs, usize), ProgramError> {
     let mut offset: usize = 0;
 
     require_len!(data, 32);
@@ -158,10 +314,41 @@ fn process_instruction_data(data: &[u8]) -> Result<CreateAttestationArgs, Progra
 
     require_len!(data, offset + 8);
     let expiry = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+    offset += 8;
+
+    Ok((
+        CreateAttestationArgs {
+            nonce,
+            data: data_bytes,
+            expiry,
+            references: Vec::new(),
+        },
+        offset,
+    ))
+}
+
+/// Parse the optional trailing `Option<Vec<Pubkey>>` of referenced
+/// Attestations. Missing data is treated as `None` for older clients.
+pub fn process_references(data: &[u8], mut offset: usize) -> Result<Vec<Pubkey>, ProgramError> {
+    if data.len() == offset {
+        return Ok(Vec::new());
+    }
+
+    let is_some = data[offset];
+    offset += 1;
+    if is_some == 0 {
+        return Ok(Vec::new());
+    }
+
+    require_len!(data, offset + 4);
+    let references_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+    offset += 4;
+
+    require_len!(data, offset + 32 * references_len);
+    let references = data[offset..offset + 32 * references_len]
+        .chunks_exact(32)
+        .map(|reference| reference.try_into().unwrap())
+        .collect();
 
-    Ok(CreateAttestationArgs {
-        nonce,
-        data: data_bytes,
-        expiry,
-    })
+    Ok(references)
 }
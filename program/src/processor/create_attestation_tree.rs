@@ -0,0 +1,135 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use solana_program::pubkey::Pubkey as SolanaPubkey;
+
+use crate::{
+    constants::ATTESTATION_TREE_SEED,
+    error::AttestationServiceError,
+    require_len,
+    state::{
+        discriminator::AccountSerialize, AttestationTree, Credential, Schema,
+        MAX_TREE_ACCOUNT_SIZE, MAX_TREE_BUFFER_SIZE, MAX_TREE_DEPTH,
+    },
+};
+
+use super::{
+    create_pda_account, verify_credential_admin, verify_owner_mutability, verify_signer,
+    verify_system_program,
+};
+
+#[inline(always)]
+pub fn process_create_attestation_tree(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [payer_info, authority_info, credential_info, schema_info, attestation_tree_info, system_program, co_signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: payer and authority should have signed
+    verify_signer(payer_info, true)?;
+    verify_signer(authority_info, false)?;
+    // Validate Credential and Schema are owned by our program
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(schema_info, program_id, false)?;
+    // Validate: system program
+    verify_system_program(system_program)?;
+
+    // Verify the Credential's admins approved the change.
+    let credential = &Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
+    verify_credential_admin(credential, authority_info, co_signers)?;
+
+    // Validate Schema is owned by Credential
+    let schema = Schema::try_from_bytes(&schema_info.try_borrow_data()?)?;
+    if schema.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+
+    // Validate tree dimensions fit in a single account.
+    let space = AttestationTree::space(args.max_depth, args.max_buffer_size);
+    if !(1..=MAX_TREE_DEPTH).contains(&args.max_depth)
+        || !(1..=MAX_TREE_BUFFER_SIZE).contains(&args.max_buffer_size)
+        || space > MAX_TREE_ACCOUNT_SIZE
+    {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let (attestation_tree_pda, attestation_tree_bump) = SolanaPubkey::find_program_address(
+        &[ATTESTATION_TREE_SEED, schema_info.key()],
+        &SolanaPubkey::from(*program_id),
+    );
+
+    // Validate AttestationTree PDA is correct
+    if attestation_tree_info
+        .key()
+        .ne(&attestation_tree_pda.to_bytes())
+    {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Create AttestationTree account
+
+    // Account layout
+    // discriminator - 1
+    // schema - 32
+    // max_depth - 1
+    // max_buffer_size - 1
+    // num_leaves - 8
+    // sequence - 8
+    // filled_subtrees - 4 + 32 * max_depth
+    // changelog - 4 + (32 + (4 + 32 * max_depth) + 4) * max_buffer_size
+    // payer - 32
+    let bump_seed = [attestation_tree_bump];
+    let signer_seeds = [
+        Seed::from(ATTESTATION_TREE_SEED),
+        Seed::from(schema_info.key()),
+        Seed::from(&bump_seed),
+    ];
+    let rent = Rent::get()?;
+    create_pda_account(
+        payer_info,
+        &rent,
+        space,
+        program_id,
+        attestation_tree_info,
+        signer_seeds,
+        None,
+    )?;
+
+    let attestation_tree = AttestationTree::new(
+        *schema_info.key(),
+        args.max_depth,
+        args.max_buffer_size,
+        *payer_info.key(),
+    );
+    let mut attestation_tree_data = attestation_tree_info.try_borrow_mut_data()?;
+    attestation_tree_data.copy_from_slice(&attestation_tree.to_bytes());
+
+    Ok(())
+}
+
+struct CreateAttestationTreeArgs {
+    max_depth: u8,
+    max_buffer_size: u8,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<CreateAttestationTreeArgs, ProgramError> {
+    require_len!(data, 2);
+    let max_depth = data[0];
+    let max_buffer_size = data[1];
+
+    Ok(CreateAttestationTreeArgs {
+        max_depth,
+        max_buffer_size,
+    })
+}
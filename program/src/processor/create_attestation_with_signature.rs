@@ -0,0 +1,172 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+use solana_attestation_service_core::create_attestation_message;
+use solana_program::{hash::hash, pubkey::Pubkey as SolanaPubkey};
+
+use crate::{
+    constants::SIGNATURE_RECEIPT_SEED,
+    error::AttestationServiceError,
+    require_len,
+    state::{discriminator::AccountSerialize, SignatureReceipt},
+};
+
+use super::{
+    create_attestation_account, create_pda_account, load_ed25519_signature,
+    validate_attestation_schema, verify_instructions_sysvar, verify_signer, CreateAttestationArgs,
+};
+
+#[inline(always)]
+pub fn process_create_attestation_with_signature(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [payer_info, authority_info, credential_info, schema_info, attestation_info, signature_receipt_info, instructions_sysvar, system_program, ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: payer should have signed
+    verify_signer(payer_info, true)?;
+
+    // Validate Instructions sysvar
+    verify_instructions_sysvar(instructions_sysvar)?;
+
+    // Validate the authority is an authorized signer, without requiring it to
+    // sign the transaction.
+    let schema = validate_attestation_schema(
+        program_id,
+        authority_info.key(),
+        credential_info,
+        schema_info,
+        system_program,
+    )?;
+
+    // Validate the signed message has not passed its deadline
+    let clock = Clock::get()?;
+    if clock.unix_timestamp > args.deadline {
+        return Err(AttestationServiceError::SignatureExpired.into());
+    }
+
+    // Validate the preceding Ed25519 instruction verified the authority's
+    // signature over this exact message.
+    let message = create_attestation_message(
+        credential_info.key(),
+        schema_info.key(),
+        &args.attestation.nonce,
+        &hash(args.attestation.data).to_bytes(),
+        args.attestation.expiry,
+        args.deadline,
+    );
+    let (signer, signed_message) = load_ed25519_signature(instructions_sysvar)?;
+    if signer.ne(authority_info.key()) || signed_message.ne(&message) {
+        return Err(AttestationServiceError::InvalidSignatureInstruction.into());
+    }
+
+    // Replay protection: a receipt PDA per signed message is created below,
+    // so the same message cannot be submitted twice.
+    if signature_receipt_info.is_owned_by(program_id) {
+        return Err(AttestationServiceError::SignatureAlreadyUsed.into());
+    }
+
+    let message_hash = hash(&message).to_bytes();
+    let (signature_receipt_pda, signature_receipt_bump) = SolanaPubkey::find_program_address(
+        &[SIGNATURE_RECEIPT_SEED, &message_hash],
+        &SolanaPubkey::from(*program_id),
+    );
+
+    // Validate signature receipt PDA is correct
+    if signature_receipt_info
+        .key()
+        .ne(&signature_receipt_pda.to_bytes())
+    {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Create signature receipt account. Its existence marks the message as
+    // used, it records the deadline after which it may be closed.
+    let bump_seed = [signature_receipt_bump];
+    let signer_seeds = [
+        Seed::from(SIGNATURE_RECEIPT_SEED),
+        Seed::from(&message_hash),
+        Seed::from(&bump_seed),
+    ];
+    let rent = Rent::get()?;
+    create_pda_account(
+        payer_info,
+        &rent,
+        SignatureReceipt::LEN,
+        program_id,
+        signature_receipt_info,
+        signer_seeds,
+        None,
+    )?;
+
+    let signature_receipt = SignatureReceipt {
+        payer: *payer_info.key(),
+        deadline: args.deadline,
+    };
+    signature_receipt_info
+        .try_borrow_mut_data()?
+        .copy_from_slice(&signature_receipt.to_bytes());
+
+    create_attestation_account(
+        program_id,
+        accounts,
+        attestation_info,
+        &schema,
+        &args.attestation,
+        None,
+    )
+}
+
+struct CreateAttestationWithSignatureArgs<'a> {
+    attestation: CreateAttestationArgs<'a>,
+    deadline: i64,
+}
+
+fn process_instruction_data(
+    data: &[u8],
+) -> Result<CreateAttestationWithSignatureArgs, ProgramError> {
+    let mut offset: usize = 0;
+
+    require_len!(data, 32);
+    let nonce: Pubkey = data[offset..offset + 32].try_into().unwrap();
+    offset += 32;
+
+    require_len!(data, offset + 4);
+    let data_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+    offset += 4;
+
+    require_len!(data, offset + data_len);
+    let data_bytes = &data[offset..offset + data_len];
+    offset += data_len;
+
+    require_len!(data, offset + 8);
+    let expiry = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+    offset += 8;
+
+    require_len!(data, offset + 8);
+    let deadline = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+    Ok(CreateAttestationWithSignatureArgs {
+        attestation: CreateAttestationArgs {
+            nonce,
+            data: data_bytes,
+            expiry,
+            references: Vec::new(),
+        },
+        deadline,
+    })
+}
@@ -0,0 +1,98 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{error::AttestationServiceError, require_len};
+
+use super::{create_attestation_account, validate_attestation_issuer, CreateAttestationArgs};
+
+#[inline(always)]
+pub fn process_create_attestations_batch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [_payer_info, authorized_signer, credential_info, schema_info, system_program, attestation_infos @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // One Attestation account is expected per entry, in the same order.
+    let entries = process_instruction_data(instruction_data, attestation_infos.len())?;
+
+    // Validate signer, Credential and Schema once for the whole batch.
+    let schema = validate_attestation_issuer(
+        program_id,
+        authorized_signer,
+        credential_info,
+        schema_info,
+        system_program,
+    )?;
+
+    // Every trailing account is an Attestation, so subjects cannot co-sign.
+    if schema.requires_subject_signer {
+        return Err(AttestationServiceError::SubjectSignerNotSupported.into());
+    }
+
+    for (args, attestation_info) in entries.iter().zip(attestation_infos) {
+        create_attestation_account(program_id, accounts, attestation_info, &schema, args, None)?;
+    }
+
+    Ok(())
+}
+
+fn process_instruction_data(
+    data: &[u8],
+    attestations_len: usize,
+) -> Result<Vec<CreateAttestationArgs>, ProgramError> {
+    let mut offset: usize = 0;
+
+    require_len!(data, 4);
+    let entries_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+    offset += 4;
+
+    if entries_len == 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    if entries_len > attestations_len {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    if entries_len < attestations_len {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut entries = Vec::with_capacity(entries_len);
+    for _ in 0..entries_len {
+        require_len!(data, offset + 32);
+        let nonce: Pubkey = data[offset..offset + 32].try_into().unwrap();
+        offset += 32;
+
+        require_len!(data, offset + 4);
+        let data_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+
+        require_len!(data, offset + data_len);
+        let data_bytes = &data[offset..offset + data_len];
+        offset += data_len;
+
+        require_len!(data, offset + 8);
+        let expiry = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        offset += 8;
+
+        entries.push(CreateAttestationArgs {
+            nonce,
+            data: data_bytes,
+            expiry,
+            references: Vec::new(),
+        });
+    }
+    if offset != data.len() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok(entries)
+}
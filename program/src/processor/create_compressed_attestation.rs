@@ -0,0 +1,133 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    error::AttestationServiceError,
+    events::{CompressedAttestationEvent, EventDiscriminators},
+    state::{
+        discriminator::AccountSerialize, validate_attestation_data, AttestationTree,
+        CompressedAttestation, Credential, Schema, SignerPermission,
+    },
+};
+
+use super::{
+    check_constraints, invoke_emit_event, process_create_attestation_data, verify_current_program,
+    verify_owner_mutability, verify_signer,
+};
+
+#[inline(always)]
+pub fn process_create_compressed_attestation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (args, _) = process_create_attestation_data(instruction_data)?;
+    let [authorized_signer, credential_info, schema_info, attestation_tree_info, event_authority_info, attestation_program, ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authorized_signer, false)?;
+
+    // Verify attestation program
+    verify_current_program(attestation_program)?;
+
+    // Validate Credential, Schema and AttestationTree are owned by our program
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(schema_info, program_id, false)?;
+    verify_owner_mutability(attestation_tree_info, program_id, true)?;
+
+    // Validate Authority is an authorized signer
+    let credential = Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
+    credential.validate_authorized_signer(
+        authorized_signer.key(),
+        schema_info.key(),
+        SignerPermission::ATTEST,
+    )?;
+
+    let schema = Schema::try_from_bytes(&schema_info.try_borrow_data()?)?;
+
+    // Validate Schema is not paused
+    if schema.is_paused {
+        return Err(AttestationServiceError::SchemaPaused.into());
+    }
+
+    // Validate Schema is owned by Credential
+    if schema.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+
+    // Validate the subject co-signed when the Schema binds the nonce to the
+    // subject's wallet.
+    if schema.requires_subject_signer
+        && !accounts
+            .iter()
+            .any(|info| info.key().eq(&args.nonce) && info.is_signer())
+    {
+        return Err(AttestationServiceError::MissingSubjectSignature.into());
+    }
+
+    // Validate expiry is greater than current timestamp
+    let clock = Clock::get()?;
+    if args.expiry < clock.unix_timestamp && args.expiry != 0 {
+        return Err(AttestationServiceError::InvalidAttestationData.into());
+    }
+
+    // Validate the Attestation data matches the layout of the Schema
+    validate_attestation_data(args.data, schema.layout.clone())?;
+    // Validate the Attestation data satisfies the Schema's field constraints
+    check_constraints(
+        &schema.constraints,
+        args.data,
+        &schema.layout,
+        clock.unix_timestamp,
+    )?;
+
+    let mut attestation_tree_data = attestation_tree_info.try_borrow_mut_data()?;
+    let mut attestation_tree = AttestationTree::try_from_bytes(&attestation_tree_data)?;
+
+    // Validate the AttestationTree is bound to the Schema
+    if attestation_tree.schema.ne(schema_info.key()) {
+        return Err(AttestationServiceError::InvalidSchema.into());
+    }
+
+    // Validate the leaf can later be revoked with a proof in one transaction
+    if !attestation_tree.fits_revocation(args.data.len()) {
+        return Err(AttestationServiceError::CompressedAttestationTooLarge.into());
+    }
+
+    let attestation = CompressedAttestation {
+        nonce: args.nonce,
+        credential: *credential_info.key(),
+        schema: *schema_info.key(),
+        data: args.data.to_vec(),
+        signer: *authorized_signer.key(),
+        expiry: args.expiry,
+        created_at: clock.unix_timestamp,
+        revoked_at: 0,
+        revoked_by: Pubkey::default(),
+        revocation_reason: 0,
+    };
+
+    let leaf_index = attestation_tree.append(attestation.hash())?;
+    attestation_tree_data.copy_from_slice(&attestation_tree.to_bytes());
+    drop(attestation_tree_data);
+
+    let event = CompressedAttestationEvent {
+        discriminator: EventDiscriminators::CompressedEvent as u8,
+        tree: *attestation_tree_info.key(),
+        leaf_index,
+        sequence: attestation_tree.sequence,
+        attestation,
+    };
+    invoke_emit_event(program_id, event_authority_info, &event.to_bytes())?;
+
+    Ok(())
+}
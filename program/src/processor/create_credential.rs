@@ -55,7 +55,14 @@ pub fn process_create_credential(
     // authorized_signers - 4 + 32 * len
     // authority - 32
     // name - 4 + len
-    let space = 1 + (4 + args.signers.len() * 32) + 32 + (4 + args.name.len());
+    // payer - 32
+    // admin_threshold - 1
+    // admins - 4 + 32 * len
+    // pending_authority - 32
+    // schema_count - 4
+    // signer_permissions - 4
+    let space =
+        1 + (4 + args.signers.len() * 32) + 32 + (4 + args.name.len()) + 32 + 1 + 4 + 32 + 4 + 4;
 
     let rent = Rent::get()?;
     let bump_seed = [credential_bump];
@@ -79,6 +86,12 @@ pub fn process_create_credential(
         authority: *authority_info.key(),
         name: args.name.to_vec(),
         authorized_signers: args.signers,
+        payer: *payer_info.key(),
+        admin_threshold: 0,
+        admins: Vec::new(),
+        pending_authority: Pubkey::default(),
+        schema_count: 0,
+        signer_permissions: Vec::new(),
     };
     let mut credential_data = credential_info.try_borrow_mut_data()?;
     credential_data.copy_from_slice(&credential.to_bytes());
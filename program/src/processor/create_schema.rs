@@ -11,7 +11,10 @@ use solana_program::pubkey::Pubkey as SolanaPubkey;
 use crate::{
     constants::SCHEMA_SEED,
     error::AttestationServiceError,
-    processor::{create_pda_account, verify_signer, verify_system_account, verify_system_program},
+    processor::{
+        create_pda_account, validate_constraints, verify_credential_admin, verify_signer,
+        verify_system_account, verify_system_program, write_account_data,
+    },
     require_len,
     state::{discriminator::AccountSerialize, Credential, Schema},
 };
@@ -25,7 +28,8 @@ pub fn process_create_schema(
     instruction_data: &[u8],
 ) -> ProgramResult {
     let args = process_instruction_data(instruction_data)?;
-    let [payer_info, authority_info, credential_info, schema_info, system_program] = accounts
+    let [payer_info, authority_info, credential_info, schema_info, system_program, co_signers @ ..] =
+        accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -33,17 +37,15 @@ pub fn process_create_schema(
     // Validate: authority should have signed
     verify_signer(authority_info, false)?;
     // Verify Credential is owned by current program.
-    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(credential_info, program_id, true)?;
     // Validate: schema should be owned by system account, empty, and writable
     verify_system_account(schema_info, true)?;
     // Validate: system program
     verify_system_program(system_program)?;
 
-    let credential = &Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
-    // Verify signer matches credential authority.
-    if credential.authority.ne(authority_info.key()) {
-        return Err(ProgramError::IncorrectAuthority);
-    }
+    let mut credential = Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
+    // Verify the Credential's admins approved the change.
+    verify_credential_admin(&credential, authority_info, co_signers)?;
 
     // NOTE: this could be optimized further by removing the `solana-program` dependency
     // and using `pubkey::checked_create_program_address` from Pinocchio to verify the
@@ -68,6 +70,11 @@ pub fn process_create_schema(
     // field_names - 4 + length
     // is_paused - 1
     // version - 1
+    // payer - 32
+    // requires_subject_signer - 1
+    // constraints - 4 + length
+    // enum_variants - 4 + length
+    // attestation_count - 8
     let space = 1
         + 32
         + (4 + args.name.len())
@@ -75,7 +82,12 @@ pub fn process_create_schema(
         + (4 + args.layout.len())
         + (4 + args.field_names_bytes.len())
         + 1
-        + 1;
+        + 1
+        + 32
+        + 1
+        + (4 + args.constraints.len())
+        + (4 + args.enum_variants_bytes.len())
+        + 8;
     let rent = Rent::get()?;
     let bump_seed = [schema_bump];
     let signer_seeds = [
@@ -103,14 +115,25 @@ pub fn process_create_schema(
         field_names: args.field_names_bytes.to_vec(),
         is_paused: false,
         version: version[0],
+        payer: *payer_info.key(),
+        requires_subject_signer: args.requires_subject_signer,
+        constraints: args.constraints.to_vec(),
+        enum_variants: args.enum_variants_bytes.to_vec(),
+        attestation_count: 0,
     };
 
-    // Checks that layout and field names are valid.
-    schema.validate(args.field_names_count)?;
+    // Checks that layout, field names and enum variant names are valid.
+    schema.validate(args.field_names_count, args.enum_variants_count)?;
+    // Checks that constraints apply to the layout's fields.
+    validate_constraints(&schema.constraints, &schema.layout)?;
 
     let mut schema_data = schema_info.try_borrow_mut_data()?;
     schema_data.copy_from_slice(&schema.to_bytes());
 
+    // Track the new Schema on its Credential.
+    credential.adjust_schema_count(1)?;
+    write_account_data(credential_info, Some(payer_info), &credential.to_bytes())?;
+
     Ok(())
 }
 
@@ -120,6 +143,10 @@ struct CreateSchemaArgs<'a> {
     layout: &'a [u8],
     field_names_count: u32,
     field_names_bytes: &'a [u8],
+    constraints: &'a [u8],
+    enum_variants_count: u32,
+    enum_variants_bytes: &'a [u8],
+    requires_subject_signer: bool,
 }
 
 fn process_instruction_data(data: &[u8]) -> Result<CreateSchemaArgs, ProgramError> {
@@ -165,6 +192,18 @@ fn process_instruction_data(data: &[u8]) -> Result<CreateSchemaArgs, ProgramErro
 
     require_len!(data, offset + byte_len);
     let field_names_bytes = &data[offset..offset + byte_len];
+    offset += byte_len;
+
+    let (constraints, offset) = process_constraints(data, offset)?;
+    let (enum_variants_count, enum_variants_bytes, offset) = process_enum_variants(data, offset)?;
+
+    // Optional trailing `Option<bool>`, missing data is treated as `None`.
+    let requires_subject_signer = if data.len() == offset || data[offset] == 0 {
+        false
+    } else {
+        require_len!(data, offset + 2);
+        data[offset + 1] == 1
+    };
 
     Ok(CreateSchemaArgs {
         name,
@@ -172,5 +211,64 @@ fn process_instruction_data(data: &[u8]) -> Result<CreateSchemaArgs, ProgramErro
         layout,
         field_names_count,
         field_names_bytes,
+        constraints,
+        enum_variants_count,
+        enum_variants_bytes,
+        requires_subject_signer,
     })
 }
+
+/// Parse the optional trailing `Option<Vec<u8>>` of field constraints shared
+/// by CreateSchema and ChangeSchemaVersion. Missing data is treated as `None`
+/// for older clients. Returns the constraints and the offset after them.
+pub fn process_constraints(data: &[u8], mut offset: usize) -> Result<(&[u8], usize), ProgramError> {
+    if data.len() == offset {
+        return Ok((&[], offset));
+    }
+    if data[offset] == 0 {
+        return Ok((&[], offset + 1));
+    }
+    offset += 1;
+
+    require_len!(data, offset + 4);
+    let constraints_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+    offset += 4;
+
+    require_len!(data, offset + constraints_len);
+    let constraints = &data[offset..offset + constraints_len];
+    Ok((constraints, offset + constraints_len))
+}
+
+/// Parse the optional trailing `Option<Vec<String>>` of Enum variant names
+/// shared by CreateSchema and ChangeSchemaVersion, following the constraints.
+/// Missing data is treated as `None`. Returns the number of names, their
+/// serialized bytes and the offset after them.
+pub fn process_enum_variants(
+    data: &[u8],
+    mut offset: usize,
+) -> Result<(u32, &[u8], usize), ProgramError> {
+    if data.len() == offset {
+        return Ok((0, &[], offset));
+    }
+    if data[offset] == 0 {
+        return Ok((0, &[], offset + 1));
+    }
+    offset += 1;
+
+    require_len!(data, offset + 4);
+    let count = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+    offset += 4;
+
+    let mut byte_len = 0;
+    for _ in 0..count {
+        let start = offset + byte_len;
+        let end = start + 4;
+        require_len!(data, end);
+
+        let name_len = u32::from_le_bytes(data[start..end].try_into().unwrap()) as usize;
+        byte_len += 4 + name_len;
+    }
+
+    require_len!(data, offset + byte_len);
+    Ok((count, &data[offset..offset + byte_len], offset + byte_len))
+}
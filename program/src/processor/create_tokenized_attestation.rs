@@ -25,11 +25,13 @@ use solana_program::pubkey::Pubkey as SolanaPubkey;
 use crate::{
     constants::{sas_pda, ATTESTATION_MINT_SEED, SAS_SEED, SCHEMA_MINT_SEED},
     error::AttestationServiceError,
-    processor::process_create_attestation,
+    processor::{create_attestation, process_create_attestation_data, process_references},
     require_len,
 };
 
-use super::{create_pda_account, verify_ata_program, verify_token22_program};
+use super::{
+    create_pda_account, verify_ata_program, verify_token22_program, CreateAttestationArgs,
+};
 
 #[inline(always)]
 pub fn process_create_tokenized_attestation(
@@ -37,22 +39,24 @@ pub fn process_create_tokenized_attestation(
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    let [payer_info, _authorized_signer, _credential_info, schema_info, attestation_info, system_program, schema_mint_info, attestation_mint_info, sas_pda_info, recipient_token_account_info, recipient_info, token_program, ata_program] =
+    let [payer_info, _authorized_signer, _credential_info, schema_info, attestation_info, system_program, schema_mint_info, attestation_mint_info, sas_pda_info, recipient_token_account_info, recipient_info, token_program, ata_program, ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    // Create Attestation first
-    process_create_attestation(
+    let args = process_instruction_data(instruction_data)?;
+
+    // Create Attestation first. All accounts are forwarded so a subject
+    // signer or referenced Attestations passed after the fixed accounts can
+    // be found.
+    create_attestation(
         program_id,
-        &accounts[0..6],
-        instruction_data,
+        accounts,
+        &args.attestation,
         Some(*recipient_token_account_info.key()),
     )?;
 
-    let args = process_instruction_data(instruction_data)?;
-
     // Validate Recipient TokenAccount is writable
     if !recipient_token_account_info.is_writable() {
         return Err(ProgramError::InvalidAccountData);
@@ -220,6 +224,7 @@ pub fn process_create_tokenized_attestation(
 }
 
 struct CreateTokenizedAttestationArgs<'a> {
+    attestation: CreateAttestationArgs<'a>,
     name: &'a [u8],
     uri: &'a [u8],
     symbol: &'a [u8],
@@ -227,12 +232,7 @@ struct CreateTokenizedAttestationArgs<'a> {
 }
 
 fn process_instruction_data(data: &[u8]) -> Result<CreateTokenizedAttestationArgs, ProgramError> {
-    let mut offset: usize = 32; // Skip Nonce
-
-    require_len!(data, offset + 4);
-    let data_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-    offset += 4 + data_len; // Skip Data field
-    offset += 8; // Skip Expiry
+    let (mut attestation, mut offset) = process_create_attestation_data(data)?;
 
     require_len!(data, offset + 4);
     let name_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
@@ -260,8 +260,12 @@ fn process_instruction_data(data: &[u8]) -> Result<CreateTokenizedAttestationArg
 
     require_len!(data, offset + 2);
     let mint_account_space = u16::from_le_bytes(data[offset..offset + 2].try_into().unwrap());
+    offset += 2;
+
+    attestation.references = process_references(data, offset)?;
 
     Ok(CreateTokenizedAttestationArgs {
+        attestation,
         name,
         uri,
         symbol,
@@ -0,0 +1,40 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{
+    processor::{
+        verify_owner_mutability, verify_signer, verify_system_program, write_account_data,
+    },
+    state::{
+        discriminator::{AccountSerialize, Discriminator},
+        Attestation, Credential, Schema,
+    },
+};
+
+#[inline(always)]
+pub fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [payer_info, account_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: payer should have signed
+    verify_signer(payer_info, true)?;
+    // Validate: system program
+    verify_system_program(system_program)?;
+    // Verify program ownership and mutability.
+    verify_owner_mutability(account_info, program_id, true)?;
+
+    // Fields missing from older layouts are read as their defaults, so
+    // rewriting the account brings it to the current layout.
+    let data = account_info.try_borrow_data()?;
+    let account_bytes = match data.first() {
+        Some(&Credential::DISCRIMINATOR) => Credential::try_from_bytes(&data)?.to_bytes(),
+        Some(&Schema::DISCRIMINATOR) => Schema::try_from_bytes(&data)?.to_bytes(),
+        Some(&Attestation::DISCRIMINATOR) => Attestation::try_from_bytes(&data)?.to_bytes(),
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+    drop(data); // Drop immutable borrow.
+
+    write_account_data(account_info, Some(payer_info), &account_bytes)
+}
@@ -1,27 +1,67 @@
+pub mod accept_credential_authority;
 pub mod change_authorized_signers;
+pub mod change_credential_admins;
 pub mod change_schema_description;
 pub mod change_schema_status;
 pub mod change_schema_version;
+pub mod change_signer_permissions;
 pub mod close_attestation;
+pub mod close_attestations_batch;
+pub mod close_credential;
+pub mod close_expired_attestation;
+pub mod close_schema;
+pub mod close_signature_receipt;
 pub mod close_tokenized_attestation;
 pub mod create_attestation;
+pub mod create_attestation_tree;
+pub mod create_attestation_with_signature;
+pub mod create_attestations_batch;
+pub mod create_compressed_attestation;
 pub mod create_credential;
 pub mod create_schema;
 pub mod create_tokenized_attestation;
 pub mod emit_event;
+pub mod migrate_account;
+pub mod propose_credential_authority;
+pub mod renew_attestation;
+pub mod revoke_attestation;
+pub mod revoke_compressed_attestation;
 pub mod shared;
 pub mod tokenize_schema;
+pub mod update_attestation;
+pub mod verify_attestation;
+pub mod verify_attestation_predicate;
 
+pub use accept_credential_authority::*;
 pub use change_authorized_signers::*;
+pub use change_credential_admins::*;
 pub use change_schema_description::*;
 pub use change_schema_status::*;
 pub use change_schema_version::*;
+pub use change_signer_permissions::*;
 pub use close_attestation::*;
+pub use close_attestations_batch::*;
+pub use close_credential::*;
+pub use close_expired_attestation::*;
+pub use close_schema::*;
+pub use close_signature_receipt::*;
 pub use close_tokenized_attestation::*;
 pub use create_attestation::*;
+pub use create_attestation_tree::*;
+pub use create_attestation_with_signature::*;
+pub use create_attestations_batch::*;
+pub use create_compressed_attestation::*;
 pub use create_credential::*;
 pub use create_schema::*;
 pub use create_tokenized_attestation::*;
 pub use emit_event::*;
+pub use migrate_account::*;
+pub use propose_credential_authority::*;
+pub use renew_attestation::*;
+pub use revoke_attestation::*;
+pub use revoke_compressed_attestation::*;
 pub use shared::*;
 pub use tokenize_schema::*;
+pub use update_attestation::*;
+pub use verify_attestation::*;
+pub use verify_attestation_predicate::*;
@@ -0,0 +1,53 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{
+    processor::{
+        verify_credential_admin, verify_owner_mutability, verify_signer, write_account_data,
+    },
+    require_len,
+    state::{discriminator::AccountSerialize, Credential},
+};
+
+#[inline(always)]
+pub fn process_propose_credential_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [authority_info, credential_info, co_signers @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authority_info, false)?;
+
+    // Verify program ownership, mutability and PDAs.
+    verify_owner_mutability(credential_info, program_id, true)?;
+
+    let credential_data = credential_info.try_borrow_data()?;
+    let mut credential = Credential::try_from_bytes(&credential_data)?;
+    drop(credential_data); // Drop immutable borrow.
+
+    // Verify the Credential's admins approved the change.
+    verify_credential_admin(&credential, authority_info, co_signers)?;
+
+    // A default key cancels any outstanding proposal.
+    credential.pending_authority = args.new_authority;
+    write_account_data(credential_info, None, &credential.to_bytes())?;
+
+    Ok(())
+}
+
+struct ProposeCredentialAuthorityArgs {
+    new_authority: Pubkey,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<ProposeCredentialAuthorityArgs, ProgramError> {
+    require_len!(data, 32);
+    let new_authority: Pubkey = data[0..32].try_into().unwrap();
+
+    Ok(ProposeCredentialAuthorityArgs { new_authority })
+}
@@ -0,0 +1,100 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    error::AttestationServiceError,
+    events::{EventDiscriminators, RenewAttestationEvent},
+    processor::{
+        invoke_emit_event, verify_current_program, verify_owner_mutability, verify_signer,
+        write_account_data,
+    },
+    require_len,
+    state::{discriminator::AccountSerialize, Attestation, Credential, SignerPermission},
+};
+
+#[inline(always)]
+pub fn process_renew_attestation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [authorized_signer, credential_info, attestation_info, event_authority_info, attestation_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authorized_signer, false)?;
+
+    // Verify attestation program
+    verify_current_program(attestation_program)?;
+
+    // Validate Credential and Attestation are owned by our program
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(attestation_info, program_id, true)?;
+
+    let credential = &Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
+
+    let attestation_data = attestation_info.try_borrow_data()?;
+    let mut attestation = Attestation::try_from_bytes(&attestation_data)?;
+    drop(attestation_data); // Drop immutable borrow.
+
+    // Check that credential matches attestation's.
+    if attestation.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+
+    // Check that one of credential's authorized signers may update under the
+    // attestation's Schema.
+    credential.validate_authorized_signer(
+        authorized_signer.key(),
+        &attestation.schema,
+        SignerPermission::UPDATE,
+    )?;
+
+    // Revoked Attestations cannot be brought back into validity.
+    if attestation.is_revoked() {
+        return Err(AttestationServiceError::AttestationRevoked.into());
+    }
+
+    // Validate expiry is greater than current timestamp
+    let clock = Clock::get()?;
+    if args.expiry < clock.unix_timestamp && args.expiry != 0 {
+        return Err(AttestationServiceError::InvalidAttestationData.into());
+    }
+
+    let old_expiry = attestation.expiry;
+    attestation.expiry = args.expiry;
+    attestation.updated_at = clock.unix_timestamp;
+
+    write_account_data(attestation_info, None, &attestation.to_bytes())?;
+
+    let event = RenewAttestationEvent {
+        discriminator: EventDiscriminators::RenewEvent as u8,
+        schema: attestation.schema,
+        attestation: *attestation_info.key(),
+        old_expiry,
+        new_expiry: args.expiry,
+    };
+    invoke_emit_event(program_id, event_authority_info, &event.to_bytes())?;
+
+    Ok(())
+}
+
+struct RenewAttestationArgs {
+    expiry: i64,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<RenewAttestationArgs, ProgramError> {
+    require_len!(data, 8);
+    let expiry = i64::from_le_bytes(data[0..8].try_into().unwrap());
+
+    Ok(RenewAttestationArgs { expiry })
+}
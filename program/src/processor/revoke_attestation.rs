@@ -0,0 +1,78 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    error::AttestationServiceError,
+    processor::{verify_owner_mutability, verify_signer, write_account_data},
+    require_len,
+    state::{discriminator::AccountSerialize, Attestation, Credential, SignerPermission},
+};
+
+#[inline(always)]
+pub fn process_revoke_attestation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [authorized_signer, credential_info, attestation_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authorized_signer, false)?;
+
+    // Validate Credential and Attestation are owned by our program
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(attestation_info, program_id, true)?;
+
+    let credential = &Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
+
+    let attestation_data = attestation_info.try_borrow_data()?;
+    let mut attestation = Attestation::try_from_bytes(&attestation_data)?;
+    drop(attestation_data); // Drop immutable borrow.
+
+    // Check that credential matches attestation's.
+    if attestation.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+
+    // Check that one of credential's authorized signers may revoke under the
+    // attestation's Schema.
+    credential.validate_authorized_signer(
+        authorized_signer.key(),
+        &attestation.schema,
+        SignerPermission::CLOSE,
+    )?;
+
+    // Revocation is final, the original revocation record is kept.
+    if attestation.is_revoked() {
+        return Err(AttestationServiceError::AttestationRevoked.into());
+    }
+
+    let clock = Clock::get()?;
+    attestation.revoked_at = clock.unix_timestamp;
+    attestation.revoked_by = *authorized_signer.key();
+    attestation.revocation_reason = args.reason;
+    attestation.updated_at = clock.unix_timestamp;
+
+    write_account_data(attestation_info, None, &attestation.to_bytes())?;
+
+    Ok(())
+}
+
+struct RevokeAttestationArgs {
+    reason: u8,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<RevokeAttestationArgs, ProgramError> {
+    require_len!(data, 1);
+    let reason = data[0];
+
+    Ok(RevokeAttestationArgs { reason })
+}
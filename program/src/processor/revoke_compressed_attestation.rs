@@ -0,0 +1,136 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    error::AttestationServiceError,
+    events::{CompressedAttestationEvent, EventDiscriminators},
+    require_len,
+    state::{
+        discriminator::AccountSerialize, AttestationTree, CompressedAttestation, Credential, Node,
+        SignerPermission,
+    },
+};
+
+use super::{invoke_emit_event, verify_current_program, verify_owner_mutability, verify_signer};
+
+#[inline(always)]
+pub fn process_revoke_compressed_attestation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [authorized_signer, credential_info, attestation_tree_info, event_authority_info, attestation_program, proof_infos @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authorized_signer, false)?;
+
+    // Verify attestation program
+    verify_current_program(attestation_program)?;
+
+    // Validate Credential and AttestationTree are owned by our program
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(attestation_tree_info, program_id, true)?;
+
+    let credential = Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
+
+    let old_attestation = args.attestation;
+
+    // Check that credential matches attestation's.
+    if old_attestation.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+
+    // Check that one of credential's authorized signers may revoke under the
+    // attestation's Schema.
+    credential.validate_authorized_signer(
+        authorized_signer.key(),
+        &old_attestation.schema,
+        SignerPermission::CLOSE,
+    )?;
+
+    // Revocation is final, the original revocation record is kept.
+    if old_attestation.is_revoked() {
+        return Err(AttestationServiceError::AttestationRevoked.into());
+    }
+
+    let mut attestation_tree_data = attestation_tree_info.try_borrow_mut_data()?;
+    let mut attestation_tree = AttestationTree::try_from_bytes(&attestation_tree_data)?;
+
+    // Check that the tree holds Attestations of the leaf's Schema.
+    if attestation_tree.schema.ne(&old_attestation.schema) {
+        return Err(AttestationServiceError::InvalidSchema.into());
+    }
+
+    let clock = Clock::get()?;
+    let mut attestation = old_attestation.clone();
+    attestation.revoked_at = clock.unix_timestamp;
+    attestation.revoked_by = *authorized_signer.key();
+    attestation.revocation_reason = args.reason;
+
+    // Proof nodes are passed as accounts, ordered from the leaf's sibling up.
+    let mut proof: Vec<Node> = proof_infos.iter().map(|info| *info.key()).collect();
+    attestation_tree.replace(
+        &args.root,
+        args.leaf_index,
+        &old_attestation.hash(),
+        &attestation.hash(),
+        &mut proof,
+    )?;
+    attestation_tree_data.copy_from_slice(&attestation_tree.to_bytes());
+    drop(attestation_tree_data);
+
+    let event = CompressedAttestationEvent {
+        discriminator: EventDiscriminators::CompressedEvent as u8,
+        tree: *attestation_tree_info.key(),
+        leaf_index: args.leaf_index,
+        sequence: attestation_tree.sequence,
+        attestation,
+    };
+    invoke_emit_event(program_id, event_authority_info, &event.to_bytes())?;
+
+    Ok(())
+}
+
+struct RevokeCompressedAttestationArgs {
+    root: Node,
+    leaf_index: u32,
+    attestation: CompressedAttestation,
+    reason: u8,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<RevokeCompressedAttestationArgs, ProgramError> {
+    let mut offset: usize = 0;
+
+    require_len!(data, offset + 32 + 4);
+    let root: Node = data[offset..offset + 32].try_into().unwrap();
+    offset += 32;
+
+    let leaf_index = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+    offset += 4;
+
+    let (attestation, read) = CompressedAttestation::try_from_bytes(&data[offset..])?;
+    offset += read;
+
+    require_len!(data, offset + 1);
+    let reason = data[offset];
+
+    Ok(RevokeCompressedAttestationArgs {
+        root,
+        leaf_index,
+        attestation,
+        reason,
+    })
+}
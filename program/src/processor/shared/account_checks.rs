@@ -1,10 +1,16 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
 use bs58;
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey,
+    sysvars::instructions::INSTRUCTIONS_ID,
+};
 use pinocchio_associated_token_account::ID as ATA_PROGRAM_ID;
 use pinocchio_log::log;
 use pinocchio_token::TOKEN_2022_PROGRAM_ID;
 
-use crate::{acc_info_as_str, key_as_str, ID};
+use crate::{acc_info_as_str, error::AttestationServiceError, key_as_str, state::Credential, ID};
 
 /// Verify account as a signer, returning an error if it is not or if it is not writable while
 /// expected to be.
@@ -135,6 +141,25 @@ pub fn verify_current_program(info: &AccountInfo) -> Result<(), ProgramError> {
     Ok(())
 }
 
+/// Verify account as the Instructions sysvar, returning an error if it is not.
+///
+/// # Arguments
+/// * `info` - The account to verify.
+///
+/// # Returns
+/// * `Result<(), ProgramError>` - The result of the operation
+pub fn verify_instructions_sysvar(info: &AccountInfo) -> Result<(), ProgramError> {
+    if info.key().ne(&INSTRUCTIONS_ID) {
+        log!(
+            "Account {} is not the Instructions sysvar",
+            acc_info_as_str!(info)
+        );
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    Ok(())
+}
+
 /// Verify account's owner and account mutability.
 ///
 /// # Arguments
@@ -167,3 +192,92 @@ pub fn verify_owner_mutability(
 
     Ok(())
 }
+
+/// Verify the Credential's admins approved an admin instruction. A Credential
+/// without admins is administered by its authority alone; otherwise at least
+/// `admin_threshold` distinct admins must sign, either as the authority
+/// account or among the co-signers.
+///
+/// # Arguments
+/// * `credential` - The Credential being administered.
+/// * `authority_info` - The signing authority account of the instruction.
+/// * `co_signers` - Additional accounts that may carry admin signatures.
+///
+/// # Returns
+/// * `Result<(), ProgramError>` - The result of the operation
+pub fn verify_credential_admin(
+    credential: &Credential,
+    authority_info: &AccountInfo,
+    co_signers: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    if credential.admin_threshold == 0 {
+        if credential.authority.ne(authority_info.key()) {
+            return Err(ProgramError::IncorrectAuthority);
+        }
+        return Ok(());
+    }
+
+    let mut approvals: Vec<&Pubkey> = Vec::with_capacity(credential.admins.len());
+    for info in core::iter::once(authority_info).chain(co_signers) {
+        if info.is_signer()
+            && credential.admins.contains(info.key())
+            && !approvals.contains(&info.key())
+        {
+            approvals.push(info.key());
+        }
+    }
+    if approvals.len() < credential.admin_threshold as usize {
+        log!(
+            "{} of {} required admin signatures",
+            approvals.len(),
+            credential.admin_threshold
+        );
+        return Err(AttestationServiceError::InsufficientAdminSignatures.into());
+    }
+
+    Ok(())
+}
+
+/// Account the rent of a closed account is returned to: its stored payer, or
+/// the Credential authority for accounts created before the payer was stored.
+///
+/// # Arguments
+/// * `funder` - The payer stored on the account being closed.
+/// * `credential` - The Credential the closed account belongs to.
+///
+/// # Returns
+/// * `&Pubkey` - The account entitled to the rent
+pub fn rent_funder<'a>(funder: &'a Pubkey, credential: &'a Credential) -> &'a Pubkey {
+    if funder.eq(&Pubkey::default()) {
+        &credential.authority
+    } else {
+        funder
+    }
+}
+
+/// Verify the rent reclaimed by a close goes to the account that funded it,
+/// see `rent_funder`. Only an approval that passes `verify_credential_admin` may direct the rent
+/// elsewhere.
+///
+/// # Arguments
+/// * `funder` - The payer stored on the account being closed.
+/// * `payer_info` - The account receiving the rent.
+/// * `credential` - The Credential the closed account belongs to.
+/// * `authority_info` - The signing authority account of the instruction.
+/// * `co_signers` - Additional accounts that may carry admin signatures.
+///
+/// # Returns
+/// * `Result<(), ProgramError>` - The result of the operation
+pub fn verify_rent_recipient(
+    funder: &Pubkey,
+    payer_info: &AccountInfo,
+    credential: &Credential,
+    authority_info: &AccountInfo,
+    co_signers: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    if rent_funder(funder, credential).eq(payer_info.key()) {
+        return Ok(());
+    }
+    verify_credential_admin(credential, authority_info, co_signers)
+        .map_err(|_| AttestationServiceError::InvalidPayer.into())
+}
@@ -0,0 +1,396 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::program_error::ProgramError;
+
+use crate::{
+    error::AttestationServiceError,
+    state::{get_attestation_field, get_size_of_field, split_layout, SchemaDataTypes},
+};
+
+use super::{decode_value, FieldValue};
+
+/// Kinds of field constraints. Constraints are stored in the Schema one after
+/// the other, each encoded as field index (u8), kind (u8), then:
+///
+/// - `Min`/`Max`: the bound, encoded like the field. Numeric fields only.
+/// - `MaxLen`: the maximum length (u32), in bytes for Strings and elements for
+///   Vecs.
+/// - `NonEmpty`: nothing. String and Vec fields only.
+/// - `OneOf`: count (u8), then `count` allowed values encoded like the field.
+///   Not supported on Vec, Option or float fields.
+/// - `NotBefore`/`NotAfter`: an offset in seconds (i64) from the Clock's unix
+///   timestamp when the data is attested. Timestamp fields only.
+#[repr(u8)]
+pub enum ConstraintKind {
+    Min = 0,
+    Max = 1,
+    MaxLen = 2,
+    NonEmpty = 3,
+    OneOf = 4,
+    NotBefore = 5,
+    NotAfter = 6,
+}
+
+impl TryFrom<u8> for ConstraintKind {
+    type Error = ProgramError;
+
+    fn try_from(byte: u8) -> Result<Self, Self::Error> {
+        match byte {
+            0 => Ok(ConstraintKind::Min),
+            1 => Ok(ConstraintKind::Max),
+            2 => Ok(ConstraintKind::MaxLen),
+            3 => Ok(ConstraintKind::NonEmpty),
+            4 => Ok(ConstraintKind::OneOf),
+            5 => Ok(ConstraintKind::NotBefore),
+            6 => Ok(ConstraintKind::NotAfter),
+            _ => Err(AttestationServiceError::InvalidFieldConstraint.into()),
+        }
+    }
+}
+
+enum Constraint<'a> {
+    Min(FieldValue<'a>),
+    Max(FieldValue<'a>),
+    MaxLen(u32),
+    NonEmpty,
+    OneOf(Vec<FieldValue<'a>>),
+    NotBefore(i64),
+    NotAfter(i64),
+}
+
+/// Whether `data_type` is an integer, float, Decimal or Timestamp.
+fn is_numeric(data_type: u8) -> bool {
+    matches!(
+        SchemaDataTypes::from(data_type),
+        SchemaDataTypes::U8
+            | SchemaDataTypes::U16
+            | SchemaDataTypes::U32
+            | SchemaDataTypes::U64
+            | SchemaDataTypes::U128
+            | SchemaDataTypes::I8
+            | SchemaDataTypes::I16
+            | SchemaDataTypes::I32
+            | SchemaDataTypes::I64
+            | SchemaDataTypes::I128
+            | SchemaDataTypes::F32
+            | SchemaDataTypes::F64
+            | SchemaDataTypes::Decimal
+            | SchemaDataTypes::Timestamp
+    )
+}
+
+/// Whether `data_type` is a single value with exact equality: an integer,
+/// bool, char, String, Pubkey, byte array, Decimal, Timestamp or Enum.
+fn is_single_value(data_type: u8) -> bool {
+    data_type <= SchemaDataTypes::String as u8
+        || matches!(
+            SchemaDataTypes::from(data_type),
+            SchemaDataTypes::Pubkey
+                | SchemaDataTypes::Bytes32
+                | SchemaDataTypes::Bytes64
+                | SchemaDataTypes::Decimal
+                | SchemaDataTypes::Timestamp
+                | SchemaDataTypes::Enum
+        )
+}
+
+/// Whether values of `data_type` start with a u32 length.
+fn has_length(data_type: u8) -> bool {
+    (SchemaDataTypes::String as u8..=SchemaDataTypes::VecString as u8).contains(&data_type)
+        || data_type == SchemaDataTypes::VecStruct as u8
+}
+
+struct ConstraintReader<'a> {
+    constraints: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> ConstraintReader<'a> {
+    fn is_empty(&self) -> bool {
+        self.offset == self.constraints.len()
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], ProgramError> {
+        let bytes = self
+            .constraints
+            .get(self.offset..self.offset + len)
+            .ok_or(AttestationServiceError::InvalidFieldConstraint)?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, ProgramError> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_i64(&mut self) -> Result<i64, ProgramError> {
+        Ok(i64::from_le_bytes(self.read_bytes(8)?.try_into().unwrap()))
+    }
+
+    /// Read an operand encoded as a value of the type `descriptor`.
+    fn read_operand(&mut self, descriptor: &[u8]) -> Result<FieldValue<'a>, ProgramError> {
+        let size = get_size_of_field(descriptor, self.constraints, self.offset)
+            .map_err(|_| AttestationServiceError::InvalidFieldConstraint)?;
+        let bytes = self.read_bytes(size)?;
+        Ok(decode_value(descriptor[0], bytes))
+    }
+
+    /// Read the next constraint, checking it applies to the type of its field
+    /// in the Schema's layout `descriptors`. Returns the field index and the
+    /// constraint.
+    fn read_constraint(
+        &mut self,
+        descriptors: &[&[u8]],
+    ) -> Result<(usize, Constraint<'a>), ProgramError> {
+        let index = self.read_u8()? as usize;
+        let descriptor = *descriptors
+            .get(index)
+            .ok_or(AttestationServiceError::InvalidFieldConstraint)?;
+        let data_type = descriptor[0];
+
+        let constraint = match ConstraintKind::try_from(self.read_u8()?)? {
+            ConstraintKind::Min if is_numeric(data_type) => {
+                Constraint::Min(self.read_operand(descriptor)?)
+            }
+            ConstraintKind::Max if is_numeric(data_type) => {
+                Constraint::Max(self.read_operand(descriptor)?)
+            }
+            ConstraintKind::MaxLen if has_length(data_type) => {
+                let max_len = self.read_bytes(4)?;
+                Constraint::MaxLen(u32::from_le_bytes(max_len.try_into().unwrap()))
+            }
+            ConstraintKind::NonEmpty if has_length(data_type) => Constraint::NonEmpty,
+            ConstraintKind::OneOf if is_single_value(data_type) => {
+                let count = self.read_u8()?;
+                let mut values = Vec::with_capacity(count as usize);
+                for _ in 0..count {
+                    values.push(self.read_operand(descriptor)?);
+                }
+                Constraint::OneOf(values)
+            }
+            ConstraintKind::NotBefore if data_type == SchemaDataTypes::Timestamp as u8 => {
+                Constraint::NotBefore(self.read_i64()?)
+            }
+            ConstraintKind::NotAfter if data_type == SchemaDataTypes::Timestamp as u8 => {
+                Constraint::NotAfter(self.read_i64()?)
+            }
+            _ => return Err(AttestationServiceError::InvalidFieldConstraint.into()),
+        };
+
+        Ok((index, constraint))
+    }
+}
+
+/// Validate the encoded field `constraints` of a Schema with `layout`.
+pub fn validate_constraints(constraints: &[u8], layout: &[u8]) -> Result<(), ProgramError> {
+    let mut reader = ConstraintReader {
+        constraints,
+        offset: 0,
+    };
+    let descriptors = split_layout(layout)?;
+    while !reader.is_empty() {
+        reader.read_constraint(&descriptors)?;
+    }
+    Ok(())
+}
+
+/// Check attestation `data`, already validated against `layout`, satisfies
+/// every field constraint of the Schema. Timestamps are checked against the
+/// Clock's `unix_timestamp`.
+pub fn check_constraints(
+    constraints: &[u8],
+    data: &[u8],
+    layout: &[u8],
+    unix_timestamp: i64,
+) -> Result<(), ProgramError> {
+    let mut reader = ConstraintReader {
+        constraints,
+        offset: 0,
+    };
+    let descriptors = split_layout(layout)?;
+    while !reader.is_empty() {
+        let (index, constraint) = reader.read_constraint(&descriptors)?;
+        let (descriptor, field) = get_attestation_field(data, &descriptors, index)?;
+        let value = decode_value(descriptor[0], field);
+
+        let satisfied = match constraint {
+            Constraint::Min(min) => value >= min,
+            Constraint::Max(max) => value <= max,
+            Constraint::MaxLen(max_len) => {
+                u32::from_le_bytes(field[..4].try_into().unwrap()) <= max_len
+            }
+            Constraint::NonEmpty => field[..4] != [0; 4],
+            Constraint::OneOf(values) => values.contains(&value),
+            Constraint::NotBefore(offset) => {
+                value >= FieldValue::Signed(unix_timestamp.saturating_add(offset).into())
+            }
+            Constraint::NotAfter(offset) => {
+                value <= FieldValue::Signed(unix_timestamp.saturating_add(offset).into())
+            }
+        };
+        if !satisfied {
+            return Err(AttestationServiceError::FieldConstraintViolated.into());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use crate::processor::to_serialized_vec;
+
+    use super::*;
+
+    // age: u8, name: String, scores: Vec<i32>
+    const LAYOUT: [u8; 3] = [0, 12, 20];
+
+    fn data(age: u8, name: &[u8], scores: &[i32]) -> Vec<u8> {
+        let mut data = Vec::from([age]);
+        data.extend(to_serialized_vec(name));
+        data.extend((scores.len() as u32).to_le_bytes());
+        for score in scores {
+            data.extend(score.to_le_bytes());
+        }
+        data
+    }
+
+    fn constraints() -> Vec<u8> {
+        let mut constraints = Vec::new();
+        // 18 <= age <= 120
+        constraints.extend([0, ConstraintKind::Min as u8, 18]);
+        constraints.extend([0, ConstraintKind::Max as u8, 120]);
+        // name is non-empty and at most 8 bytes
+        constraints.extend([1, ConstraintKind::NonEmpty as u8]);
+        constraints.extend([1, ConstraintKind::MaxLen as u8]);
+        constraints.extend(8u32.to_le_bytes());
+        // at most 2 scores
+        constraints.extend([2, ConstraintKind::MaxLen as u8]);
+        constraints.extend(2u32.to_le_bytes());
+        constraints
+    }
+
+    #[test]
+    fn constraints_check() {
+        let constraints = constraints();
+        assert!(validate_constraints(&constraints, &LAYOUT).is_ok());
+        assert!(validate_constraints(&[], &LAYOUT).is_ok());
+
+        let valid = data(21, b"alice", &[1, 2]);
+        assert!(check_constraints(&constraints, &valid, &LAYOUT, 0).is_ok());
+
+        for invalid in [
+            data(17, b"alice", &[1]),
+            data(121, b"alice", &[1]),
+            data(21, b"", &[1]),
+            data(21, b"alice in wonderland", &[1]),
+            data(21, b"alice", &[1, 2, 3]),
+        ] {
+            assert_eq!(
+                check_constraints(&constraints, &invalid, &LAYOUT, 0),
+                Err(AttestationServiceError::FieldConstraintViolated.into())
+            );
+        }
+
+        // name in [alice, bob]
+        let mut one_of = Vec::from([1, ConstraintKind::OneOf as u8, 2]);
+        one_of.extend(to_serialized_vec(b"alice"));
+        one_of.extend(to_serialized_vec(b"bob"));
+        assert!(validate_constraints(&one_of, &LAYOUT).is_ok());
+        assert!(check_constraints(&one_of, &valid, &LAYOUT, 0).is_ok());
+        let carol = data(21, b"carol", &[]);
+        assert!(check_constraints(&one_of, &carol, &LAYOUT, 0).is_err());
+    }
+
+    #[test]
+    fn constraints_validate_fail_invalid() {
+        // Unknown field
+        assert!(validate_constraints(&[3, ConstraintKind::NonEmpty as u8], &LAYOUT).is_err());
+        // Unknown kind
+        assert!(validate_constraints(&[0, 9], &LAYOUT).is_err());
+        // Min on a String
+        let mut min_name = Vec::from([1, ConstraintKind::Min as u8]);
+        min_name.extend(to_serialized_vec(b"a"));
+        assert!(validate_constraints(&min_name, &LAYOUT).is_err());
+        // MaxLen on an integer
+        let mut max_len_age = Vec::from([0, ConstraintKind::MaxLen as u8]);
+        max_len_age.extend(8u32.to_le_bytes());
+        assert!(validate_constraints(&max_len_age, &LAYOUT).is_err());
+        // OneOf on a Vec
+        assert!(validate_constraints(&[2, ConstraintKind::OneOf as u8, 0], &LAYOUT).is_err());
+        // Truncated bound
+        assert!(validate_constraints(&[0, ConstraintKind::Min as u8], &LAYOUT).is_err());
+    }
+
+    #[test]
+    fn constraints_check_one_of_pubkey() {
+        // owner: Pubkey
+        let layout = [SchemaDataTypes::Pubkey as u8];
+        let mut constraints = Vec::from([0, ConstraintKind::OneOf as u8, 2]);
+        constraints.extend([1; 32]);
+        constraints.extend([2; 32]);
+        assert!(validate_constraints(&constraints, &layout).is_ok());
+
+        assert!(check_constraints(&constraints, &[2; 32], &layout, 0).is_ok());
+        assert_eq!(
+            check_constraints(&constraints, &[3; 32], &layout, 0),
+            Err(AttestationServiceError::FieldConstraintViolated.into())
+        );
+    }
+
+    #[test]
+    fn constraints_check_timestamp() {
+        // expiry: Timestamp, score: Decimal with 2 places, ratio: f64
+        let layout = [
+            SchemaDataTypes::Timestamp as u8,
+            SchemaDataTypes::Decimal as u8,
+            2,
+            SchemaDataTypes::F64 as u8,
+        ];
+        let now = 1_700_000_000i64;
+        let mut constraints = Vec::new();
+        // not expired, and at most a day from now
+        constraints.extend([0, ConstraintKind::NotBefore as u8]);
+        constraints.extend(0i64.to_le_bytes());
+        constraints.extend([0, ConstraintKind::NotAfter as u8]);
+        constraints.extend(86_400i64.to_le_bytes());
+        // score >= -1.50
+        constraints.extend([1, ConstraintKind::Min as u8]);
+        constraints.extend((-150i128).to_le_bytes());
+        // ratio <= 0.5
+        constraints.extend([2, ConstraintKind::Max as u8]);
+        constraints.extend(0.5f64.to_le_bytes());
+        assert!(validate_constraints(&constraints, &layout).is_ok());
+
+        let data = |expiry: i64, score: i128, ratio: f64| {
+            let mut data = Vec::from(expiry.to_le_bytes());
+            data.extend(score.to_le_bytes());
+            data.extend(ratio.to_le_bytes());
+            data
+        };
+        assert!(check_constraints(&constraints, &data(now + 60, -150, 0.5), &layout, now).is_ok());
+        for invalid in [
+            data(now - 1, 0, 0.0),
+            data(now + 86_401, 0, 0.0),
+            data(now, -151, 0.0),
+            data(now, 0, 0.75),
+            data(now, 0, f64::NAN),
+        ] {
+            assert_eq!(
+                check_constraints(&constraints, &invalid, &layout, now),
+                Err(AttestationServiceError::FieldConstraintViolated.into())
+            );
+        }
+
+        // NotBefore on a Decimal
+        let mut not_before = Vec::from([1, ConstraintKind::NotBefore as u8]);
+        not_before.extend(0i64.to_le_bytes());
+        assert!(validate_constraints(&not_before, &layout).is_err());
+        // OneOf on a float
+        assert!(validate_constraints(&[2, ConstraintKind::OneOf as u8, 0], &layout).is_err());
+    }
+}
@@ -1,8 +1,47 @@
 extern crate alloc;
 
 use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
+
+use crate::error::AttestationServiceError;
 
 // Serializes an array of bytes to Vector representation by prepending array length.
 pub fn to_serialized_vec(data: &[u8]) -> Vec<u8> {
     [(data.len() as u32).to_le_bytes().as_slice(), data].concat()
 }
+
+/// Write serialized account `data` to `account_info`. Accounts created with
+/// an older, shorter layout are resized first, with `payer_info` funding any
+/// additional rent. Without a payer such accounts must be migrated first.
+pub fn write_account_data(
+    account_info: &AccountInfo,
+    payer_info: Option<&AccountInfo>,
+    data: &[u8],
+) -> ProgramResult {
+    if data.len() != account_info.data_len() {
+        let Some(payer_info) = payer_info else {
+            return Err(AttestationServiceError::AccountNotMigrated.into());
+        };
+        account_info.realloc(data.len(), false)?;
+
+        // top up lamports to account for additional rent.
+        let min_rent = Rent::get()?.minimum_balance(data.len());
+        let current_rent = account_info.lamports();
+        if min_rent > current_rent {
+            Transfer {
+                from: payer_info,
+                to: account_info,
+                lamports: min_rent - current_rent,
+            }
+            .invoke()?;
+        }
+    }
+
+    account_info.try_borrow_mut_data()?.copy_from_slice(data);
+    Ok(())
+}
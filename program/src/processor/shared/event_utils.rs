@@ -0,0 +1,45 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Seed, Signer},
+    program::invoke_signed,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::{
+    constants::{event_authority_pda, EVENT_AUTHORITY_SEED},
+    error::AttestationServiceError,
+};
+
+/// CPI to emit_event ix on same program to store event data in ix arg.
+///
+/// # Arguments
+/// * `program_id` - The current program ID.
+/// * `event_authority_info` - The event authority PDA account.
+/// * `event_data` - The serialized event, including the emit_event discriminator.
+///
+/// # Returns
+/// * `ProgramResult` - The result of the operation
+pub fn invoke_emit_event(
+    program_id: &Pubkey,
+    event_authority_info: &AccountInfo,
+    event_data: &[u8],
+) -> ProgramResult {
+    // Check that event authority PDA is valid.
+    if event_authority_info.key().ne(&event_authority_pda::ID) {
+        return Err(AttestationServiceError::InvalidEventAuthority.into());
+    }
+
+    invoke_signed(
+        &Instruction {
+            program_id,
+            accounts: &[AccountMeta::new(event_authority_info.key(), false, true)],
+            data: event_data,
+        },
+        &[event_authority_info],
+        &[Signer::from(&[
+            Seed::from(EVENT_AUTHORITY_SEED),
+            Seed::from(&[event_authority_pda::BUMP]),
+        ])],
+    )
+}
@@ -1,7 +1,15 @@
 pub mod account_checks;
+pub mod constraint_utils;
 pub mod data_utils;
+pub mod event_utils;
 pub mod pda_utils;
+pub mod predicate_utils;
+pub mod signature_utils;
 
 pub use account_checks::*;
+pub use constraint_utils::*;
 pub use data_utils::*;
+pub use event_utils::*;
 pub use pda_utils::*;
+pub use predicate_utils::*;
+pub use signature_utils::*;
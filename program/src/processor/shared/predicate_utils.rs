@@ -0,0 +1,341 @@
+use core::cmp::Ordering;
+
+use pinocchio::program_error::ProgramError;
+
+use crate::{
+    error::AttestationServiceError,
+    state::{get_attestation_field, get_size_of_field, split_layout, SchemaDataTypes},
+};
+
+/// Nested predicates deeper than this are rejected to bound stack usage.
+pub const MAX_PREDICATE_DEPTH: u8 = 8;
+
+/// Predicate opcodes. A predicate is encoded in prefix form:
+///
+/// - `And`/`Or`: opcode, count (u8), then `count` predicates
+/// - `Not`: opcode, then one predicate
+/// - `Compare`: opcode, field index (u8), CompareOp (u8), then the operand
+/// - `In`: opcode, field index (u8), count (u8), then `count` operands
+///
+/// Operands are encoded like the field's value in Attestation data.
+#[repr(u8)]
+pub enum PredicateOp {
+    And = 0,
+    Or = 1,
+    Not = 2,
+    Compare = 3,
+    In = 4,
+}
+
+impl TryFrom<u8> for PredicateOp {
+    type Error = ProgramError;
+
+    fn try_from(byte: u8) -> Result<Self, Self::Error> {
+        match byte {
+            0 => Ok(PredicateOp::And),
+            1 => Ok(PredicateOp::Or),
+            2 => Ok(PredicateOp::Not),
+            3 => Ok(PredicateOp::Compare),
+            4 => Ok(PredicateOp::In),
+            _ => Err(AttestationServiceError::InvalidPredicate.into()),
+        }
+    }
+}
+
+#[repr(u8)]
+pub enum CompareOp {
+    Eq = 0,
+    Ne = 1,
+    Lt = 2,
+    Le = 3,
+    Gt = 4,
+    Ge = 5,
+}
+
+impl TryFrom<u8> for CompareOp {
+    type Error = ProgramError;
+
+    fn try_from(byte: u8) -> Result<Self, Self::Error> {
+        match byte {
+            0 => Ok(CompareOp::Eq),
+            1 => Ok(CompareOp::Ne),
+            2 => Ok(CompareOp::Lt),
+            3 => Ok(CompareOp::Le),
+            4 => Ok(CompareOp::Gt),
+            5 => Ok(CompareOp::Ge),
+            _ => Err(AttestationServiceError::InvalidPredicate.into()),
+        }
+    }
+}
+
+/// Decoded field value. Only values of the same kind are comparable.
+#[derive(PartialEq, PartialOrd)]
+pub enum FieldValue<'a> {
+    Unsigned(u128),
+    Signed(i128),
+    /// f32 and f64 values, NaN is unordered and unequal to everything
+    Float(f64),
+    Bool(bool),
+    /// Contents of a String, compared lexicographically
+    Text(&'a [u8]),
+    /// Encoded bytes of a Vec, only comparable for equality
+    Raw(&'a [u8]),
+}
+
+/// Decode the encoded `bytes` of a value of `data_type`.
+pub fn decode_value(data_type: u8, bytes: &[u8]) -> FieldValue<'_> {
+    let mut buf = [0u8; 16];
+    let schema_data_type: SchemaDataTypes = data_type.into();
+    match schema_data_type {
+        SchemaDataTypes::U8
+        | SchemaDataTypes::U16
+        | SchemaDataTypes::U32
+        | SchemaDataTypes::U64
+        | SchemaDataTypes::U128
+        | SchemaDataTypes::Char
+        | SchemaDataTypes::Enum => {
+            buf[..bytes.len()].copy_from_slice(bytes);
+            FieldValue::Unsigned(u128::from_le_bytes(buf))
+        }
+        SchemaDataTypes::I8
+        | SchemaDataTypes::I16
+        | SchemaDataTypes::I32
+        | SchemaDataTypes::I64
+        | SchemaDataTypes::I128
+        | SchemaDataTypes::Timestamp
+        | SchemaDataTypes::Decimal => {
+            // Sign extend to 128 bits.
+            if bytes[bytes.len() - 1] & 0x80 != 0 {
+                buf = [0xff; 16];
+            }
+            buf[..bytes.len()].copy_from_slice(bytes);
+            FieldValue::Signed(i128::from_le_bytes(buf))
+        }
+        SchemaDataTypes::F32 => {
+            FieldValue::Float(f32::from_le_bytes(bytes.try_into().unwrap()) as f64)
+        }
+        SchemaDataTypes::F64 => FieldValue::Float(f64::from_le_bytes(bytes.try_into().unwrap())),
+        SchemaDataTypes::Bool => FieldValue::Bool(bytes[0] != 0),
+        SchemaDataTypes::String => FieldValue::Text(&bytes[4..]),
+        _ => FieldValue::Raw(bytes),
+    }
+}
+
+struct PredicateReader<'a> {
+    predicate: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> PredicateReader<'a> {
+    fn read_u8(&mut self) -> Result<u8, ProgramError> {
+        let byte = *self
+            .predicate
+            .get(self.offset)
+            .ok_or(AttestationServiceError::InvalidPredicate)?;
+        self.offset += 1;
+        Ok(byte)
+    }
+
+    /// Read an operand encoded as a value of the type `descriptor`.
+    fn read_operand(&mut self, descriptor: &[u8]) -> Result<FieldValue<'a>, ProgramError> {
+        let size = get_size_of_field(descriptor, self.predicate, self.offset)
+            .map_err(|_| AttestationServiceError::InvalidPredicate)?;
+        let bytes = self
+            .predicate
+            .get(self.offset..self.offset + size)
+            .ok_or(AttestationServiceError::InvalidPredicate)?;
+        self.offset += size;
+        Ok(decode_value(descriptor[0], bytes))
+    }
+}
+
+/// Evaluate an encoded predicate against attestation `data` laid out
+/// according to the Schema's `layout`. Fields are referenced by their index
+/// in the layout.
+pub fn evaluate_predicate(
+    predicate: &[u8],
+    data: &[u8],
+    layout: &[u8],
+) -> Result<bool, ProgramError> {
+    let mut reader = PredicateReader {
+        predicate,
+        offset: 0,
+    };
+    let descriptors = split_layout(layout)?;
+    let result = evaluate(&mut reader, data, &descriptors, 0)?;
+
+    // The whole predicate must be a single expression.
+    if reader.offset != predicate.len() {
+        return Err(AttestationServiceError::InvalidPredicate.into());
+    }
+    Ok(result)
+}
+
+/// Evaluate the next expression. Every operand is read, even once the result
+/// is known, so the reader ends up after the expression.
+fn evaluate(
+    reader: &mut PredicateReader,
+    data: &[u8],
+    descriptors: &[&[u8]],
+    depth: u8,
+) -> Result<bool, ProgramError> {
+    if depth >= MAX_PREDICATE_DEPTH {
+        return Err(AttestationServiceError::InvalidPredicate.into());
+    }
+
+    match PredicateOp::try_from(reader.read_u8()?)? {
+        PredicateOp::And => {
+            let count = reader.read_u8()?;
+            let mut result = true;
+            for _ in 0..count {
+                result &= evaluate(reader, data, descriptors, depth + 1)?;
+            }
+            Ok(result)
+        }
+        PredicateOp::Or => {
+            let count = reader.read_u8()?;
+            let mut result = false;
+            for _ in 0..count {
+                result |= evaluate(reader, data, descriptors, depth + 1)?;
+            }
+            Ok(result)
+        }
+        PredicateOp::Not => Ok(!evaluate(reader, data, descriptors, depth + 1)?),
+        PredicateOp::Compare => {
+            let (descriptor, field) = read_field(reader, data, descriptors)?;
+            let compare_op = CompareOp::try_from(reader.read_u8()?)?;
+            let operand = reader.read_operand(descriptor)?;
+            compare(&decode_value(descriptor[0], field), compare_op, &operand)
+        }
+        PredicateOp::In => {
+            let (descriptor, field) = read_field(reader, data, descriptors)?;
+            let value = decode_value(descriptor[0], field);
+            let count = reader.read_u8()?;
+            let mut result = false;
+            for _ in 0..count {
+                result |= value == reader.read_operand(descriptor)?;
+            }
+            Ok(result)
+        }
+    }
+}
+
+fn read_field<'a, 'b>(
+    reader: &mut PredicateReader,
+    data: &'a [u8],
+    descriptors: &[&'b [u8]],
+) -> Result<(&'b [u8], &'a [u8]), ProgramError> {
+    let index = reader.read_u8()? as usize;
+    if index >= descriptors.len() {
+        return Err(AttestationServiceError::InvalidPredicate.into());
+    }
+    get_attestation_field(data, descriptors, index)
+}
+
+fn compare(
+    value: &FieldValue,
+    compare_op: CompareOp,
+    operand: &FieldValue,
+) -> Result<bool, ProgramError> {
+    let ordering = match compare_op {
+        CompareOp::Eq => return Ok(value == operand),
+        CompareOp::Ne => return Ok(value != operand),
+        // Ordering is only defined for numbers and Strings.
+        _ => match value {
+            FieldValue::Bool(_) | FieldValue::Raw(_) => {
+                return Err(AttestationServiceError::InvalidPredicate.into())
+            }
+            _ => value.partial_cmp(operand),
+        },
+    };
+    let result = match compare_op {
+        CompareOp::Lt => ordering == Some(Ordering::Less),
+        CompareOp::Le => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+        CompareOp::Gt => ordering == Some(Ordering::Greater),
+        _ => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+    };
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use crate::processor::to_serialized_vec;
+
+    use super::*;
+
+    // age: u8, country: String, score: i32
+    const LAYOUT: [u8; 3] = [0, 12, 7];
+
+    fn data() -> Vec<u8> {
+        let mut data = Vec::from([21]);
+        data.extend(to_serialized_vec(b"US"));
+        data.extend((-5i32).to_le_bytes());
+        data
+    }
+
+    fn compare_op(field: u8, op: CompareOp, operand: &[u8]) -> Vec<u8> {
+        let mut predicate = Vec::from([PredicateOp::Compare as u8, field, op as u8]);
+        predicate.extend_from_slice(operand);
+        predicate
+    }
+
+    fn group(op: PredicateOp, predicates: &[Vec<u8>]) -> Vec<u8> {
+        let mut predicate = Vec::from([op as u8, predicates.len() as u8]);
+        for p in predicates {
+            predicate.extend_from_slice(p);
+        }
+        predicate
+    }
+
+    #[test]
+    fn predicate_evaluate() {
+        let data = data();
+        let age_at_least_18 = compare_op(0, CompareOp::Ge, &[18]);
+        let age_below_18 = compare_op(0, CompareOp::Lt, &[18]);
+        let negative_score = compare_op(2, CompareOp::Lt, &0i32.to_le_bytes());
+        let country_fr = compare_op(1, CompareOp::Eq, &to_serialized_vec(b"FR"));
+
+        assert!(evaluate_predicate(&age_at_least_18, &data, &LAYOUT).unwrap());
+        assert!(!evaluate_predicate(&age_below_18, &data, &LAYOUT).unwrap());
+        assert!(evaluate_predicate(&negative_score, &data, &LAYOUT).unwrap());
+
+        // country not in [KP, IR]
+        let mut sanctioned = Vec::from([PredicateOp::Not as u8, PredicateOp::In as u8, 1, 2]);
+        sanctioned.extend(to_serialized_vec(b"KP"));
+        sanctioned.extend(to_serialized_vec(b"IR"));
+        assert!(evaluate_predicate(&sanctioned, &data, &LAYOUT).unwrap());
+
+        let and = group(PredicateOp::And, &[age_at_least_18.clone(), negative_score]);
+        assert!(evaluate_predicate(&and, &data, &LAYOUT).unwrap());
+        let or = group(PredicateOp::Or, &[age_below_18, country_fr]);
+        assert!(!evaluate_predicate(&or, &data, &LAYOUT).unwrap());
+        let empty_and = group(PredicateOp::And, &[]);
+        assert!(evaluate_predicate(&empty_and, &data, &LAYOUT).unwrap());
+    }
+
+    #[test]
+    fn predicate_evaluate_fail_invalid() {
+        let data = data();
+
+        // Unknown field
+        let predicate = compare_op(3, CompareOp::Eq, &[18]);
+        assert!(evaluate_predicate(&predicate, &data, &LAYOUT).is_err());
+        // Truncated operand
+        let predicate = compare_op(2, CompareOp::Eq, &[0, 0]);
+        assert!(evaluate_predicate(&predicate, &data, &LAYOUT).is_err());
+        // Trailing bytes
+        let mut predicate = compare_op(0, CompareOp::Eq, &[18]);
+        predicate.push(0);
+        assert!(evaluate_predicate(&predicate, &data, &LAYOUT).is_err());
+        // Unknown opcode
+        assert!(evaluate_predicate(&[9], &data, &LAYOUT).is_err());
+        // Too deep
+        let mut predicate = Vec::from([PredicateOp::Not as u8; MAX_PREDICATE_DEPTH as usize]);
+        predicate.extend(compare_op(0, CompareOp::Eq, &[18]));
+        assert!(evaluate_predicate(&predicate, &data, &LAYOUT).is_err());
+    }
+}
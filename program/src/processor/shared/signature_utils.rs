@@ -0,0 +1,85 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey,
+    sysvars::instructions::Instructions,
+};
+
+use crate::{constants::ED25519_PROGRAM_ID, error::AttestationServiceError};
+
+// Ed25519 program instruction data layout:
+// num_signatures - 1
+// padding - 1
+// signature_offset - 2
+// signature_instruction_index - 2
+// public_key_offset - 2
+// public_key_instruction_index - 2
+// message_data_offset - 2
+// message_data_size - 2
+// message_instruction_index - 2
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_SIZE: usize = 14;
+// Instruction index meaning "the Ed25519 instruction itself".
+const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([data[offset], data[offset + 1]])
+}
+
+/// Load the public key and message verified by the Ed25519 program
+/// instruction placed immediately before the current instruction.
+///
+/// Only a single signature whose public key and message are stored in the
+/// Ed25519 instruction itself is accepted.
+///
+/// # Arguments
+/// * `instructions_sysvar` - The Instructions sysvar account.
+///
+/// # Returns
+/// * `Result<(Pubkey, Vec<u8>), ProgramError>` - The signer and signed message
+pub fn load_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+) -> Result<(Pubkey, Vec<u8>), ProgramError> {
+    let instructions = Instructions::try_from(instructions_sysvar)?;
+    let ed25519_ix = instructions
+        .get_instruction_relative(-1)
+        .map_err(|_| AttestationServiceError::InvalidSignatureInstruction)?;
+
+    if ed25519_ix.get_program_id().ne(&ED25519_PROGRAM_ID) {
+        return Err(AttestationServiceError::InvalidSignatureInstruction.into());
+    }
+
+    let data = ed25519_ix.get_instruction_data();
+    if data.len() < ED25519_OFFSETS_START + ED25519_OFFSETS_SIZE || data[0] != 1 {
+        return Err(AttestationServiceError::InvalidSignatureInstruction.into());
+    }
+
+    let offsets = ED25519_OFFSETS_START;
+    let signature_instruction_index = read_u16(data, offsets + 2);
+    let public_key_offset = read_u16(data, offsets + 4) as usize;
+    let public_key_instruction_index = read_u16(data, offsets + 6);
+    let message_data_offset = read_u16(data, offsets + 8) as usize;
+    let message_data_size = read_u16(data, offsets + 10) as usize;
+    let message_instruction_index = read_u16(data, offsets + 12);
+
+    // Data referenced in other instructions is not covered by this check.
+    if signature_instruction_index != ED25519_CURRENT_INSTRUCTION
+        || public_key_instruction_index != ED25519_CURRENT_INSTRUCTION
+        || message_instruction_index != ED25519_CURRENT_INSTRUCTION
+    {
+        return Err(AttestationServiceError::InvalidSignatureInstruction.into());
+    }
+
+    let public_key: Pubkey = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(AttestationServiceError::InvalidSignatureInstruction)?
+        .try_into()
+        .unwrap();
+    let message = data
+        .get(message_data_offset..message_data_offset + message_data_size)
+        .ok_or(AttestationServiceError::InvalidSignatureInstruction)?
+        .to_vec();
+
+    Ok((public_key, message))
+}
@@ -18,7 +18,9 @@ use solana_program::pubkey::Pubkey as SolanaPubkey;
 use crate::{
     constants::{sas_pda, SAS_SEED, SCHEMA_MINT_SEED},
     error::AttestationServiceError,
-    processor::{create_pda_account, verify_signer, verify_system_program},
+    processor::{
+        create_pda_account, verify_credential_admin, verify_signer, verify_system_program,
+    },
     require_len,
     state::{Credential, Schema},
 };
@@ -32,7 +34,7 @@ pub fn process_tokenize_schema(
     instruction_data: &[u8],
 ) -> ProgramResult {
     let args = process_instruction_data(instruction_data)?;
-    let [payer_info, authority_info, credential_info, schema_info, mint_info, sas_pda_info, system_program, token_program] =
+    let [payer_info, authority_info, credential_info, schema_info, mint_info, sas_pda_info, system_program, token_program, co_signers @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -47,11 +49,9 @@ pub fn process_tokenize_schema(
     verify_system_program(system_program)?;
     verify_token22_program(token_program)?;
 
-    // Verify signer matches credential authority.
+    // Verify the Credential's admins approved the change.
     let credential = &Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
-    if credential.authority.ne(authority_info.key()) {
-        return Err(ProgramError::IncorrectAuthority);
-    }
+    verify_credential_admin(credential, authority_info, co_signers)?;
 
     // Validate Schema is owned by Credential
     let schema = Schema::try_from_bytes(&schema_info.try_borrow_data()?)?;
@@ -0,0 +1,171 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
+
+use crate::{
+    error::AttestationServiceError,
+    events::{EventDiscriminators, UpdateAttestationEvent},
+    processor::{
+        check_constraints, invoke_emit_event, verify_current_program, verify_owner_mutability,
+        verify_signer, verify_system_program,
+    },
+    require_len,
+    state::{discriminator::AccountSerialize, Attestation, Credential, Schema, SignerPermission},
+};
+
+#[inline(always)]
+pub fn process_update_attestation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [payer_info, authorized_signer, credential_info, schema_info, attestation_info, event_authority_info, system_program, attestation_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authorized_signer, false)?;
+
+    // Validate system program
+    verify_system_program(system_program)?;
+
+    // Verify attestation program
+    verify_current_program(attestation_program)?;
+
+    // Validate Credential, Schema and Attestation are owned by our program
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(schema_info, program_id, false)?;
+    verify_owner_mutability(attestation_info, program_id, true)?;
+
+    // Check that one of credential's authorized signers have signed.
+    let credential = &Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
+    credential.validate_authorized_signer(
+        authorized_signer.key(),
+        schema_info.key(),
+        SignerPermission::UPDATE,
+    )?;
+
+    let schema = Schema::try_from_bytes(&schema_info.try_borrow_data()?)?;
+
+    // Validate Schema is not paused
+    if schema.is_paused {
+        return Err(AttestationServiceError::SchemaPaused.into());
+    }
+
+    // Validate Schema is owned by Credential
+    if schema.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+
+    let attestation_data = attestation_info.try_borrow_data()?;
+    let mut attestation = Attestation::try_from_bytes(&attestation_data)?;
+    drop(attestation_data); // Drop immutable borrow.
+
+    // Check that credential and schema match attestation's.
+    if attestation.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+    if attestation.schema.ne(schema_info.key()) {
+        return Err(AttestationServiceError::InvalidSchema.into());
+    }
+
+    // Revoked attestations can no longer be modified.
+    if attestation.is_revoked() {
+        return Err(AttestationServiceError::AttestationRevoked.into());
+    }
+
+    // Update data, signer and last-modified timestamp on struct.
+    let old_data = core::mem::replace(&mut attestation.data, args.data);
+    attestation.signer = *authorized_signer.key();
+    attestation.updated_at = Clock::get()?.unix_timestamp;
+
+    // Validate the Attestation data matches the layout of the Schema
+    attestation.validate_data(schema.layout.clone())?;
+    // Validate the Attestation data satisfies the Schema's field constraints
+    check_constraints(
+        &schema.constraints,
+        &attestation.data,
+        &schema.layout,
+        attestation.updated_at,
+    )?;
+
+    // Resize account if needed. Attestations created with an older layout
+    // grow to the current one, so the new space is taken from the
+    // serialized Attestation.
+    let attestation_bytes = attestation.to_bytes();
+    let new_space = attestation_bytes.len();
+    if new_space != attestation_info.data_len() {
+        attestation_info.realloc(new_space, false)?;
+
+        let rent = Rent::get()?;
+        let min_rent = rent.minimum_balance(new_space);
+        let current_rent = attestation_info.lamports();
+        if min_rent > current_rent {
+            // top up lamports to account for additional rent.
+            Transfer {
+                from: payer_info,
+                to: attestation_info,
+                lamports: min_rent - current_rent,
+            }
+            .invoke()?;
+        } else if current_rent > min_rent && attestation.payer.ne(&Pubkey::default()) {
+            // Validate the refund goes to the account that funded the Attestation.
+            // Older Attestations have no recorded payer and keep the excess.
+            if payer_info.key().ne(&attestation.payer) {
+                return Err(AttestationServiceError::InvalidPayer.into());
+            }
+
+            // refund lamports no longer needed for rent.
+            let excess = current_rent - min_rent;
+            *attestation_info.try_borrow_mut_lamports()? = min_rent;
+            let payer_lamports = payer_info.lamports();
+            *payer_info.try_borrow_mut_lamports()? = payer_lamports
+                .checked_add(excess)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+    }
+
+    // Write updated data.
+    let mut attestation_data = attestation_info.try_borrow_mut_data()?;
+    attestation_data.copy_from_slice(&attestation_bytes);
+    drop(attestation_data); // Drop mutable borrow.
+
+    let event = UpdateAttestationEvent {
+        discriminator: EventDiscriminators::UpdateEvent as u8,
+        schema: attestation.schema,
+        attestation: *attestation_info.key(),
+        old_data,
+        new_data: attestation.data,
+    };
+    invoke_emit_event(program_id, event_authority_info, &event.to_bytes())?;
+
+    Ok(())
+}
+
+struct UpdateAttestationArgs {
+    data: Vec<u8>,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<UpdateAttestationArgs, ProgramError> {
+    let mut offset: usize = 0;
+
+    require_len!(data, 4);
+    let data_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+    offset += 4;
+
+    require_len!(data, offset + data_len);
+    let data_bytes = data[offset..offset + data_len].to_vec();
+
+    Ok(UpdateAttestationArgs { data: data_bytes })
+}
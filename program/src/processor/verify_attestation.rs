@@ -0,0 +1,121 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo,
+    program::set_return_data,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use shank::ShankType;
+
+use crate::{
+    error::AttestationServiceError,
+    state::{Attestation, Credential, Schema, SignerPermission},
+};
+
+use super::verify_owner_mutability;
+
+/// Status returned by VerifyAttestation. When several apply, the first in
+/// declaration order is reported.
+#[repr(u8)]
+pub enum AttestationStatus {
+    Valid = 0,
+    Revoked = 1,
+    Expired = 2,
+    /// The signer is no longer one of the Credential's authorized signers
+    SignerNotAuthorized = 3,
+    SchemaPaused = 4,
+}
+
+/// Return data of VerifyAttestation.
+#[derive(ShankType)]
+pub struct VerifyAttestationResult {
+    /// AttestationStatus of the Attestation
+    pub status: u8,
+    /// A pubkey that may either be randomly generated OR associated with a User's wallet
+    pub nonce: Pubkey,
+    /// The pubkey of the signer that created the Attestation
+    pub signer: Pubkey,
+    /// Designates when the credential is expired. 0 means never expired
+    pub expiry: i64,
+}
+
+impl VerifyAttestationResult {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(1 + 32 + 32 + 8);
+        data.push(self.status);
+        data.extend_from_slice(self.nonce.as_ref());
+        data.extend_from_slice(self.signer.as_ref());
+        data.extend_from_slice(&self.expiry.to_le_bytes());
+
+        data
+    }
+}
+
+#[inline(always)]
+pub fn process_verify_attestation(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let (_schema, attestation, status) = load_attestation_status(program_id, accounts)?;
+
+    let result = VerifyAttestationResult {
+        status: status as u8,
+        nonce: attestation.nonce,
+        signer: attestation.signer,
+        expiry: attestation.expiry,
+    };
+    set_return_data(&result.to_bytes());
+
+    Ok(())
+}
+
+/// Load and cross-check the Credential, Schema and Attestation accounts,
+/// returning the Schema, Attestation and current status of the Attestation.
+pub fn load_attestation_status(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(Schema, Attestation, AttestationStatus), ProgramError> {
+    let [credential_info, schema_info, attestation_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate Credential, Schema and Attestation are owned by our program
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(schema_info, program_id, false)?;
+    verify_owner_mutability(attestation_info, program_id, false)?;
+
+    let credential = Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
+    let schema = Schema::try_from_bytes(&schema_info.try_borrow_data()?)?;
+    let attestation = Attestation::try_from_bytes(&attestation_info.try_borrow_data()?)?;
+
+    // Check that credential and schema match attestation's.
+    if attestation.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+    if attestation.schema.ne(schema_info.key()) {
+        return Err(AttestationServiceError::InvalidSchema.into());
+    }
+
+    let clock = Clock::get()?;
+    let status = if attestation.is_revoked() {
+        AttestationStatus::Revoked
+    } else if attestation.is_expired(clock.unix_timestamp) {
+        AttestationStatus::Expired
+    } else if credential
+        .validate_authorized_signer(
+            &attestation.signer,
+            &attestation.schema,
+            SignerPermission::ATTEST,
+        )
+        .is_err()
+    {
+        AttestationStatus::SignerNotAuthorized
+    } else if schema.is_paused {
+        AttestationStatus::SchemaPaused
+    } else {
+        AttestationStatus::Valid
+    };
+
+    Ok((schema, attestation, status))
+}
@@ -0,0 +1,54 @@
+use pinocchio::{
+    account_info::AccountInfo, program::set_return_data, program_error::ProgramError,
+    pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{error::AttestationServiceError, require_len};
+
+use super::{evaluate_predicate, load_attestation_status, AttestationStatus};
+
+#[inline(always)]
+pub fn process_verify_attestation_predicate(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let (schema, attestation, status) = load_attestation_status(program_id, accounts)?;
+
+    // The predicate is always evaluated so malformed predicates fail
+    // regardless of the Attestation's status.
+    let satisfied = evaluate_predicate(args.predicate, &attestation.data, &schema.layout)?
+        && matches!(status, AttestationStatus::Valid);
+
+    if args.require_satisfied && !satisfied {
+        return Err(AttestationServiceError::PredicateNotSatisfied.into());
+    }
+    set_return_data(&[satisfied as u8]);
+
+    Ok(())
+}
+
+struct VerifyAttestationPredicateArgs<'a> {
+    predicate: &'a [u8],
+    require_satisfied: bool,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<VerifyAttestationPredicateArgs, ProgramError> {
+    let mut offset: usize = 0;
+
+    require_len!(data, offset + 4);
+    let predicate_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+    offset += 4;
+
+    require_len!(data, offset + predicate_len + 1);
+    let predicate = &data[offset..offset + predicate_len];
+    offset += predicate_len;
+
+    let require_satisfied = data[offset] == 1;
+
+    Ok(VerifyAttestationPredicateArgs {
+        predicate,
+        require_satisfied,
+    })
+}
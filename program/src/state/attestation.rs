@@ -8,7 +8,7 @@ use crate::error::AttestationServiceError;
 
 use super::{
     discriminator::{AccountSerialize, AttestationAccountDiscriminators, Discriminator},
-    SchemaDataTypes,
+    split_layout, SchemaDataTypes,
 };
 
 // PDA ["attestation", credential, schema, nonce]
@@ -29,6 +29,21 @@ pub struct Attestation {
     pub expiry: i64,
     /// The pubkey of Attestation token account if created. Otherwise set to default pubkey.
     pub token_account: Pubkey,
+    /// Designates when the attestation was revoked. 0 means not revoked
+    pub revoked_at: i64,
+    /// The pubkey of the authorized signer that revoked the attestation.
+    /// Otherwise set to default pubkey.
+    pub revoked_by: Pubkey,
+    /// Issuer-defined code describing why the attestation was revoked.
+    pub revocation_reason: u8,
+    /// Unix timestamp of when the attestation was created
+    pub created_at: i64,
+    /// Unix timestamp of when the attestation was last modified
+    pub updated_at: i64,
+    /// The account that funded the Attestation's rent
+    pub payer: Pubkey,
+    /// Attestations this Attestation depends on, validated at creation
+    pub references: Vec<Pubkey>,
 }
 
 impl Discriminator for Attestation {
@@ -46,113 +61,227 @@ impl AccountSerialize for Attestation {
         data.extend_from_slice(self.signer.as_ref());
         data.extend_from_slice(&self.expiry.to_le_bytes());
         data.extend_from_slice(self.token_account.as_ref());
+        data.extend_from_slice(&self.revoked_at.to_le_bytes());
+        data.extend_from_slice(self.revoked_by.as_ref());
+        data.push(self.revocation_reason);
+        data.extend_from_slice(&self.created_at.to_le_bytes());
+        data.extend_from_slice(&self.updated_at.to_le_bytes());
+        data.extend_from_slice(self.payer.as_ref());
+        data.extend_from_slice(&(self.references.len() as u32).to_le_bytes());
+        for reference in &self.references {
+            data.extend_from_slice(reference.as_ref());
+        }
 
         data
     }
 }
 
 #[inline]
-fn get_size_of_vec(offset: usize, element_size: usize, data: &Vec<u8>) -> usize {
-    let len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-    4 + len * element_size
+fn get_len_of_vec(offset: usize, data: &[u8]) -> Result<usize, ProgramError> {
+    if offset + 4 > data.len() {
+        return Err(AttestationServiceError::InvalidAttestationData.into());
+    }
+    Ok(u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize)
 }
 
-impl Attestation {
-    /// Validate the data in the Attestation conforms to the Schema's
-    /// layout.
-    pub fn validate_data(&self, layout: Vec<u8>) -> Result<(), ProgramError> {
-        // Iterate over the data and ensure there are no overflows.
-        // If we do not overflow and match with the end of the data,
-        // then we can assume the data is valid for the schema.
-        let mut data_offset = 0;
-        for data_type in layout {
-            let schema_data_type: SchemaDataTypes = data_type.into();
-            match schema_data_type {
-                // u8 -> u128
-                SchemaDataTypes::U8 => data_offset += 1,
-                SchemaDataTypes::U16 => data_offset += 2,
-                SchemaDataTypes::U32 => data_offset += 4,
-                SchemaDataTypes::U64 => data_offset += 8,
-                SchemaDataTypes::U128 => data_offset += 16,
-                // i8 -> i128
-                SchemaDataTypes::I8 => data_offset += 1,
-                SchemaDataTypes::I16 => data_offset += 2,
-                SchemaDataTypes::I32 => data_offset += 4,
-                SchemaDataTypes::I64 => data_offset += 8,
-                SchemaDataTypes::I128 => data_offset += 16,
-                // bool
-                SchemaDataTypes::Bool => data_offset += 1,
-                // char
-                SchemaDataTypes::Char => data_offset += 4,
-                // String
-                SchemaDataTypes::String => {
-                    data_offset += get_size_of_vec(data_offset, 1, &self.data)
-                }
-                // Vec<u8> -> Vec<u128>
-                SchemaDataTypes::VecU8 => {
-                    data_offset += get_size_of_vec(data_offset, 1, &self.data)
-                }
-                SchemaDataTypes::VecU16 => {
-                    data_offset += get_size_of_vec(data_offset, 2, &self.data)
-                }
-                SchemaDataTypes::VecU32 => {
-                    data_offset += get_size_of_vec(data_offset, 4, &self.data)
-                }
-                SchemaDataTypes::VecU64 => {
-                    data_offset += get_size_of_vec(data_offset, 8, &self.data)
-                }
-                SchemaDataTypes::VecU128 => {
-                    data_offset += get_size_of_vec(data_offset, 16, &self.data)
-                }
-                // Vec<i8> -> Vec<i128>
-                SchemaDataTypes::VecI8 => {
-                    data_offset += get_size_of_vec(data_offset, 1, &self.data)
-                }
-                SchemaDataTypes::VecI16 => {
-                    data_offset += get_size_of_vec(data_offset, 2, &self.data)
-                }
-                SchemaDataTypes::VecI32 => {
-                    data_offset += get_size_of_vec(data_offset, 4, &self.data)
-                }
-                SchemaDataTypes::VecI64 => {
-                    data_offset += get_size_of_vec(data_offset, 8, &self.data)
-                }
-                SchemaDataTypes::VecI128 => {
-                    data_offset += get_size_of_vec(data_offset, 16, &self.data)
-                }
-                // Vec<bool>
-                SchemaDataTypes::VecBool => {
-                    data_offset += get_size_of_vec(data_offset, 1, &self.data)
-                }
-                // Vec<char>
-                SchemaDataTypes::VecChar => {
-                    data_offset += get_size_of_vec(data_offset, 4, &self.data)
-                }
-                // Vec<String>
-                SchemaDataTypes::VecString => {
-                    let len = u32::from_le_bytes(
-                        self.data[data_offset..data_offset + 4].try_into().unwrap(),
-                    ) as usize;
-                    data_offset += 4;
-                    // must iterate over the strings using their len
-                    for _ in 0..len {
-                        let string_len = u32::from_le_bytes(
-                            self.data[data_offset..data_offset + 4].try_into().unwrap(),
-                        ) as usize;
-                        data_offset += 4 + string_len;
-                    }
+#[inline]
+fn get_size_of_vec(offset: usize, element_size: usize, data: &[u8]) -> Result<usize, ProgramError> {
+    Ok(4 + get_len_of_vec(offset, data)? * element_size)
+}
+
+/// Size of an Option: the one byte tag, plus the size of the value when Some.
+fn get_size_of_option(data_type: u8, data: &[u8], offset: usize) -> Result<usize, ProgramError> {
+    let inner_type = SchemaDataTypes::option_inner(data_type).unwrap();
+    match data.get(offset) {
+        Some(0) => Ok(1),
+        Some(1) => Ok(1 + get_size_of_field(&[inner_type], data, offset + 1)?),
+        _ => Err(AttestationServiceError::InvalidAttestationData.into()),
+    }
+}
+
+/// Size of a group of fields, each described by one of `fields`.
+fn get_size_of_struct(fields: &[&[u8]], data: &[u8], offset: usize) -> Result<usize, ProgramError> {
+    let mut size = 0;
+    for field in fields {
+        size += get_size_of_field(field, data, offset + size)?;
+    }
+    Ok(size)
+}
+
+/// Size in bytes of the value encoded at `offset` in `data`, described by the
+/// type descriptor `layout` (see `validate_type_descriptor`).
+/// The returned size may exceed the remaining data, callers must check.
+pub fn get_size_of_field(layout: &[u8], data: &[u8], offset: usize) -> Result<usize, ProgramError> {
+    let data_type = layout[0];
+    let schema_data_type: SchemaDataTypes = data_type.into();
+    let size = match schema_data_type {
+        // u8 -> u128
+        SchemaDataTypes::U8 => 1,
+        SchemaDataTypes::U16 => 2,
+        SchemaDataTypes::U32 => 4,
+        SchemaDataTypes::U64 => 8,
+        SchemaDataTypes::U128 => 16,
+        // i8 -> i128
+        SchemaDataTypes::I8 => 1,
+        SchemaDataTypes::I16 => 2,
+        SchemaDataTypes::I32 => 4,
+        SchemaDataTypes::I64 => 8,
+        SchemaDataTypes::I128 => 16,
+        // bool
+        SchemaDataTypes::Bool => 1,
+        // char
+        SchemaDataTypes::Char => 4,
+        // String
+        SchemaDataTypes::String => get_size_of_vec(offset, 1, data)?,
+        // Vec<u8> -> Vec<u128>
+        SchemaDataTypes::VecU8 => get_size_of_vec(offset, 1, data)?,
+        SchemaDataTypes::VecU16 => get_size_of_vec(offset, 2, data)?,
+        SchemaDataTypes::VecU32 => get_size_of_vec(offset, 4, data)?,
+        SchemaDataTypes::VecU64 => get_size_of_vec(offset, 8, data)?,
+        SchemaDataTypes::VecU128 => get_size_of_vec(offset, 16, data)?,
+        // Vec<i8> -> Vec<i128>
+        SchemaDataTypes::VecI8 => get_size_of_vec(offset, 1, data)?,
+        SchemaDataTypes::VecI16 => get_size_of_vec(offset, 2, data)?,
+        SchemaDataTypes::VecI32 => get_size_of_vec(offset, 4, data)?,
+        SchemaDataTypes::VecI64 => get_size_of_vec(offset, 8, data)?,
+        SchemaDataTypes::VecI128 => get_size_of_vec(offset, 16, data)?,
+        // Vec<bool>
+        SchemaDataTypes::VecBool => get_size_of_vec(offset, 1, data)?,
+        // Vec<char>
+        SchemaDataTypes::VecChar => get_size_of_vec(offset, 4, data)?,
+        // Vec<String>
+        SchemaDataTypes::VecString => {
+            let len = get_len_of_vec(offset, data)?;
+            let mut size = 4;
+            // must iterate over the strings using their len
+            for _ in 0..len {
+                size += get_size_of_vec(offset + size, 1, data)?;
+            }
+            size
+        }
+        // Option<u8> -> Option<Vec<String>>
+        SchemaDataTypes::OptionU8
+        | SchemaDataTypes::OptionU16
+        | SchemaDataTypes::OptionU32
+        | SchemaDataTypes::OptionU64
+        | SchemaDataTypes::OptionU128
+        | SchemaDataTypes::OptionI8
+        | SchemaDataTypes::OptionI16
+        | SchemaDataTypes::OptionI32
+        | SchemaDataTypes::OptionI64
+        | SchemaDataTypes::OptionI128
+        | SchemaDataTypes::OptionBool
+        | SchemaDataTypes::OptionChar
+        | SchemaDataTypes::OptionString
+        | SchemaDataTypes::OptionVecU8
+        | SchemaDataTypes::OptionVecU16
+        | SchemaDataTypes::OptionVecU32
+        | SchemaDataTypes::OptionVecU64
+        | SchemaDataTypes::OptionVecU128
+        | SchemaDataTypes::OptionVecI8
+        | SchemaDataTypes::OptionVecI16
+        | SchemaDataTypes::OptionVecI32
+        | SchemaDataTypes::OptionVecI64
+        | SchemaDataTypes::OptionVecI128
+        | SchemaDataTypes::OptionVecBool
+        | SchemaDataTypes::OptionVecChar
+        | SchemaDataTypes::OptionVecString => get_size_of_option(data_type, data, offset)?,
+        // Pubkey
+        SchemaDataTypes::Pubkey => 32,
+        // [u8; 32], [u8; 64]
+        SchemaDataTypes::Bytes32 => 32,
+        SchemaDataTypes::Bytes64 => 64,
+        // f32, f64
+        SchemaDataTypes::F32 => 4,
+        SchemaDataTypes::F64 => 8,
+        // Decimal mantissa
+        SchemaDataTypes::Decimal => 16,
+        // Timestamp
+        SchemaDataTypes::Timestamp => 8,
+        // Enum variant index, which must be one of the declared variants
+        SchemaDataTypes::Enum => match data.get(offset) {
+            Some(variant) if *variant < layout[1] => 1,
+            _ => return Err(AttestationServiceError::InvalidAttestationData.into()),
+        },
+        // Struct, Vec<Struct>
+        SchemaDataTypes::Struct => get_size_of_struct(&split_layout(&layout[2..])?, data, offset)?,
+        SchemaDataTypes::VecStruct => {
+            let fields = split_layout(&layout[2..])?;
+            let len = get_len_of_vec(offset, data)?;
+            let mut size = 4;
+            for _ in 0..len {
+                size += get_size_of_struct(&fields, data, offset + size)?;
+                // Elements may be read without bounds checks, stop at the end
+                // of the data rather than iterating over every element.
+                if offset + size > data.len() {
+                    return Err(AttestationServiceError::InvalidAttestationData.into());
                 }
             }
+            size
+        }
+    };
 
-            // Check data size at end of each iteration and error if offset exceeds the data length.
-            if data_offset > self.data.len() {
-                return Err(AttestationServiceError::InvalidAttestationData.into());
-            }
+    Ok(size)
+}
+
+/// Validate attestation `data` conforms to the Schema's layout. Shared by
+/// Attestation accounts and compressed Attestations.
+pub fn validate_attestation_data(data: &[u8], layout: Vec<u8>) -> Result<(), ProgramError> {
+    // Iterate over the data and ensure there are no overflows.
+    // If we do not overflow and match with the end of the data,
+    // then we can assume the data is valid for the schema.
+    let mut data_offset = 0;
+    for descriptor in split_layout(&layout)? {
+        data_offset += get_size_of_field(descriptor, data, data_offset)?;
+
+        // Check data size at end of each iteration and error if offset exceeds the data length.
+        if data_offset > data.len() {
+            return Err(AttestationServiceError::InvalidAttestationData.into());
         }
-        if data_offset != self.data.len() {
+    }
+    if data_offset != data.len() {
+        return Err(AttestationServiceError::InvalidAttestationData.into());
+    }
+    Ok(())
+}
+
+/// Locate top level field `index` in attestation `data`, given the type
+/// `descriptors` of the Schema's layout (see `split_layout`). Returns the
+/// field's type descriptor and its encoded bytes.
+pub fn get_attestation_field<'a, 'b>(
+    data: &'a [u8],
+    descriptors: &[&'b [u8]],
+    index: usize,
+) -> Result<(&'b [u8], &'a [u8]), ProgramError> {
+    let mut data_offset = 0;
+    for (field_index, descriptor) in descriptors.iter().enumerate() {
+        let size = get_size_of_field(descriptor, data, data_offset)?;
+        if data_offset + size > data.len() {
             return Err(AttestationServiceError::InvalidAttestationData.into());
         }
-        Ok(())
+        if field_index == index {
+            return Ok((descriptor, &data[data_offset..data_offset + size]));
+        }
+        data_offset += size;
+    }
+    Err(AttestationServiceError::InvalidAttestationData.into())
+}
+
+impl Attestation {
+    /// Whether the Attestation has been revoked by an authorized signer.
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at != 0
+    }
+
+    /// Whether the Attestation has a non-zero expiry that is before `unix_timestamp`.
+    pub fn is_expired(&self, unix_timestamp: i64) -> bool {
+        self.expiry != 0 && unix_timestamp > self.expiry
+    }
+
+    /// Validate the data in the Attestation conforms to the Schema's
+    /// layout.
+    pub fn validate_data(&self, layout: Vec<u8>) -> Result<(), ProgramError> {
+        validate_attestation_data(&self.data, layout)
     }
 
     pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
@@ -186,8 +315,9 @@ impl Attestation {
         offset += 8;
 
         let token_account: Pubkey = data[offset..offset + 32].try_into().unwrap();
+        offset += 32;
 
-        Ok(Self {
+        let mut attestation = Self {
             nonce,
             credential,
             schema,
@@ -195,7 +325,48 @@ impl Attestation {
             signer,
             expiry,
             token_account,
-        })
+            revoked_at: 0,
+            revoked_by: Pubkey::default(),
+            revocation_reason: 0,
+            created_at: 0,
+            updated_at: 0,
+            payer: Pubkey::default(),
+            references: Vec::new(),
+        };
+
+        // Attestations created before the fields below were added end here
+        // and keep their defaults until migrated.
+        if offset == data.len() {
+            return Ok(attestation);
+        }
+
+        attestation.revoked_at = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        offset += 8;
+
+        attestation.revoked_by = data[offset..offset + 32].try_into().unwrap();
+        offset += 32;
+
+        attestation.revocation_reason = data[offset];
+        offset += 1;
+
+        attestation.created_at = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        offset += 8;
+
+        attestation.updated_at = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        offset += 8;
+
+        attestation.payer = data[offset..offset + 32].try_into().unwrap();
+        offset += 32;
+
+        let references_len =
+            u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+        attestation.references = data[offset..offset + 32 * references_len]
+            .chunks_exact(32)
+            .map(|reference| reference.try_into().unwrap())
+            .collect();
+
+        Ok(attestation)
     }
 }
 
@@ -215,6 +386,13 @@ mod tests {
             signer: Pubkey::default(),
             expiry: 0,
             token_account: Pubkey::default(),
+            revoked_at: 0,
+            revoked_by: Pubkey::default(),
+            revocation_reason: 0,
+            created_at: 0,
+            updated_at: 0,
+            payer: Pubkey::default(),
+            references: Vec::new(),
         };
 
         // u8
@@ -251,4 +429,113 @@ mod tests {
         // Should fail when attestion has no data
         assert!(attestation.validate_data(layout).is_err());
     }
+
+    #[test]
+    fn attestation_validate_option_data() {
+        // Option<u8>, Option<String>, Option<Vec<u16>>
+        let layout = alloc::vec![26, 38, 40];
+
+        let mut data: Vec<u8> = alloc::vec![1, 10, 0, 1];
+        data.extend(2u32.to_le_bytes());
+        data.extend([1, 0, 2, 0]);
+        assert!(validate_attestation_data(&data, layout.clone()).is_ok());
+
+        // All None
+        assert!(validate_attestation_data(&[0, 0, 0], layout.clone()).is_ok());
+
+        // Invalid tag
+        assert!(validate_attestation_data(&[2, 10, 0, 0], layout.clone()).is_err());
+
+        // Some without a value
+        assert!(validate_attestation_data(&[0, 0, 1], layout).is_err());
+    }
+
+    #[test]
+    fn attestation_validate_fixed_size_data() {
+        // Pubkey, [u8; 32], [u8; 64]
+        let layout = alloc::vec![52, 53, 54];
+
+        let data = [7u8; 32 + 32 + 64];
+        assert!(validate_attestation_data(&data, layout.clone()).is_ok());
+
+        // No length prefix is expected, so a short array is an error.
+        assert!(validate_attestation_data(&data[1..], layout).is_err());
+    }
+
+    #[test]
+    fn attestation_validate_struct_data() {
+        // u8, Struct { String, Vec<Struct { u16 }> }
+        let layout = alloc::vec![0, 55, 2, 12, 56, 1, 1];
+
+        let mut data: Vec<u8> = alloc::vec![10];
+        data.extend(to_serialized_vec(b"test"));
+        data.extend(2u32.to_le_bytes());
+        data.extend([1, 0, 2, 0]);
+        assert!(validate_attestation_data(&data, layout.clone()).is_ok());
+
+        // Missing Vec element
+        assert!(validate_attestation_data(&data[..data.len() - 2], layout.clone()).is_err());
+
+        // Huge Vec length with no elements
+        let mut data: Vec<u8> = alloc::vec![10];
+        data.extend(to_serialized_vec(b"test"));
+        data.extend(u32::MAX.to_le_bytes());
+        assert!(validate_attestation_data(&data, layout).is_err());
+
+        // Struct without fields
+        assert!(validate_attestation_data(&[10], alloc::vec![0, 55, 0]).is_err());
+        // Truncated Struct
+        assert!(validate_attestation_data(&[10, 1], alloc::vec![0, 55, 2, 0]).is_err());
+    }
+
+    #[test]
+    fn attestation_validate_enum_data() {
+        // u8, Enum with 3 variants
+        let layout = alloc::vec![0, 61, 3];
+        assert!(validate_attestation_data(&[10, 2], layout.clone()).is_ok());
+
+        // Out of range discriminant
+        assert!(validate_attestation_data(&[10, 3], layout.clone()).is_err());
+        // Missing discriminant
+        assert!(validate_attestation_data(&[10], layout).is_err());
+        // Enum without variants
+        assert!(validate_attestation_data(&[0], alloc::vec![61, 0]).is_err());
+    }
+
+    #[test]
+    fn attestation_from_legacy_bytes() {
+        let mut attestation = Attestation {
+            nonce: [1; 32],
+            credential: [2; 32],
+            schema: [3; 32],
+            data: alloc::vec![10, 11],
+            signer: [4; 32],
+            expiry: 5,
+            token_account: [6; 32],
+            revoked_at: 0,
+            revoked_by: Pubkey::default(),
+            revocation_reason: 0,
+            created_at: 0,
+            updated_at: 0,
+            payer: Pubkey::default(),
+            references: Vec::new(),
+        };
+
+        // Accounts created before revocation and the fields after it end
+        // with the token account.
+        let bytes = attestation.to_bytes();
+        let legacy_len = bytes.len() - (8 + 32 + 1 + 8 + 8 + 32 + 4);
+        assert_eq!(
+            Attestation::try_from_bytes(&bytes[..legacy_len]).unwrap(),
+            attestation
+        );
+
+        attestation.revoked_at = 7;
+        attestation.payer = [8; 32];
+        attestation.references = alloc::vec![[9; 32]];
+        assert_eq!(
+            Attestation::try_from_bytes(&attestation.to_bytes()).unwrap(),
+            attestation
+        );
+    }
 }
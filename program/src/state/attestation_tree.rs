@@ -0,0 +1,452 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{msg, program_error::ProgramError, pubkey::Pubkey, ProgramResult};
+use shank::{ShankAccount, ShankType};
+use solana_program::keccak::hashv;
+
+use crate::error::AttestationServiceError;
+
+use super::discriminator::{AccountSerialize, AttestationAccountDiscriminators, Discriminator};
+
+pub type Node = [u8; 32];
+
+/// Deepest supported tree. RevokeCompressedAttestation takes one proof account
+/// per level, so deeper trees leave no room for the leaf, see
+/// `MAX_REVOCATION_PROOF_AND_DATA_SIZE`.
+pub const MAX_TREE_DEPTH: u8 = 16;
+/// Largest supported changelog buffer.
+pub const MAX_TREE_BUFFER_SIZE: u8 = 64;
+/// Each instruction holds the deserialized tree and its serialized bytes twice
+/// on the 32KiB heap.
+pub const MAX_TREE_ACCOUNT_SIZE: usize = 8_192;
+/// Bytes of a 1232 byte RevokeCompressedAttestation transaction, signed by a
+/// fee payer and a separate authority, left for the proof (33 bytes per level:
+/// an account key and its index) and the Attestation data. The rest is taken
+/// by the signatures, the other accounts and the fixed part of the leaf.
+pub const MAX_REVOCATION_PROOF_AND_DATA_SIZE: usize = 638;
+
+/// A change applied to the tree, kept so proofs against a recent root can be
+/// fast-forwarded to the current root.
+#[derive(Clone, Debug, PartialEq, ShankType)]
+pub struct ChangeLog {
+    /// Root of the tree after the change
+    pub root: [u8; 32],
+    /// Nodes from the changed leaf (index 0) up to, but excluding, the root
+    pub path: Vec<[u8; 32]>,
+    /// Index of the changed leaf
+    pub index: u32,
+}
+
+// PDA ["attestationTree", schema]
+/// Concurrent Merkle tree holding compressed Attestations of a Schema.
+#[derive(Clone, Debug, PartialEq, ShankAccount)]
+#[repr(C)]
+pub struct AttestationTree {
+    /// Schema whose compressed Attestations are stored in the tree
+    pub schema: Pubkey,
+    /// Depth of the tree. Holds up to 2^max_depth leaves
+    pub max_depth: u8,
+    /// Number of changelog entries kept to fast-forward stale proofs
+    pub max_buffer_size: u8,
+    /// Number of leaves appended so far
+    pub num_leaves: u64,
+    /// Number of changes applied to the tree
+    pub sequence: u64,
+    /// Left nodes along the path of the last appended leaf, one per level
+    pub filled_subtrees: Vec<[u8; 32]>,
+    /// Ring buffer of recent changes. The entry for `sequence` holds the
+    /// current root
+    pub changelog: Vec<ChangeLog>,
+    /// The account that funded the tree's rent
+    pub payer: Pubkey,
+}
+
+impl Discriminator for AttestationTree {
+    const DISCRIMINATOR: u8 = AttestationAccountDiscriminators::AttestationTreeDiscriminator as u8;
+}
+
+impl AccountSerialize for AttestationTree {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        // Allocate once, the heap is not reclaimed while growing.
+        let mut data = Vec::with_capacity(Self::space(self.max_depth, self.max_buffer_size) - 1);
+        data.extend_from_slice(self.schema.as_ref());
+        data.push(self.max_depth);
+        data.push(self.max_buffer_size);
+        data.extend_from_slice(&self.num_leaves.to_le_bytes());
+        data.extend_from_slice(&self.sequence.to_le_bytes());
+        data.extend_from_slice(&(self.filled_subtrees.len() as u32).to_le_bytes());
+        for node in &self.filled_subtrees {
+            data.extend_from_slice(node);
+        }
+        data.extend_from_slice(&(self.changelog.len() as u32).to_le_bytes());
+        for change in &self.changelog {
+            data.extend_from_slice(&change.root);
+            data.extend_from_slice(&(change.path.len() as u32).to_le_bytes());
+            for node in &change.path {
+                data.extend_from_slice(node);
+            }
+            data.extend_from_slice(&change.index.to_le_bytes());
+        }
+        data.extend_from_slice(self.payer.as_ref());
+
+        data
+    }
+}
+
+/// Hash of an empty subtree at each level, `zero[0]` being an empty leaf.
+fn zero_hashes(depth: usize) -> Vec<Node> {
+    let mut zeros = Vec::with_capacity(depth);
+    let mut node = [0u8; 32];
+    for _ in 0..depth {
+        zeros.push(node);
+        node = hash_pair(&node, &node);
+    }
+    zeros
+}
+
+#[inline]
+fn hash_pair(left: &Node, right: &Node) -> Node {
+    hashv(&[left.as_ref(), right.as_ref()]).to_bytes()
+}
+
+/// Compute the path of `leaf` at `index` given its sibling `proof`, returning
+/// the path and the resulting root.
+fn compute_path(leaf: &Node, index: u32, proof: &[Node]) -> (Vec<Node>, Node) {
+    let mut path = Vec::with_capacity(proof.len());
+    let mut node = *leaf;
+    for (level, sibling) in proof.iter().enumerate() {
+        path.push(node);
+        node = if (index >> level) & 1 == 0 {
+            hash_pair(&node, sibling)
+        } else {
+            hash_pair(sibling, &node)
+        };
+    }
+    (path, node)
+}
+
+impl AttestationTree {
+    /// Serialized size of a tree, including the discriminator.
+    pub fn space(max_depth: u8, max_buffer_size: u8) -> usize {
+        let depth = max_depth as usize;
+        let changelog_entry = 32 + (4 + 32 * depth) + 4;
+        1 + 32
+            + 1
+            + 1
+            + 8
+            + 8
+            + (4 + 32 * depth)
+            + (4 + changelog_entry * max_buffer_size as usize)
+            + 32
+    }
+
+    /// Whether a leaf with `data_len` bytes of Attestation data can be revoked
+    /// in a single transaction.
+    pub fn fits_revocation(&self, data_len: usize) -> bool {
+        33 * self.max_depth as usize + data_len <= MAX_REVOCATION_PROOF_AND_DATA_SIZE
+    }
+
+    /// Create an empty tree.
+    pub fn new(schema: Pubkey, max_depth: u8, max_buffer_size: u8, payer: Pubkey) -> Self {
+        let zeros = zero_hashes(max_depth as usize + 1);
+        let empty_change = ChangeLog {
+            root: zeros[max_depth as usize],
+            path: zeros[..max_depth as usize].to_vec(),
+            index: 0,
+        };
+        let mut changelog = Vec::with_capacity(max_buffer_size as usize);
+        changelog.resize(max_buffer_size as usize, empty_change);
+
+        Self {
+            schema,
+            max_depth,
+            max_buffer_size,
+            num_leaves: 0,
+            sequence: 0,
+            filled_subtrees: zeros[..max_depth as usize].to_vec(),
+            changelog,
+            payer,
+        }
+    }
+
+    fn active_index(&self) -> usize {
+        (self.sequence % self.max_buffer_size as u64) as usize
+    }
+
+    /// Current root of the tree.
+    pub fn root(&self) -> Node {
+        self.changelog[self.active_index()].root
+    }
+
+    fn push_change(&mut self, root: Node, path: Vec<Node>, index: u32) {
+        self.sequence += 1;
+        let active_index = self.active_index();
+        self.changelog[active_index] = ChangeLog { root, path, index };
+    }
+
+    /// Append `leaf` as the next leaf without requiring a proof. Returns the
+    /// leaf index.
+    pub fn append(&mut self, leaf: Node) -> Result<u32, ProgramError> {
+        if self.num_leaves >= 1u64 << self.max_depth {
+            return Err(AttestationServiceError::TreeFull.into());
+        }
+        let index = self.num_leaves as u32;
+        let zeros = zero_hashes(self.max_depth as usize);
+
+        let mut path = Vec::with_capacity(self.max_depth as usize);
+        let mut node = leaf;
+        for level in 0..self.max_depth as usize {
+            path.push(node);
+            node = if (index >> level) & 1 == 0 {
+                self.filled_subtrees[level] = node;
+                hash_pair(&node, &zeros[level])
+            } else {
+                hash_pair(&self.filled_subtrees[level], &node)
+            };
+        }
+
+        self.num_leaves += 1;
+        self.push_change(node, path, index);
+        Ok(index)
+    }
+
+    /// Replace `old_leaf` at `index` with `new_leaf`. `proof` may have been
+    /// computed against any root still held in the changelog.
+    pub fn replace(
+        &mut self,
+        root: &Node,
+        index: u32,
+        old_leaf: &Node,
+        new_leaf: &Node,
+        proof: &mut [Node],
+    ) -> ProgramResult {
+        if index as u64 >= self.num_leaves || proof.len() != self.max_depth as usize {
+            return Err(AttestationServiceError::InvalidProof.into());
+        }
+
+        // Find the changelog entry the proof was generated against.
+        let buffer_size = self.max_buffer_size as u64;
+        let available = (self.sequence + 1).min(buffer_size);
+        let age = (0..available)
+            .find(|age| {
+                let entry = ((self.sequence - age) % buffer_size) as usize;
+                self.changelog[entry].root.eq(root)
+            })
+            .ok_or(AttestationServiceError::InvalidProof)?;
+
+        // Fast-forward the proof through every newer change. A change to the
+        // same leaf invalidates the proof.
+        for newer in (0..age).rev() {
+            let change = &self.changelog[((self.sequence - newer) % buffer_size) as usize];
+            if change.index == index {
+                return Err(AttestationServiceError::InvalidProof.into());
+            }
+            let critical_level = (31 - (change.index ^ index).leading_zeros()) as usize;
+            proof[critical_level] = change.path[critical_level];
+        }
+
+        let (_, current_root) = compute_path(old_leaf, index, proof);
+        if current_root.ne(&self.root()) {
+            return Err(AttestationServiceError::InvalidProof.into());
+        }
+
+        let (path, new_root) = compute_path(new_leaf, index, proof);
+
+        // Keep the append frontier in sync when it covers the replaced leaf.
+        let last_index = (self.num_leaves - 1) as u32;
+        for (level, node) in path.iter().enumerate() {
+            if index >> level == (last_index >> level) & !1 {
+                self.filled_subtrees[level] = *node;
+            }
+        }
+
+        self.push_change(new_root, path, index);
+        Ok(())
+    }
+
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        // Check discriminator
+        if data[0] != Self::DISCRIMINATOR {
+            msg!("Invalid AttestationTree Data");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Start offset after Discriminator
+        let mut offset: usize = 1;
+
+        let schema: Pubkey = data[offset..offset + 32].try_into().unwrap();
+        offset += 32;
+
+        let max_depth = data[offset];
+        offset += 1;
+
+        let max_buffer_size = data[offset];
+        offset += 1;
+
+        let num_leaves = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        offset += 8;
+
+        let sequence = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        offset += 8;
+
+        let (filled_subtrees, read) = read_nodes(&data[offset..]);
+        offset += read;
+
+        let changelog_len =
+            u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+        let mut changelog = Vec::with_capacity(changelog_len);
+        for _ in 0..changelog_len {
+            let root: Node = data[offset..offset + 32].try_into().unwrap();
+            offset += 32;
+            let (path, read) = read_nodes(&data[offset..]);
+            offset += read;
+            let index = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+            offset += 4;
+            changelog.push(ChangeLog { root, path, index });
+        }
+
+        let payer: Pubkey = data[offset..offset + 32].try_into().unwrap();
+
+        Ok(Self {
+            schema,
+            max_depth,
+            max_buffer_size,
+            num_leaves,
+            sequence,
+            filled_subtrees,
+            changelog,
+            payer,
+        })
+    }
+}
+
+/// Read a length-prefixed list of nodes, returning it and the bytes read.
+fn read_nodes(data: &[u8]) -> (Vec<Node>, usize) {
+    let len = u32::from_le_bytes(data[0..4].try_into().unwrap()) as usize;
+    let nodes = data[4..4 + 32 * len]
+        .chunks_exact(32)
+        .map(|node| node.try_into().unwrap())
+        .collect();
+    (nodes, 4 + 32 * len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(i: u8) -> Node {
+        solana_program::keccak::hash(&[i]).to_bytes()
+    }
+
+    /// Root and proof computed from scratch over `leaves`.
+    fn reference(depth: usize, leaves: &[Node], index: usize) -> (Node, Vec<Node>) {
+        let mut level: Vec<Node> = leaves.to_vec();
+        level.resize(1 << depth, [0u8; 32]);
+        let mut proof = Vec::new();
+        let mut index = index;
+        for _ in 0..depth {
+            proof.push(level[index ^ 1]);
+            level = level
+                .chunks_exact(2)
+                .map(|pair| hash_pair(&pair[0], &pair[1]))
+                .collect();
+            index >>= 1;
+        }
+        (level[0], proof)
+    }
+
+    #[test]
+    fn attestation_tree_append_and_replace() {
+        let mut tree = AttestationTree::new(Pubkey::default(), 3, 4, Pubkey::default());
+        assert_eq!(tree.root(), reference(3, &[], 0).0);
+
+        let mut leaves = Vec::new();
+        for i in 0..5 {
+            leaves.push(leaf(i));
+            assert_eq!(tree.append(leaf(i)).unwrap(), i as u32);
+            assert_eq!(tree.root(), reference(3, &leaves, 0).0);
+        }
+
+        // Replace with a proof against the current root.
+        let (root, mut proof) = reference(3, &leaves, 1);
+        tree.replace(&root, 1, &leaves[1], &leaf(10), &mut proof)
+            .unwrap();
+        leaves[1] = leaf(10);
+        assert_eq!(tree.root(), reference(3, &leaves, 0).0);
+
+        // Replace with a stale proof, fast-forwarded over newer changes.
+        let (stale_root, mut stale_proof) = reference(3, &leaves, 3);
+        tree.append(leaf(5)).unwrap();
+        leaves.push(leaf(5));
+        let (root, mut proof) = reference(3, &leaves, 0);
+        tree.replace(&root, 0, &leaves[0], &leaf(11), &mut proof)
+            .unwrap();
+        leaves[0] = leaf(11);
+        tree.replace(&stale_root, 3, &leaves[3], &leaf(12), &mut stale_proof)
+            .unwrap();
+        leaves[3] = leaf(12);
+        assert_eq!(tree.root(), reference(3, &leaves, 0).0);
+
+        // Appending after replacements keeps the frontier consistent.
+        tree.append(leaf(6)).unwrap();
+        leaves.push(leaf(6));
+        assert_eq!(tree.root(), reference(3, &leaves, 0).0);
+
+        // Round trip through account bytes.
+        let bytes = tree.to_bytes();
+        assert_eq!(bytes.len(), AttestationTree::space(3, 4));
+        assert_eq!(AttestationTree::try_from_bytes(&bytes).unwrap(), tree);
+    }
+
+    #[test]
+    fn attestation_tree_at_limits() {
+        // Largest changelog buffer fitting an account at the deepest tree.
+        let depth = MAX_TREE_DEPTH;
+        let buffer_size = (1..=MAX_TREE_BUFFER_SIZE)
+            .rev()
+            .find(|size| AttestationTree::space(depth, *size) <= MAX_TREE_ACCOUNT_SIZE)
+            .unwrap();
+        assert_eq!(buffer_size, 13);
+
+        let mut tree =
+            AttestationTree::new(Pubkey::default(), depth, buffer_size, Pubkey::default());
+        let mut leaves = Vec::new();
+        for i in 0..3 {
+            leaves.push(leaf(i));
+            tree.append(leaf(i)).unwrap();
+        }
+        let (root, mut proof) = reference(depth as usize, &leaves, 1);
+        assert_eq!(tree.root(), root);
+        tree.replace(&root, 1, &leaves[1], &leaf(10), &mut proof)
+            .unwrap();
+        leaves[1] = leaf(10);
+        assert_eq!(tree.root(), reference(depth as usize, &leaves, 0).0);
+
+        let bytes = tree.to_bytes();
+        assert_eq!(bytes.len(), AttestationTree::space(depth, buffer_size));
+        assert_eq!(AttestationTree::try_from_bytes(&bytes).unwrap(), tree);
+
+        // Leaves of the deepest tree keep room for 110 bytes of data.
+        assert!(tree.fits_revocation(110));
+        assert!(!tree.fits_revocation(111));
+    }
+
+    #[test]
+    fn attestation_tree_replace_fail_changed_leaf() {
+        let mut tree = AttestationTree::new(Pubkey::default(), 2, 4, Pubkey::default());
+        let leaves = [leaf(0), leaf(1)];
+        for node in leaves {
+            tree.append(node).unwrap();
+        }
+
+        let (root, proof) = reference(2, &leaves, 1);
+        tree.replace(&root, 1, &leaves[1], &leaf(10), &mut proof.clone())
+            .unwrap();
+        // Reusing the proof for the same, now changed, leaf fails.
+        assert!(tree
+            .replace(&root, 1, &leaves[1], &leaf(11), &mut proof.clone())
+            .is_err());
+    }
+}
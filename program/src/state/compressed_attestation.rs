@@ -0,0 +1,148 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+use shank::ShankType;
+use solana_program::keccak::hash;
+
+use crate::require_len;
+
+use super::attestation_tree::Node;
+
+/// Leaf of an AttestationTree. Only its hash is stored on-chain, the full
+/// leaf is emitted in a CompressedAttestationEvent on every change.
+#[derive(Clone, Debug, PartialEq, ShankType)]
+pub struct CompressedAttestation {
+    /// A pubkey that may either be randomly generated OR associated with a User's wallet
+    pub nonce: Pubkey,
+    /// Credential this attestation is related to
+    pub credential: Pubkey,
+    /// Reference to the Schema this Attestation adheres to
+    pub schema: Pubkey,
+    /// Data that was verified and matches the Schema
+    pub data: Vec<u8>,
+    /// The pubkey of the signer. Must be one of the `authorized_signer`s at time of attestation
+    pub signer: Pubkey,
+    /// Designates when the credential is expired. 0 means never expired
+    pub expiry: i64,
+    /// Unix timestamp of when the attestation was created
+    pub created_at: i64,
+    /// Designates when the attestation was revoked. 0 means not revoked
+    pub revoked_at: i64,
+    /// The pubkey of the authorized signer that revoked the attestation.
+    /// Otherwise set to default pubkey.
+    pub revoked_by: Pubkey,
+    /// Issuer-defined code describing why the attestation was revoked.
+    pub revocation_reason: u8,
+}
+
+impl CompressedAttestation {
+    /// Whether the Attestation has been revoked by an authorized signer.
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at != 0
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(self.nonce.as_ref());
+        data.extend_from_slice(self.credential.as_ref());
+        data.extend_from_slice(self.schema.as_ref());
+        data.extend_from_slice(&(self.data.len() as u32).to_le_bytes());
+        data.extend_from_slice(self.data.as_ref());
+        data.extend_from_slice(self.signer.as_ref());
+        data.extend_from_slice(&self.expiry.to_le_bytes());
+        data.extend_from_slice(&self.created_at.to_le_bytes());
+        data.extend_from_slice(&self.revoked_at.to_le_bytes());
+        data.extend_from_slice(self.revoked_by.as_ref());
+        data.push(self.revocation_reason);
+
+        data
+    }
+
+    /// Leaf hash stored in the tree: keccak256 of the serialized leaf.
+    pub fn hash(&self) -> Node {
+        hash(&self.to_bytes()).to_bytes()
+    }
+
+    /// Parse a leaf from instruction data. Returns the leaf and the bytes read.
+    pub fn try_from_bytes(data: &[u8]) -> Result<(Self, usize), ProgramError> {
+        let mut offset: usize = 0;
+
+        require_len!(data, offset + 96 + 4);
+        let nonce: Pubkey = data[offset..offset + 32].try_into().unwrap();
+        offset += 32;
+
+        let credential: Pubkey = data[offset..offset + 32].try_into().unwrap();
+        offset += 32;
+
+        let schema: Pubkey = data[offset..offset + 32].try_into().unwrap();
+        offset += 32;
+
+        let data_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+
+        require_len!(data, offset + data_len + 32 + 8 * 3 + 32 + 1);
+        let attestation_data = data[offset..offset + data_len].to_vec();
+        offset += data_len;
+
+        let signer: Pubkey = data[offset..offset + 32].try_into().unwrap();
+        offset += 32;
+
+        let expiry = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        offset += 8;
+
+        let created_at = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        offset += 8;
+
+        let revoked_at = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        offset += 8;
+
+        let revoked_by: Pubkey = data[offset..offset + 32].try_into().unwrap();
+        offset += 32;
+
+        let revocation_reason = data[offset];
+        offset += 1;
+
+        Ok((
+            Self {
+                nonce,
+                credential,
+                schema,
+                data: attestation_data,
+                signer,
+                expiry,
+                created_at,
+                revoked_at,
+                revoked_by,
+                revocation_reason,
+            },
+            offset,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compressed_attestation_round_trip() {
+        let leaf = CompressedAttestation {
+            nonce: [1; 32],
+            credential: [2; 32],
+            schema: [3; 32],
+            data: Vec::from([4, 5, 6]),
+            signer: [7; 32],
+            expiry: 8,
+            created_at: 9,
+            revoked_at: 10,
+            revoked_by: [11; 32],
+            revocation_reason: 12,
+        };
+        let bytes = leaf.to_bytes();
+        let (parsed, read) = CompressedAttestation::try_from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, leaf);
+        assert_eq!(read, bytes.len());
+        assert!(CompressedAttestation::try_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
@@ -2,27 +2,108 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 use pinocchio::{msg, program_error::ProgramError, pubkey::Pubkey};
-use pinocchio_log::log;
-use shank::ShankAccount;
+use shank::{ShankAccount, ShankType};
 
-use crate::error::AttestationServiceError;
+use crate::{error::AttestationServiceError, require_len};
 
 use super::discriminator::{AccountSerialize, AttestationAccountDiscriminators, Discriminator};
 
-// PDA ["credential", authority, name]
+// PDA ["credential", original authority, name]
 /// Tracks the authorized signers of for schemas and their attestations.
 #[derive(Clone, Debug, PartialEq, ShankAccount)]
 #[repr(C)]
 pub struct Credential {
-    /// Admin of this credential
+    /// Admin of this credential. May differ from the PDA seed once the
+    /// authority has been transferred.
     pub authority: Pubkey,
     /// UTF-8 encoded Name of this credential
     /// Includes 4 bytes for length of name
     pub name: Vec<u8>,
     /// List of signers that are allowed to "attest"
     pub authorized_signers: Vec<Pubkey>,
+    /// The account that funded the Credential's rent
+    pub payer: Pubkey,
+    /// Number of admins that must sign admin instructions. Zero leaves the
+    /// Credential administered by `authority` alone.
+    pub admin_threshold: u8,
+    /// Keys allowed to co-sign admin instructions
+    pub admins: Vec<Pubkey>,
+    /// Proposed new authority awaiting acceptance, default when none
+    pub pending_authority: Pubkey,
+    /// Number of Schemas created under this credential that are still open
+    pub schema_count: u32,
+    /// Restrictions on authorized signers to a set of capabilities and
+    /// Schemas. An entry grants nothing unless its signer is also listed in
+    /// `authorized_signers`.
+    pub signer_permissions: Vec<SignerPermission>,
 }
 
+/// Capabilities granted to a single signer of a Credential.
+#[derive(Clone, Debug, PartialEq, ShankType)]
+pub struct SignerPermission {
+    pub signer: Pubkey,
+    /// Bitmask of `SignerPermission::ATTEST`, `CLOSE` and `UPDATE`
+    pub capabilities: u8,
+    /// Schemas the signer may act on. Empty allows every Schema of the Credential.
+    pub schemas: Vec<Pubkey>,
+}
+
+impl SignerPermission {
+    /// Create Attestations.
+    pub const ATTEST: u8 = 1 << 0;
+    /// Close and revoke Attestations.
+    pub const CLOSE: u8 = 1 << 1;
+    /// Update and renew Attestations.
+    pub const UPDATE: u8 = 1 << 2;
+    pub const ALL: u8 = Self::ATTEST | Self::CLOSE | Self::UPDATE;
+
+    /// Whether the entry grants `capability` on `schema`.
+    pub fn allows(&self, schema: &Pubkey, capability: u8) -> bool {
+        self.capabilities & capability == capability
+            && (self.schemas.is_empty() || self.schemas.contains(schema))
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(self.signer.as_ref());
+        data.push(self.capabilities);
+        data.extend_from_slice(&(self.schemas.len() as u32).to_le_bytes());
+        for schema in &self.schemas {
+            data.extend_from_slice(schema.as_ref());
+        }
+        data
+    }
+
+    /// Parse an entry, returning it with the number of bytes read.
+    pub fn try_from_bytes(data: &[u8]) -> Result<(Self, usize), ProgramError> {
+        require_len!(data, 37);
+        let signer: Pubkey = data[0..32].try_into().unwrap();
+        let capabilities = data[32];
+        let schemas_len = u32::from_le_bytes(data[33..37].try_into().unwrap()) as usize;
+        let mut offset = 37;
+
+        require_len!(data, offset + schemas_len * 32);
+        let mut schemas = Vec::with_capacity(schemas_len);
+        for _ in 0..schemas_len {
+            let schema: Pubkey = data[offset..offset + 32].try_into().unwrap();
+            schemas.push(schema);
+            offset += 32;
+        }
+
+        Ok((
+            Self {
+                signer,
+                capabilities,
+                schemas,
+            },
+            offset,
+        ))
+    }
+}
+
+/// Maximum number of admins a Credential can hold.
+pub const MAX_CREDENTIAL_ADMINS: usize = 10;
+
 impl Discriminator for Credential {
     const DISCRIMINATOR: u8 = AttestationAccountDiscriminators::CredentialDiscriminator as u8;
 }
@@ -43,27 +124,106 @@ impl AccountSerialize for Credential {
             data.extend_from_slice(signer.as_ref());
         }
 
+        // Payer encoding
+        data.extend_from_slice(self.payer.as_ref());
+
+        // Admins encoding
+        data.push(self.admin_threshold);
+        data.extend_from_slice(&(self.admins.len() as u32).to_le_bytes());
+        for admin in &self.admins {
+            data.extend_from_slice(admin.as_ref());
+        }
+
+        // Pending authority encoding
+        data.extend_from_slice(self.pending_authority.as_ref());
+
+        // Schema count encoding
+        data.extend_from_slice(&self.schema_count.to_le_bytes());
+
+        // Signer permissions encoding
+        data.extend_from_slice(&(self.signer_permissions.len() as u32).to_le_bytes());
+        for permission in &self.signer_permissions {
+            data.extend(permission.to_bytes());
+        }
+
         data
     }
 }
 
 impl Credential {
-    pub fn validate_authority(&self, authority: &Pubkey) -> Result<(), ProgramError> {
-        if self.authority.ne(authority) {
-            log!("Authority Mismatch");
-            return Err(ProgramError::InvalidAccountData);
+    /// Schema count of Credentials created before Schemas were counted.
+    /// Such Credentials cannot be closed, since open Schemas cannot be
+    /// ruled out.
+    pub const UNTRACKED_SCHEMA_COUNT: u32 = u32::MAX;
+
+    /// Add `delta` to the Schema count, unless it is untracked.
+    pub fn adjust_schema_count(&mut self, delta: i32) -> Result<(), ProgramError> {
+        if self.schema_count != Self::UNTRACKED_SCHEMA_COUNT {
+            self.schema_count = self
+                .schema_count
+                .checked_add_signed(delta)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
         }
         Ok(())
     }
 
-    /// Validate the signer is one of the authorized signers.
-    pub fn validate_authorized_signer(&self, signer: &Pubkey) -> Result<(), ProgramError> {
-        if !self.authorized_signers.contains(signer) {
+    /// Validate the signer may use `capability` on `schema`. The signer must
+    /// be one of the authorized signers, and a permission entry, if any,
+    /// further narrows what it may do.
+    pub fn validate_authorized_signer(
+        &self,
+        signer: &Pubkey,
+        schema: &Pubkey,
+        capability: u8,
+    ) -> Result<(), ProgramError> {
+        let authorized = self.authorized_signers.contains(signer)
+            && self
+                .signer_permissions
+                .iter()
+                .find(|p| p.signer.eq(signer))
+                .map_or(true, |permission| permission.allows(schema, capability));
+        if !authorized {
             return Err(AttestationServiceError::SignerNotAuthorized.into());
         }
         Ok(())
     }
 
+    /// Validate signer permission entries. Each signer may appear once and
+    /// must be granted at least one known capability.
+    pub fn validate_signer_permissions(
+        permissions: &[SignerPermission],
+    ) -> Result<(), ProgramError> {
+        for (i, permission) in permissions.iter().enumerate() {
+            if permission.capabilities == 0
+                || permission.capabilities & !SignerPermission::ALL != 0
+                || permissions[..i]
+                    .iter()
+                    .any(|p| p.signer.eq(&permission.signer))
+            {
+                return Err(AttestationServiceError::InvalidSignerPermissions.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate an admin configuration. No admins requires a zero threshold,
+    /// otherwise the threshold must be reachable by distinct admins.
+    pub fn validate_admins(admin_threshold: u8, admins: &[Pubkey]) -> Result<(), ProgramError> {
+        let threshold = admin_threshold as usize;
+        if admins.len() > MAX_CREDENTIAL_ADMINS
+            || (admins.is_empty() && threshold != 0)
+            || (!admins.is_empty() && (threshold == 0 || threshold > admins.len()))
+        {
+            return Err(AttestationServiceError::InvalidAdminConfig.into());
+        }
+        for (i, admin) in admins.iter().enumerate() {
+            if admins[..i].contains(admin) {
+                return Err(AttestationServiceError::InvalidAdminConfig.into());
+            }
+        }
+        Ok(())
+    }
+
     pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
         // Check discriminator
         if data[0] != Self::DISCRIMINATOR {
@@ -92,10 +252,55 @@ impl Credential {
             offset += 32;
         }
 
-        Ok(Self {
+        let mut credential = Self {
             authority,
             name,
             authorized_signers,
-        })
+            payer: Pubkey::default(),
+            admin_threshold: 0,
+            admins: Vec::new(),
+            pending_authority: Pubkey::default(),
+            schema_count: Self::UNTRACKED_SCHEMA_COUNT,
+            signer_permissions: Vec::new(),
+        };
+
+        // Credentials created before the fields below were added end here
+        // and keep their defaults until migrated.
+        if offset == data.len() {
+            return Ok(credential);
+        }
+
+        credential.payer = data[offset..offset + 32].try_into().unwrap();
+        offset += 32;
+
+        credential.admin_threshold = data[offset];
+        offset += 1;
+
+        let admins_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+
+        for _ in 0..admins_len {
+            let admin: Pubkey = data[offset..offset + 32].try_into().unwrap();
+            credential.admins.push(admin);
+            offset += 32;
+        }
+
+        credential.pending_authority = data[offset..offset + 32].try_into().unwrap();
+        offset += 32;
+
+        credential.schema_count = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        offset += 4;
+
+        let permissions_len =
+            u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+
+        for _ in 0..permissions_len {
+            let (permission, len) = SignerPermission::try_from_bytes(&data[offset..])?;
+            credential.signer_permissions.push(permission);
+            offset += len;
+        }
+
+        Ok(credential)
     }
 }
@@ -10,6 +10,8 @@ pub enum AttestationAccountDiscriminators {
     CredentialDiscriminator = 0,
     SchemaDiscriminator = 1,
     AttestationDiscriminator = 2,
+    AttestationTreeDiscriminator = 3,
+    SignatureReceiptDiscriminator = 4,
 }
 
 pub trait AccountSerialize: Discriminator {
@@ -1,8 +1,14 @@
 pub mod attestation;
+pub mod attestation_tree;
+pub mod compressed_attestation;
 pub mod credential;
 pub mod discriminator;
 pub mod schema;
+pub mod signature_receipt;
 
 pub use attestation::*;
+pub use attestation_tree::*;
+pub use compressed_attestation::*;
 pub use credential::*;
 pub use schema::*;
+pub use signature_receipt::*;
@@ -36,12 +36,70 @@ pub enum SchemaDataTypes {
     VecI128 = 22,
     VecBool = 23,
     VecChar = 24,
-    VecString = 25, // Max Value
+    VecString = 25,
+    OptionU8 = 26,
+    OptionU16 = 27,
+    OptionU32 = 28,
+    OptionU64 = 29,
+    OptionU128 = 30,
+    OptionI8 = 31,
+    OptionI16 = 32,
+    OptionI32 = 33,
+    OptionI64 = 34,
+    OptionI128 = 35,
+    OptionBool = 36,
+    OptionChar = 37,
+    OptionString = 38,
+    OptionVecU8 = 39,
+    OptionVecU16 = 40,
+    OptionVecU32 = 41,
+    OptionVecU64 = 42,
+    OptionVecU128 = 43,
+    OptionVecI8 = 44,
+    OptionVecI16 = 45,
+    OptionVecI32 = 46,
+    OptionVecI64 = 47,
+    OptionVecI128 = 48,
+    OptionVecBool = 49,
+    OptionVecChar = 50,
+    OptionVecString = 51,
+    Pubkey = 52,
+    Bytes32 = 53,
+    Bytes64 = 54,
+    /// Inline group of fields, followed in the layout by the number of fields
+    /// (u8) and their types.
+    Struct = 55,
+    /// Vec of field groups, followed in the layout like `Struct`.
+    VecStruct = 56,
+    F32 = 57,
+    F64 = 58,
+    /// Fixed-point number encoded as an i128 mantissa, followed in the layout
+    /// by its scale (u8): the number of decimal places.
+    Decimal = 59,
+    /// Unix timestamp in seconds, encoded as an i64.
+    Timestamp = 60,
+    /// Variant index encoded as a u8, followed in the layout by the number of
+    /// variants (u8). Variant names are stored in `Schema::enum_variants`.
+    Enum = 61, // Max Value
 }
 
 impl SchemaDataTypes {
     pub fn max() -> u8 {
-        SchemaDataTypes::VecString as u8
+        SchemaDataTypes::Enum as u8
+    }
+
+    /// Whether `data_type` is followed in the layout by a group of fields.
+    pub fn is_struct(data_type: u8) -> bool {
+        data_type == SchemaDataTypes::Struct as u8 || data_type == SchemaDataTypes::VecStruct as u8
+    }
+
+    /// Data type wrapped by `data_type` if it is an Option, encoded as a one
+    /// byte tag (0 for None, 1 for Some) followed by the value when Some.
+    pub fn option_inner(data_type: u8) -> Option<u8> {
+        let first = SchemaDataTypes::OptionU8 as u8;
+        (first..=SchemaDataTypes::OptionVecString as u8)
+            .contains(&data_type)
+            .then(|| data_type - first)
     }
 }
 
@@ -74,11 +132,113 @@ impl From<u8> for SchemaDataTypes {
             23 => SchemaDataTypes::VecBool,
             24 => SchemaDataTypes::VecChar,
             25 => SchemaDataTypes::VecString,
+            26 => SchemaDataTypes::OptionU8,
+            27 => SchemaDataTypes::OptionU16,
+            28 => SchemaDataTypes::OptionU32,
+            29 => SchemaDataTypes::OptionU64,
+            30 => SchemaDataTypes::OptionU128,
+            31 => SchemaDataTypes::OptionI8,
+            32 => SchemaDataTypes::OptionI16,
+            33 => SchemaDataTypes::OptionI32,
+            34 => SchemaDataTypes::OptionI64,
+            35 => SchemaDataTypes::OptionI128,
+            36 => SchemaDataTypes::OptionBool,
+            37 => SchemaDataTypes::OptionChar,
+            38 => SchemaDataTypes::OptionString,
+            39 => SchemaDataTypes::OptionVecU8,
+            40 => SchemaDataTypes::OptionVecU16,
+            41 => SchemaDataTypes::OptionVecU32,
+            42 => SchemaDataTypes::OptionVecU64,
+            43 => SchemaDataTypes::OptionVecU128,
+            44 => SchemaDataTypes::OptionVecI8,
+            45 => SchemaDataTypes::OptionVecI16,
+            46 => SchemaDataTypes::OptionVecI32,
+            47 => SchemaDataTypes::OptionVecI64,
+            48 => SchemaDataTypes::OptionVecI128,
+            49 => SchemaDataTypes::OptionVecBool,
+            50 => SchemaDataTypes::OptionVecChar,
+            51 => SchemaDataTypes::OptionVecString,
+            52 => SchemaDataTypes::Pubkey,
+            53 => SchemaDataTypes::Bytes32,
+            54 => SchemaDataTypes::Bytes64,
+            55 => SchemaDataTypes::Struct,
+            56 => SchemaDataTypes::VecStruct,
+            57 => SchemaDataTypes::F32,
+            58 => SchemaDataTypes::F64,
+            59 => SchemaDataTypes::Decimal,
+            60 => SchemaDataTypes::Timestamp,
+            61 => SchemaDataTypes::Enum,
             _ => panic!("Invalid u8 for SchemaDataTypes"),
         }
     }
 }
 
+/// Struct groups nested deeper than this are rejected to bound stack usage.
+pub const MAX_STRUCT_DEPTH: u8 = 4;
+
+/// Decimals with more places than an i128 mantissa can hold are rejected.
+pub const MAX_DECIMAL_SCALE: u8 = 38;
+
+/// Validate the type descriptor at the start of `layout`: a single data type,
+/// a Decimal with its scale, an Enum with its variant count, or a struct group
+/// with its fields. Returns the descriptor's length in the layout, the number
+/// of fields it describes and the number of Enum variants they declare, both
+/// including nested fields.
+pub fn validate_type_descriptor(
+    layout: &[u8],
+    depth: u8,
+) -> Result<(usize, u32, u32), ProgramError> {
+    let data_type = *layout
+        .first()
+        .ok_or(AttestationServiceError::InvalidSchemaDataType)?;
+    if data_type > SchemaDataTypes::max() {
+        return Err(AttestationServiceError::InvalidSchemaDataType.into());
+    }
+    if data_type == SchemaDataTypes::Decimal as u8 {
+        match layout.get(1) {
+            Some(scale) if *scale <= MAX_DECIMAL_SCALE => return Ok((2, 1, 0)),
+            _ => return Err(AttestationServiceError::InvalidSchemaDataType.into()),
+        }
+    }
+    if data_type == SchemaDataTypes::Enum as u8 {
+        match layout.get(1) {
+            Some(variants) if *variants > 0 => return Ok((2, 1, *variants as u32)),
+            _ => return Err(AttestationServiceError::InvalidSchemaDataType.into()),
+        }
+    }
+    if !SchemaDataTypes::is_struct(data_type) {
+        return Ok((1, 1, 0));
+    }
+
+    let field_count = *layout
+        .get(1)
+        .ok_or(AttestationServiceError::InvalidSchemaDataType)?;
+    if field_count == 0 || depth >= MAX_STRUCT_DEPTH {
+        return Err(AttestationServiceError::InvalidSchemaDataType.into());
+    }
+    let (mut len, mut count, mut variants) = (2, 1, 0);
+    for _ in 0..field_count {
+        let (field_len, nested_count, nested_variants) =
+            validate_type_descriptor(&layout[len..], depth + 1)?;
+        len += field_len;
+        count += nested_count;
+        variants += nested_variants;
+    }
+    Ok((len, count, variants))
+}
+
+/// Split `layout` into the type descriptors of its top level fields.
+pub fn split_layout(layout: &[u8]) -> Result<Vec<&[u8]>, ProgramError> {
+    let mut descriptors = Vec::new();
+    let mut offset = 0;
+    while offset < layout.len() {
+        let (len, _, _) = validate_type_descriptor(&layout[offset..], 0)?;
+        descriptors.push(&layout[offset..offset + len]);
+        offset += len;
+    }
+    Ok(descriptors)
+}
+
 impl From<SchemaDataTypes> for u8 {
     fn from(data_type: SchemaDataTypes) -> u8 {
         data_type as u8
@@ -104,6 +264,20 @@ pub struct Schema {
     pub is_paused: bool,
     /// Version of this schema. Defaults to 1.
     pub version: u8,
+    /// The account that funded the Schema's rent
+    pub payer: Pubkey,
+    /// Whether the Attestation nonce must be the subject's wallet, co-signing
+    /// every CreateAttestation.
+    pub requires_subject_signer: bool,
+    /// Per-field constraints on Attestation data, checked when Attestations
+    /// are created or updated. See `ConstraintKind` for the encoding.
+    pub constraints: Vec<u8>,
+    /// Variant names of the Schema's Enum fields, in layout order, stored as
+    /// serialized array of Strings. First 4 bytes are number of bytes in array.
+    pub enum_variants: Vec<u8>,
+    /// Number of open Attestation accounts of this Schema. Compressed
+    /// Attestations are not counted.
+    pub attestation_count: u64,
 }
 
 impl Discriminator for Schema {
@@ -124,24 +298,51 @@ impl AccountSerialize for Schema {
         data.extend_from_slice(self.field_names.as_ref());
         data.extend_from_slice(&[self.is_paused as u8]);
         data.extend_from_slice(&[self.version]);
+        data.extend_from_slice(self.payer.as_ref());
+        data.extend_from_slice(&[self.requires_subject_signer as u8]);
+        data.extend(&(self.constraints.len() as u32).to_le_bytes());
+        data.extend_from_slice(self.constraints.as_ref());
+        data.extend(&(self.enum_variants.len() as u32).to_le_bytes());
+        data.extend_from_slice(self.enum_variants.as_ref());
+        data.extend_from_slice(&self.attestation_count.to_le_bytes());
 
         data
     }
 }
 
 impl Schema {
-    pub fn validate(&self, field_names_count: u32) -> Result<(), ProgramError> {
-        for data_type in &self.layout {
-            if data_type > &SchemaDataTypes::max() {
-                return Err(AttestationServiceError::InvalidSchemaDataType.into());
-            }
+    /// Attestation count of Schemas created before Attestations were
+    /// counted. Such Schemas cannot be closed, since open Attestations
+    /// cannot be ruled out.
+    pub const UNTRACKED_ATTESTATION_COUNT: u64 = u64::MAX;
+
+    pub fn validate(
+        &self,
+        field_names_count: u32,
+        enum_variants_count: u32,
+    ) -> Result<(), ProgramError> {
+        let mut fields_count = 0;
+        let mut variants_count = 0;
+        let mut offset = 0;
+        while offset < self.layout.len() {
+            let (len, count, variants) = validate_type_descriptor(&self.layout[offset..], 0)?;
+            offset += len;
+            fields_count += count;
+            variants_count += variants;
         }
 
-        // Expect number of field names to match number of fields in layout.
-        if field_names_count != u32::try_from(self.layout.len()).unwrap() {
+        // Expect number of field names to match number of fields in layout,
+        // with struct groups and each of their fields named in order.
+        if field_names_count != fields_count {
             log!("Field names does not match layout length");
             return Err(AttestationServiceError::InvalidSchema.into());
         }
+
+        // Expect a name for every variant of every Enum field.
+        if enum_variants_count != variants_count {
+            log!("Enum variant names does not match layout");
+            return Err(AttestationServiceError::InvalidSchema.into());
+        }
         Ok(())
     }
 
@@ -183,8 +384,9 @@ impl Schema {
         offset += 1;
 
         let version = data[offset];
+        offset += 1;
 
-        Ok(Self {
+        let mut schema = Self {
             credential,
             name,
             description,
@@ -192,6 +394,57 @@ impl Schema {
             field_names,
             is_paused,
             version,
-        })
+            payer: Pubkey::default(),
+            requires_subject_signer: false,
+            constraints: Vec::new(),
+            enum_variants: Vec::new(),
+            attestation_count: Self::UNTRACKED_ATTESTATION_COUNT,
+        };
+
+        // Schemas created before the fields below were added end here and
+        // keep their defaults until migrated.
+        if offset == data.len() {
+            return Ok(schema);
+        }
+
+        schema.payer = data[offset..offset + 32].try_into().unwrap();
+        offset += 32;
+
+        schema.requires_subject_signer = data[offset] == 1;
+        offset += 1;
+
+        let constraints_len =
+            u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+        schema.constraints = data[offset..offset + constraints_len].to_vec();
+        offset += constraints_len;
+
+        let enum_variants_len =
+            u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+        schema.enum_variants = data[offset..offset + enum_variants_len].to_vec();
+        offset += enum_variants_len;
+
+        schema.attestation_count = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+        Ok(schema)
+    }
+
+    /// Add `delta` to the attestation count of a serialized Schema in place.
+    /// The count is the last field of the account, so creating or closing
+    /// Attestations doesn't need to rewrite the whole Schema. Untracked
+    /// counts are left as is.
+    pub fn adjust_attestation_count(data: &mut [u8], delta: i64) -> Result<(), ProgramError> {
+        if Self::try_from_bytes(data)?.attestation_count == Self::UNTRACKED_ATTESTATION_COUNT {
+            return Ok(());
+        }
+
+        let offset = data.len() - 8;
+        let count = u64::from_le_bytes(data[offset..].try_into().unwrap())
+            .checked_add_signed(delta)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        data[offset..].copy_from_slice(&count.to_le_bytes());
+
+        Ok(())
     }
 }
@@ -0,0 +1,52 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{msg, program_error::ProgramError, pubkey::Pubkey};
+use shank::ShankAccount;
+
+use super::discriminator::{AccountSerialize, AttestationAccountDiscriminators, Discriminator};
+
+// PDA ["signatureReceipt", sha256(message)]
+/// Marks a message signed for CreateAttestationWithSignature as used. It can
+/// be closed once the message's deadline has passed, as the message can no
+/// longer be submitted.
+#[derive(Clone, Debug, PartialEq, ShankAccount)]
+#[repr(C)]
+pub struct SignatureReceipt {
+    /// The account that funded the receipt's rent
+    pub payer: Pubkey,
+    /// Deadline of the signed message
+    pub deadline: i64,
+}
+
+impl Discriminator for SignatureReceipt {
+    const DISCRIMINATOR: u8 = AttestationAccountDiscriminators::SignatureReceiptDiscriminator as u8;
+}
+
+impl AccountSerialize for SignatureReceipt {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN - 1);
+        data.extend_from_slice(self.payer.as_ref());
+        data.extend_from_slice(&self.deadline.to_le_bytes());
+
+        data
+    }
+}
+
+impl SignatureReceipt {
+    /// Account size: discriminator - 1, payer - 32, deadline - 8
+    pub const LEN: usize = 1 + 32 + 8;
+
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        // Check discriminator and size
+        if data.len() != Self::LEN || data[0] != Self::DISCRIMINATOR {
+            msg!("Invalid SignatureReceipt Data");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let payer: Pubkey = data[1..33].try_into().unwrap();
+        let deadline = i64::from_le_bytes(data[33..41].try_into().unwrap());
+
+        Ok(Self { payer, deadline })
+    }
+}